@@ -8,14 +8,22 @@
 //! - エラーハンドリング
 
 use crate::components::auth::AuthToken;
+use crate::components::rate_limit::{
+    ApiRateLimiter, CategoryLimits, DailyQuotaUsage, RateLimitCategory, RateLimitDecision,
+    RateLimitHeaders,
+};
+use crate::components::redaction::{redact_text, truncate_text};
+use crate::components::retry::{ExponentialBackoff, RetryDecision, RetryPolicy};
+use crate::components::token_provider::TokenProvider;
 use crate::components::{ComponentLifecycle, Configurable};
 use crate::errors::{AppError, AppResult};
 use async_trait::async_trait;
 use chrono::NaiveDate;
 use log;
-use reqwest::{Client, StatusCode};
+use reqwest::{Client, RequestBuilder, Response, StatusCode};
 use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::{Mutex, RwLock};
@@ -55,15 +63,12 @@ impl Default for ApiConfig {
 
 /// レート制限設定
 ///
-/// Zoom APIプラン別上限: Free=2, Pro=20, Business+=60 req/sec
-/// デフォルト10はPro以上で安全なマージン付きの値
+/// Zoom はエンドポイントのカテゴリ（Light/Medium/Heavy/Resource-intensive）ごとに上限を設ける。
+/// デフォルトはPro以上で安全なマージン付きの値
 #[derive(Debug, Clone)]
 pub struct RateLimitConfig {
-    /// 秒あたりのリクエスト数
-    /// Zoom APIプラン別上限: Free=2, Pro=20, Business+=60
-    pub requests_per_second: u32,
-    /// バースト容量
-    pub burst_size: u32,
+    /// カテゴリ別の上限
+    pub categories: CategoryLimits,
     /// 最大待機時間
     pub max_wait_time: Duration,
 }
@@ -71,13 +76,47 @@ pub struct RateLimitConfig {
 impl Default for RateLimitConfig {
     fn default() -> Self {
         Self {
-            requests_per_second: 10,
-            burst_size: 20,
+            categories: CategoryLimits::default(),
             max_wait_time: Duration::from_secs(60),
         }
     }
 }
 
+/// ApiComponent が呼び出すエンドポイント
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiEndpoint {
+    /// GET /users/{userId}/recordings
+    ListRecordings,
+    /// GET /accounts/{accountId}/recordings
+    ListAccountRecordings,
+    /// GET /users
+    ListUsers,
+    /// GET /meetings/{meetingId}/recordings
+    GetMeetingRecordings,
+    /// GET /meetings/{meetingId}/meeting_summary
+    GetMeetingSummary,
+    /// DELETE /meetings/{meetingId}/recordings[/{recordingId}]（ゴミ箱への移動・完全削除）
+    DeleteRecordings,
+    /// PUT /meetings/{meetingId}/recordings[/{recordingId}]/status
+    RecoverRecordings,
+}
+
+impl ApiEndpoint {
+    /// Zoom が割り当てているレート制限カテゴリ
+    pub fn category(self) -> RateLimitCategory {
+        match self {
+            Self::ListRecordings | Self::ListAccountRecordings | Self::ListUsers => {
+                RateLimitCategory::Medium
+            }
+            Self::GetMeetingRecordings | Self::DeleteRecordings | Self::RecoverRecordings => {
+                RateLimitCategory::Light
+            }
+            // AI要約の取得は1日あたりの上限の対象
+            Self::GetMeetingSummary => RateLimitCategory::Heavy,
+        }
+    }
+}
+
 /// Zoom録画ファイルタイプ
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum RecordingFileType {
@@ -129,7 +168,7 @@ impl RecordingFileType {
 }
 
 /// 録画ファイル情報
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RecordingFile {
     #[serde(default)]
     pub id: String,
@@ -165,13 +204,16 @@ impl RecordingFile {
 }
 
 /// 会議録画情報
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MeetingRecording {
     pub uuid: String,
     pub id: u64,
     #[serde(default)]
     pub account_id: String,
     pub host_id: String,
+    /// ホストのメールアドレス（アカウント単位の取得時にユーザー一覧から補完する）
+    #[serde(default)]
+    pub host_email: String,
     pub topic: String,
     #[serde(rename = "type", default)]
     pub meeting_type: u32,
@@ -250,78 +292,107 @@ pub struct SummaryDetail {
     pub summary: String,
 }
 
-/// 録画検索リクエスト
-#[derive(Debug, Clone)]
-pub struct RecordingSearchRequest {
-    pub user_id: Option<String>,
-    pub from: NaiveDate,
-    pub to: NaiveDate,
-    pub page_size: Option<u32>,
-    pub next_page_token: Option<String>,
-}
-
-/// Token Bucket レート制限実装
-#[derive(Debug)]
-struct TokenBucket {
-    capacity: u32,
-    tokens: f64,
-    refill_rate: f64,
-    last_refill: Instant,
+/// アカウントのユーザー
+///
+/// Zoom API `GET /v2/users` のレスポンスの1件を表す。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ZoomUser {
+    pub id: String,
+    #[serde(default)]
+    pub email: String,
+    #[serde(default)]
+    pub first_name: String,
+    #[serde(default)]
+    pub last_name: String,
+    #[serde(default)]
+    pub display_name: String,
+    #[serde(default)]
+    pub status: String,
 }
 
-impl TokenBucket {
-    fn new(requests_per_second: u32, burst_capacity: u32) -> Self {
-        Self {
-            capacity: burst_capacity,
-            tokens: burst_capacity as f64,
-            refill_rate: requests_per_second as f64,
-            last_refill: Instant::now(),
+impl ZoomUser {
+    /// 表示名（未設定の場合は氏名、さらに未設定の場合はメールアドレス）
+    pub fn label(&self) -> String {
+        if !self.display_name.is_empty() {
+            return self.display_name.clone();
         }
-    }
-
-    fn refill_tokens(&mut self) {
-        let now = Instant::now();
-        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
-
-        let tokens_to_add = elapsed * self.refill_rate;
-        self.tokens = (self.tokens + tokens_to_add).min(self.capacity as f64);
-        self.last_refill = now;
-    }
-
-    fn try_consume_token(&mut self) -> bool {
-        self.refill_tokens();
-
-        if self.tokens >= 1.0 {
-            self.tokens -= 1.0;
-            true
+        let name = format!("{} {}", self.first_name, self.last_name);
+        let name = name.trim();
+        if name.is_empty() {
+            self.email.clone()
         } else {
-            false
+            name.to_string()
         }
     }
+}
 
-    fn time_until_next_token(&self) -> Duration {
-        if self.tokens >= 1.0 {
-            Duration::ZERO
-        } else {
-            let tokens_needed = 1.0 - self.tokens;
-            let seconds_to_wait = tokens_needed / self.refill_rate;
-            Duration::from_secs_f64(seconds_to_wait)
+/// ユーザー一覧で取得するユーザーの状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UserStatus {
+    /// 有効なユーザー
+    Active,
+    /// 無効化されたユーザー（クラウド録画は残っている場合がある）
+    Inactive,
+    /// 招待に応答していないユーザー
+    Pending,
+}
+
+impl UserStatus {
+    /// Zoom API の status パラメータの値
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Active => "active",
+            Self::Inactive => "inactive",
+            Self::Pending => "pending",
         }
     }
 }
 
+/// ユーザー一覧レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserListResponse {
+    #[serde(default)]
+    pub page_count: u32,
+    #[serde(default)]
+    pub page_size: u32,
+    #[serde(default)]
+    pub total_records: u32,
+    #[serde(default)]
+    pub next_page_token: Option<String>,
+    #[serde(default)]
+    pub users: Vec<ZoomUser>,
+}
+
+/// 録画検索リクエスト
+#[derive(Debug, Clone)]
+pub struct RecordingSearchRequest {
+    pub user_id: Option<String>,
+    /// 指定時はアカウント全体の録画を取得する（user_id は使用しない、"me" は自分のアカウント）
+    pub account_id: Option<String>,
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub page_size: Option<u32>,
+    pub next_page_token: Option<String>,
+    /// ゴミ箱の録画を取得する（ユーザー単位の取得のみ対応）
+    pub trash: bool,
+}
+
 /// API統合コンポーネント
 pub struct ApiComponent {
     /// API設定
     config: ApiConfig,
     /// HTTPクライアント
     http_client: Client,
-    /// レート制限管理
-    rate_limiter: Arc<Mutex<TokenBucket>>,
+    /// レート制限管理（カテゴリ別）
+    rate_limiter: Arc<Mutex<ApiRateLimiter>>,
     /// 現在のトークン
     current_token: Arc<RwLock<Option<AuthToken>>>,
+    /// トークンの供給元（設定時は current_token より優先する）
+    token_provider: Option<Arc<dyn TokenProvider>>,
     /// API呼び出し統計
     metrics: Arc<Mutex<ApiCallMetrics>>,
+    /// 失敗時のリトライ判定
+    retry_policy: Arc<dyn RetryPolicy>,
 }
 
 /// API呼び出し統計
@@ -384,20 +455,35 @@ impl ApiComponent {
             .build()
             .expect("Failed to create HTTP client");
 
-        let rate_limiter = Arc::new(Mutex::new(TokenBucket::new(
-            config.rate_limit.requests_per_second,
-            config.rate_limit.burst_size,
+        let rate_limiter = Arc::new(Mutex::new(ApiRateLimiter::new(
+            &config.rate_limit.categories,
         )));
 
+        let retry_policy = Arc::new(ExponentialBackoff {
+            max_attempts: config.max_retries + 1,
+            initial_delay: Duration::from_secs(5),
+            ..ExponentialBackoff::default()
+        });
+
         Self {
             config,
             http_client,
             rate_limiter,
             current_token: Arc::new(RwLock::new(None)),
+            token_provider: None,
             metrics: Arc::new(Mutex::new(ApiCallMetrics::new())),
+            retry_policy,
         }
     }
 
+    /// リトライポリシーを設定
+    ///
+    /// # 事後条件
+    /// - 以降の API 呼び出しは指定したポリシーで再試行される
+    pub fn set_retry_policy(&mut self, policy: Arc<dyn RetryPolicy>) {
+        self.retry_policy = policy;
+    }
+
     /// 認証トークンを設定
     ///
     /// # 副作用
@@ -420,6 +506,32 @@ impl ApiComponent {
         log::info!("Auth token set for API component");
     }
 
+    /// 1日あたりの上限の消費状況の保存先を設定
+    ///
+    /// # 副作用
+    /// - 保存済みの消費状況の読み込み
+    ///
+    /// # 事後条件
+    /// - 同じ日（UTC）に保存された消費状況を引き継ぎ、以降の消費は保存先に記録される
+    pub fn set_quota_store(&mut self, path: impl Into<PathBuf>) {
+        let limiter = ApiRateLimiter::new(&self.config.rate_limit.categories).with_store(path);
+        self.rate_limiter = Arc::new(Mutex::new(limiter));
+    }
+
+    /// 1日あたりの上限の消費状況を取得
+    pub async fn daily_quota_usage(&self) -> DailyQuotaUsage {
+        self.rate_limiter.lock().await.daily_usage().clone()
+    }
+
+    /// トークンの供給元を設定
+    ///
+    /// # 事後条件
+    /// - 以降の API 呼び出しはリクエストごとに供給元からトークンを取得する
+    /// - 401 で拒否されたリクエストは更新したトークンで1回だけ再送される
+    pub fn set_token_provider(&mut self, provider: Arc<dyn TokenProvider>) {
+        self.token_provider = Some(provider);
+    }
+
     /// 録画データを検索
     ///
     /// # 副作用
@@ -445,16 +557,30 @@ impl ApiComponent {
                 None,
             ));
         }
-
-        // レート制限チェック
-        self.wait_for_rate_limit().await?;
-
-        // 認証トークン取得
-        let token = self.get_valid_token().await?;
+        if request.trash && request.account_id.is_some() {
+            return Err(AppError::validation(
+                "Trashed recordings can only be listed per user",
+                Some("account_id".to_string()),
+            ));
+        }
 
         // API URL構築
-        let user_id = request.user_id.as_deref().unwrap_or("me");
-        let url = format!("{}/users/{}/recordings", self.config.base_url, user_id);
+        let (endpoint, url) = match &request.account_id {
+            Some(account_id) => (
+                ApiEndpoint::ListAccountRecordings,
+                format!(
+                    "{}/accounts/{}/recordings",
+                    self.config.base_url, account_id
+                ),
+            ),
+            None => {
+                let user_id = request.user_id.as_deref().unwrap_or("me");
+                (
+                    ApiEndpoint::ListRecordings,
+                    format!("{}/users/{}/recordings", self.config.base_url, user_id),
+                )
+            }
+        };
 
         // クエリパラメータ構築
         let mut query_params = vec![
@@ -469,130 +595,20 @@ impl ApiComponent {
             query_params.push(("next_page_token", next_page_token.clone()));
         }
 
-        // HTTPリクエスト実行（サーバーエラー時のリトライ付き）
-        let max_retries = self.config.max_retries;
-        let mut last_error: Option<AppError> = None;
-
-        let response_text = 'retry: {
-            for attempt in 0..=max_retries {
-                let start_time = Instant::now();
-                let response = match self
-                    .http_client
-                    .get(&url)
-                    .bearer_auth(&token.access_token)
-                    .query(&query_params)
-                    .send()
-                    .await
-                {
-                    Ok(resp) => resp,
-                    Err(e) => {
-                        let duration = start_time.elapsed();
-                        self.record_api_call(duration, false).await;
-                        if attempt < max_retries {
-                            let wait = 5 * 2u64.pow(attempt);
-                            log::warn!(
-                                "API request failed (attempt {}/{}), retrying in {}s: {}",
-                                attempt + 1,
-                                max_retries + 1,
-                                wait,
-                                e
-                            );
-                            tokio::time::sleep(Duration::from_secs(wait)).await;
-                            continue;
-                        }
-                        return Err(AppError::network("Failed to send API request", Some(e)));
-                    }
-                };
-
-                let duration = start_time.elapsed();
-
-                // ステータスコードチェック
-                let status = response.status();
-                if !status.is_success() {
-                    self.record_api_call(duration, false).await;
-
-                    match status {
-                        StatusCode::UNAUTHORIZED => {
-                            return Err(AppError::authentication(
-                                "Unauthorized API access",
-                                None::<std::io::Error>,
-                            ));
-                        }
-                        StatusCode::TOO_MANY_REQUESTS => {
-                            self.record_rate_limit_error().await;
-                            let retry_after_secs = response
-                                .headers()
-                                .get(reqwest::header::RETRY_AFTER)
-                                .and_then(|v| v.to_str().ok())
-                                .and_then(|v| v.parse::<u64>().ok())
-                                .unwrap_or(10);
-                            if attempt < max_retries {
-                                log::warn!(
-                                    "Rate limited 429 (attempt {}/{}), retrying in {}s",
-                                    attempt + 1,
-                                    max_retries + 1,
-                                    retry_after_secs
-                                );
-                                tokio::time::sleep(Duration::from_secs(retry_after_secs)).await;
-                                continue;
-                            }
-                            return Err(AppError::rate_limit_with_retry(
-                                "API rate limit exceeded",
-                                Some(retry_after_secs),
-                            ));
-                        }
-                        StatusCode::NOT_FOUND => {
-                            return Err(AppError::not_found("User or resource not found"));
-                        }
-                        _ if status.as_u16() >= 500 && attempt < max_retries => {
-                            let wait = 10 * 2u64.pow(attempt);
-                            log::warn!(
-                                "Server error {} (attempt {}/{}), retrying in {}s",
-                                status,
-                                attempt + 1,
-                                max_retries + 1,
-                                wait
-                            );
-                            last_error =
-                                Some(AppError::external_service(format!("API error: {}", status)));
-                            tokio::time::sleep(Duration::from_secs(wait)).await;
-                            continue;
-                        }
-                        _ => {
-                            let error_body = response.text().await.unwrap_or_default();
-                            return Err(AppError::external_service(format!(
-                                "API error: {} - {}",
-                                status, error_body
-                            )));
-                        }
-                    }
-                }
-
-                self.record_api_call(duration, true).await;
-
-                // 成功: レスポンスボディ読み取り
-                let text = response
-                    .text()
-                    .await
-                    .map_err(|e| AppError::network("Failed to read API response body", Some(e)))?;
-                break 'retry text;
-            }
+        if request.trash {
+            query_params.push(("trash", "true".to_string()));
+            query_params.push(("trash_type", "meeting_recordings".to_string()));
+        }
 
-            // すべてのリトライが失敗した場合
-            return Err(last_error.unwrap_or_else(|| {
-                AppError::external_service("API request failed after all retries")
-            }));
-        };
+        // HTTPリクエスト実行（リトライポリシーに従って再試行）
+        let response_text = self.get_with_retry(endpoint, &url, &query_params).await?;
 
         // レスポンス解析（raw JSONをログ出力してからパース）
 
+        let redacted_response = redact_text(&response_text);
         log::debug!(
             "[DL-DIAG] API raw response (truncated): {}",
-            if response_text.len() > 2000 {
-                &response_text[..2000]
-            } else {
-                &response_text
-            }
+            truncate_text(&redacted_response, 2000)
         );
 
         // SUMMARYファイルのdownload_url有無を個別にINFOログ出力
@@ -685,6 +701,89 @@ impl ApiComponent {
         Ok(all_meetings)
     }
 
+    /// アカウントのユーザー一覧を1ページ取得する
+    ///
+    /// # 副作用
+    /// - HTTPリクエストの送信
+    /// - レート制限の消費
+    /// - メトリクスの記録
+    ///
+    /// # 事前条件
+    /// - 認証トークンが設定されている（user:read:admin スコープが必要）
+    ///
+    /// # 事後条件
+    /// - 成功時: status の状態のユーザーの1ページ分が返される
+    /// - 失敗時: 適切なエラーが返される
+    pub async fn list_users(
+        &self,
+        status: UserStatus,
+        next_page_token: Option<&str>,
+    ) -> AppResult<UserListResponse> {
+        let url = format!("{}/users", self.config.base_url);
+        let mut query_params = vec![
+            ("status", status.as_str().to_string()),
+            ("page_size", self.config.default_page_size.to_string()),
+        ];
+        if let Some(next_page_token) = next_page_token {
+            query_params.push(("next_page_token", next_page_token.to_string()));
+        }
+
+        let response_text = self
+            .get_with_retry(ApiEndpoint::ListUsers, &url, &query_params)
+            .await?;
+        serde_json::from_str(&response_text)
+            .map_err(|e| AppError::data_format("Failed to parse user list response", Some(e)))
+    }
+
+    /// アカウントのすべてのユーザーを取得（ページネーション対応）
+    ///
+    /// # 副作用
+    /// - 複数のHTTPリクエストの送信
+    /// - レート制限の消費
+    ///
+    /// # 事後条件
+    /// - 成功時: 有効なユーザーに続けて、無効化されたユーザーが返される（状態ごとに最大ページ数まで）
+    /// - 招待に応答していないユーザーは録画を持たないため含まれない
+    /// - 失敗時: 適切なエラーが返される
+    pub async fn get_all_users(&self) -> AppResult<Vec<ZoomUser>> {
+        let mut users = self.get_users_with_status(UserStatus::Active).await?;
+        users.extend(self.get_users_with_status(UserStatus::Inactive).await?);
+        Ok(users)
+    }
+
+    /// 指定した状態のユーザーをすべて取得（ページネーション対応）
+    async fn get_users_with_status(&self, status: UserStatus) -> AppResult<Vec<ZoomUser>> {
+        let mut users = Vec::new();
+        let mut next_page_token: Option<String> = None;
+        let mut total_pages = 0;
+
+        loop {
+            let response = self.list_users(status, next_page_token.as_deref()).await?;
+            users.extend(response.users);
+            total_pages += 1;
+
+            match response.next_page_token {
+                Some(next_token) if !next_token.is_empty() => {
+                    if total_pages >= self.config.max_pages {
+                        log::warn!("Reached maximum page limit ({})", self.config.max_pages);
+                        break;
+                    }
+                    next_page_token = Some(next_token);
+                    tokio::time::sleep(Duration::from_millis(self.config.page_interval_ms)).await;
+                }
+                _ => break,
+            }
+        }
+
+        log::info!(
+            "Retrieved {} {} users across {} pages",
+            users.len(),
+            status.as_str(),
+            total_pages
+        );
+        Ok(users)
+    }
+
     /// Meeting Summary APIからAI要約を取得する
     ///
     /// # 副作用
@@ -704,9 +803,9 @@ impl ApiComponent {
         &self,
         meeting_uuid: &str,
     ) -> AppResult<Option<MeetingSummaryResponse>> {
-        self.wait_for_rate_limit().await?;
+        self.wait_for_rate_limit(ApiEndpoint::GetMeetingSummary)
+            .await?;
 
-        let token = self.get_valid_token().await?;
         // UUIDに '/' が含まれる場合はダブルURLエンコードが必要（Zoom API仕様）
         let encoded_uuid = Self::double_encode_uuid(meeting_uuid);
         let url = format!(
@@ -718,12 +817,12 @@ impl ApiComponent {
 
         let start_time = Instant::now();
         let response = self
-            .http_client
-            .get(&url)
-            .bearer_auth(&token.access_token)
-            .send()
-            .await
-            .map_err(|e| AppError::network("Failed to fetch meeting summary", Some(e)))?;
+            .send_authorized(
+                ApiEndpoint::GetMeetingSummary,
+                || self.http_client.get(&url),
+                "Failed to fetch meeting summary",
+            )
+            .await?;
 
         let duration = start_time.elapsed();
         let status = response.status();
@@ -776,11 +875,7 @@ impl ApiComponent {
         log::debug!(
             "Meeting summary response for meeting_uuid={}: {}",
             meeting_uuid,
-            if response_text.len() > 500 {
-                &response_text[..500]
-            } else {
-                &response_text
-            }
+            truncate_text(&response_text, 500)
         );
 
         let summary: MeetingSummaryResponse =
@@ -792,6 +887,247 @@ impl ApiComponent {
         Ok(Some(summary))
     }
 
+    /// 会議1件分の録画情報を取得する
+    ///
+    /// 録画検索から時間が経ち download_url が失効した場合の再取得に使う。
+    ///
+    /// # 副作用
+    /// - HTTPリクエストの送信
+    /// - レート制限の消費
+    /// - メトリクスの記録
+    ///
+    /// # 事前条件
+    /// - meeting_uuid は有効なミーティングUUIDである
+    /// - 認証トークンが設定されている
+    ///
+    /// # 事後条件
+    /// - 成功時: 最新の download_url を含む MeetingRecording が返される
+    /// - 失敗時: 適切なエラーが返される
+    pub async fn get_meeting_recordings(&self, meeting_uuid: &str) -> AppResult<MeetingRecording> {
+        self.wait_for_rate_limit(ApiEndpoint::GetMeetingRecordings)
+            .await?;
+
+        let encoded_uuid = Self::double_encode_uuid(meeting_uuid);
+        let url = format!(
+            "{}/meetings/{}/recordings",
+            self.config.base_url, encoded_uuid
+        );
+
+        log::info!("Fetching recordings for meeting_uuid={}", meeting_uuid);
+
+        let start_time = Instant::now();
+        let response = self
+            .send_authorized(
+                ApiEndpoint::GetMeetingRecordings,
+                || self.http_client.get(&url),
+                "Failed to fetch meeting recordings",
+            )
+            .await?;
+
+        let duration = start_time.elapsed();
+        let status = response.status();
+
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            self.record_api_call(duration, false).await;
+            self.record_rate_limit_error().await;
+            let retry_after = response
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.parse::<u64>().ok());
+            return Err(AppError::rate_limit_with_retry(
+                "Meeting recordings API rate limit exceeded",
+                retry_after,
+            ));
+        }
+
+        if !status.is_success() {
+            self.record_api_call(duration, false).await;
+            let error_body = response.text().await.unwrap_or_default();
+            return Err(AppError::external_service(format!(
+                "Meeting recordings API error: {} - {}",
+                status, error_body
+            )));
+        }
+
+        let meeting: MeetingRecording = response.json().await.map_err(|e| {
+            AppError::data_format("Failed to parse meeting recordings response", Some(e))
+        })?;
+
+        self.record_api_call(duration, true).await;
+        Ok(meeting)
+    }
+
+    /// クラウド録画をゴミ箱へ移動する（`action=trash`、30日間は Zoom 上で復元可能）
+    ///
+    /// # 副作用
+    /// - HTTPリクエストの送信（クラウド上の録画の状態変更）
+    /// - レート制限の消費
+    /// - メトリクスの記録
+    ///
+    /// # 事前条件
+    /// - 呼び出し側でローカルのファイルの検証を済ませている
+    /// - 認証トークンに録画の書き込み権限がある
+    ///
+    /// # 事後条件
+    /// - recording_id 指定時はその録画ファイルのみ、未指定時はミーティングの全録画が移動される
+    /// - 失敗時: 適切なエラーが返される（404 は not_found）
+    pub async fn trash_recordings(
+        &self,
+        meeting_uuid: &str,
+        recording_id: Option<&str>,
+    ) -> AppResult<()> {
+        log::info!(
+            "Moving recordings to trash: meeting_uuid={} recording_id={:?}",
+            meeting_uuid,
+            recording_id
+        );
+        let url = self.recordings_url(meeting_uuid, recording_id, "");
+        self.change_recordings(
+            ApiEndpoint::DeleteRecordings,
+            || self.http_client.delete(&url).query(&[("action", "trash")]),
+            "move recordings to trash",
+        )
+        .await
+    }
+
+    /// ゴミ箱の録画を完全に削除する（`action=delete`、元に戻せない）
+    ///
+    /// # 副作用
+    /// - HTTPリクエストの送信（クラウド上の録画の削除）
+    /// - レート制限の消費
+    /// - メトリクスの記録
+    ///
+    /// # 事前条件
+    /// - 呼び出し側で利用者の確認を済ませている
+    /// - 認証トークンに録画の書き込み権限がある
+    ///
+    /// # 事後条件
+    /// - recording_id 指定時はその録画ファイルのみ、未指定時はミーティングの全録画が削除される
+    /// - 失敗時: 適切なエラーが返される（404 は not_found）
+    pub async fn delete_recordings_permanently(
+        &self,
+        meeting_uuid: &str,
+        recording_id: Option<&str>,
+    ) -> AppResult<()> {
+        log::warn!(
+            "Permanently deleting recordings: meeting_uuid={} recording_id={:?}",
+            meeting_uuid,
+            recording_id
+        );
+        let url = self.recordings_url(meeting_uuid, recording_id, "");
+        self.change_recordings(
+            ApiEndpoint::DeleteRecordings,
+            || self.http_client.delete(&url).query(&[("action", "delete")]),
+            "delete recordings",
+        )
+        .await
+    }
+
+    /// ゴミ箱の録画を復元する（`action=recover`）
+    ///
+    /// # 副作用
+    /// - HTTPリクエストの送信（クラウド上の録画の状態変更）
+    /// - レート制限の消費
+    /// - メトリクスの記録
+    ///
+    /// # 事前条件
+    /// - 認証トークンに録画の書き込み権限がある
+    ///
+    /// # 事後条件
+    /// - recording_id 指定時はその録画ファイルのみ、未指定時はミーティングの全録画が復元される
+    /// - 失敗時: 適切なエラーが返される（404 は not_found）
+    pub async fn recover_recordings(
+        &self,
+        meeting_uuid: &str,
+        recording_id: Option<&str>,
+    ) -> AppResult<()> {
+        log::info!(
+            "Recovering recordings from trash: meeting_uuid={} recording_id={:?}",
+            meeting_uuid,
+            recording_id
+        );
+        let url = self.recordings_url(meeting_uuid, recording_id, "/status");
+        let body = serde_json::json!({ "action": "recover" });
+        self.change_recordings(
+            ApiEndpoint::RecoverRecordings,
+            || self.http_client.put(&url).json(&body),
+            "recover recordings",
+        )
+        .await
+    }
+
+    /// ミーティングの録画（recording_id 指定時は録画ファイル）の URL
+    fn recordings_url(
+        &self,
+        meeting_uuid: &str,
+        recording_id: Option<&str>,
+        suffix: &str,
+    ) -> String {
+        let encoded_uuid = Self::double_encode_uuid(meeting_uuid);
+        match recording_id {
+            Some(recording_id) => format!(
+                "{}/meetings/{}/recordings/{}{}",
+                self.config.base_url, encoded_uuid, recording_id, suffix
+            ),
+            None => format!(
+                "{}/meetings/{}/recordings{}",
+                self.config.base_url, encoded_uuid, suffix
+            ),
+        }
+    }
+
+    /// 録画の状態を変更するリクエスト（ゴミ箱への移動・完全削除・復元）を1回送信する
+    ///
+    /// # 事後条件
+    /// - 2xx の場合のみ Ok
+    /// - 404 は not_found、429 は Retry-After 付きレート制限、その他はステータス付き API エラー
+    async fn change_recordings(
+        &self,
+        endpoint: ApiEndpoint,
+        request: impl Fn() -> RequestBuilder,
+        action: &str,
+    ) -> AppResult<()> {
+        self.wait_for_rate_limit(endpoint).await?;
+
+        let start_time = Instant::now();
+        let response = self
+            .send_authorized(endpoint, request, &format!("Failed to {}", action))
+            .await?;
+
+        let duration = start_time.elapsed();
+        let status = response.status();
+        if status.is_success() {
+            self.record_api_call(duration, true).await;
+            return Ok(());
+        }
+
+        self.record_api_call(duration, false).await;
+        Err(match status {
+            StatusCode::NOT_FOUND => AppError::not_found("Recording not found"),
+            StatusCode::TOO_MANY_REQUESTS => {
+                self.record_rate_limit_error().await;
+                let retry_after = response
+                    .headers()
+                    .get(reqwest::header::RETRY_AFTER)
+                    .and_then(|v| v.to_str().ok())
+                    .and_then(|v| v.parse::<u64>().ok());
+                AppError::rate_limit_with_retry(
+                    format!("Rate limit exceeded while trying to {}", action),
+                    retry_after,
+                )
+            }
+            _ => {
+                let error_body = response.text().await.unwrap_or_default();
+                AppError::api(
+                    status.as_u16(),
+                    format!("Failed to {}: {} - {}", action, status, error_body),
+                    None::<std::io::Error>,
+                )
+            }
+        })
+    }
+
     /// Zoom API用にUUIDをダブルURLエンコードする
     ///
     /// UUIDが '/' で始まる、または '//' を含む場合にダブルエンコードが必要（Zoom API仕様）
@@ -821,12 +1157,125 @@ impl ApiComponent {
         }
     }
 
-    /// 有効な認証トークンを取得
-    async fn get_valid_token(&self) -> AppResult<AuthToken> {
+    /// 一覧取得の GET リクエストをリトライポリシーに従って送信し、レスポンスボディを返す
+    ///
+    /// # 副作用
+    /// - レート制限の待機と消費（送信ごと）
+    async fn get_with_retry(
+        &self,
+        endpoint: ApiEndpoint,
+        url: &str,
+        query_params: &[(&str, String)],
+    ) -> AppResult<String> {
+        let first_started_at = Instant::now();
+        let mut attempt = 0;
+
+        loop {
+            attempt += 1;
+            self.wait_for_rate_limit(endpoint).await?;
+            let error = match self.send_list_request(endpoint, url, query_params).await {
+                Ok(text) => return Ok(text),
+                Err(error) => error,
+            };
+
+            match self
+                .retry_policy
+                .decide(&error, attempt, first_started_at.elapsed())
+            {
+                RetryDecision::Retry(delay) => {
+                    log::warn!(
+                        "API request failed (attempt {}), retrying in {:.1}s: {}",
+                        attempt,
+                        delay.as_secs_f64(),
+                        error
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                RetryDecision::GiveUp => return Err(error),
+            }
+        }
+    }
+
+    /// 一覧取得リクエストを1回送信し、レスポンスボディを返す
+    ///
+    /// # 事後条件
+    /// - HTTPステータスはリトライ判定可能なエラー種別に変換される
+    ///   （401: 認証、404: not_found、429: Retry-After 付きレート制限、その他: ステータス付き API エラー）
+    async fn send_list_request(
+        &self,
+        endpoint: ApiEndpoint,
+        url: &str,
+        query_params: &[(&str, String)],
+    ) -> AppResult<String> {
+        let start_time = Instant::now();
+        let response = match self
+            .send_authorized(
+                endpoint,
+                || self.http_client.get(url).query(query_params),
+                "Failed to send API request",
+            )
+            .await
+        {
+            Ok(resp) => resp,
+            Err(e) => {
+                self.record_api_call(start_time.elapsed(), false).await;
+                return Err(e);
+            }
+        };
+
+        let duration = start_time.elapsed();
+        let status = response.status();
+        if !status.is_success() {
+            self.record_api_call(duration, false).await;
+
+            return Err(match status {
+                StatusCode::UNAUTHORIZED => {
+                    AppError::authentication("Unauthorized API access", None::<std::io::Error>)
+                }
+                StatusCode::TOO_MANY_REQUESTS => {
+                    self.record_rate_limit_error().await;
+                    let retry_after_secs = response
+                        .headers()
+                        .get(reqwest::header::RETRY_AFTER)
+                        .and_then(|v| v.to_str().ok())
+                        .and_then(|v| v.parse::<u64>().ok())
+                        .unwrap_or(10);
+                    AppError::rate_limit_with_retry(
+                        "API rate limit exceeded",
+                        Some(retry_after_secs),
+                    )
+                }
+                StatusCode::NOT_FOUND => AppError::not_found("User or resource not found"),
+                _ => {
+                    let error_body = response.text().await.unwrap_or_default();
+                    AppError::api(
+                        status.as_u16(),
+                        format!("API error: {} - {}", status, error_body),
+                        None::<std::io::Error>,
+                    )
+                }
+            });
+        }
+
+        self.record_api_call(duration, true).await;
+
+        // 成功: レスポンスボディ読み取り
+        response
+            .text()
+            .await
+            .map_err(|e| AppError::network("Failed to read API response body", Some(e)))
+    }
+
+    /// 有効なアクセストークンを取得
+    async fn get_valid_token(&self) -> AppResult<String> {
+        if let Some(provider) = &self.token_provider {
+            return provider.access_token().await;
+        }
+
         let token_guard = self.current_token.read().await;
 
         match token_guard.as_ref() {
-            Some(token) if token.is_valid() => Ok(token.clone()),
+            Some(token) if token.is_valid() => Ok(token.access_token.clone()),
             _ => Err(AppError::authentication(
                 "No valid auth token available",
                 None::<std::io::Error>,
@@ -834,22 +1283,83 @@ impl ApiComponent {
         }
     }
 
+    /// Bearer 認証を付けてリクエストを送信する
+    ///
+    /// # 事後条件
+    /// - 401 が返され、トークンの供給元が更新できた場合は新しいトークンで1回だけ再送する
+    /// - 送信失敗は context をメッセージとする AppError::Network
+    /// - レスポンスのレート制限ヘッダーはレート制限管理に反映される
+    async fn send_authorized(
+        &self,
+        endpoint: ApiEndpoint,
+        request: impl Fn() -> RequestBuilder,
+        context: &str,
+    ) -> AppResult<Response> {
+        let token = self.get_valid_token().await?;
+        let response = request()
+            .bearer_auth(&token)
+            .send()
+            .await
+            .map_err(|e| AppError::network(context, Some(e)))?;
+        self.observe_rate_limit(endpoint, &response).await;
+
+        let Some(provider) = &self.token_provider else {
+            return Ok(response);
+        };
+        if response.status() != StatusCode::UNAUTHORIZED {
+            return Ok(response);
+        }
+
+        let token = match provider.refresh_rejected(&token).await {
+            Ok(token) => token,
+            Err(e) => {
+                log::warn!("Could not refresh rejected access token: {}", e);
+                return Ok(response);
+            }
+        };
+        log::info!("API request was rejected (401), retrying with a refreshed token");
+        let response = request()
+            .bearer_auth(&token)
+            .send()
+            .await
+            .map_err(|e| AppError::network(context, Some(e)))?;
+        self.observe_rate_limit(endpoint, &response).await;
+        Ok(response)
+    }
+
+    /// レスポンスのレート制限ヘッダーを反映する
+    async fn observe_rate_limit(&self, endpoint: ApiEndpoint, response: &Response) {
+        let headers = RateLimitHeaders::parse(response.headers());
+        if headers == RateLimitHeaders::default() {
+            return;
+        }
+        let mut rate_limiter = self.rate_limiter.lock().await;
+        rate_limiter.observe(endpoint.category(), &headers, chrono::Utc::now());
+    }
+
     /// レート制限の待機
-    async fn wait_for_rate_limit(&self) -> AppResult<()> {
+    ///
+    /// # 事後条件
+    /// - エンドポイントのカテゴリの枠を取得できた場合は Ok
+    /// - 1日あたりの上限に達している場合は待機せず、リセットまでの秒数付きの RateLimit エラー
+    async fn wait_for_rate_limit(&self, endpoint: ApiEndpoint) -> AppResult<()> {
         let start_time = Instant::now();
+        let category = endpoint.category();
 
         loop {
-            {
+            let decision = {
                 let mut rate_limiter = self.rate_limiter.lock().await;
-                if rate_limiter.try_consume_token() {
-                    return Ok(());
+                rate_limiter.try_acquire(category, chrono::Utc::now())
+            };
+            let wait_time = match decision {
+                RateLimitDecision::Granted => return Ok(()),
+                RateLimitDecision::Wait(wait_time) => wait_time,
+                RateLimitDecision::QuotaExhausted { resets_in } => {
+                    return Err(AppError::rate_limit_with_retry(
+                        format!("Daily API quota for {} requests exhausted", category),
+                        Some(resets_in.as_secs()),
+                    ));
                 }
-            }
-
-            // 次のトークンまでの待機時間を計算
-            let wait_time = {
-                let rate_limiter = self.rate_limiter.lock().await;
-                rate_limiter.time_until_next_token()
             };
 
             // 最大待機時間チェック
@@ -931,7 +1441,7 @@ impl ComponentLifecycle for ApiComponent {
         // HTTPクライアントの状態確認
         // 設定の妥当性確認
         !self.config.base_url.is_empty()
-            && self.config.rate_limit.requests_per_second > 0
+            && self.config.rate_limit.categories.medium.requests_per_second > 0.0
             && self.config.default_page_size > 0
     }
 }
@@ -947,10 +1457,19 @@ impl Configurable<ApiConfig> for ApiComponent {
             .map_err(|e| AppError::configuration("Failed to create HTTP client", Some(e)))?;
 
         // レート制限の更新
-        let new_rate_limiter = TokenBucket::new(
-            self.config.rate_limit.requests_per_second,
-            self.config.rate_limit.burst_size,
-        );
+        let mut new_rate_limiter = ApiRateLimiter::new(&self.config.rate_limit.categories);
+        // 未保存の消費を書き込んでから新しいレート制限に引き継ぐ
+        let store = self
+            .rate_limiter
+            .try_lock()
+            .ok()
+            .and_then(|mut rate_limiter| {
+                rate_limiter.flush();
+                rate_limiter.store_path().map(PathBuf::from)
+            });
+        if let Some(store) = store {
+            new_rate_limiter = new_rate_limiter.with_store(store);
+        }
         self.rate_limiter = Arc::new(Mutex::new(new_rate_limiter));
 
         log::info!("ApiComponent configuration updated");
@@ -965,11 +1484,12 @@ impl Configurable<ApiConfig> for ApiComponent {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::components::rate_limit::TokenBucket;
     use chrono::NaiveDate;
 
     #[test]
     fn test_token_bucket() {
-        let mut bucket = TokenBucket::new(10, 20);
+        let mut bucket = TokenBucket::with_rate(10.0, 20.0);
 
         // 初期状態では20トークン利用可能
         for _ in 0..20 {
@@ -1001,10 +1521,12 @@ mod tests {
     fn test_recording_search_request_validation() {
         let valid_request = RecordingSearchRequest {
             user_id: Some("test_user".to_string()),
+            account_id: None,
             from: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
             to: NaiveDate::from_ymd_opt(2025, 1, 31).unwrap(),
             page_size: Some(30),
             next_page_token: None,
+            trash: false,
         };
 
         // 日付範囲が有効
@@ -1256,4 +1778,360 @@ mod tests {
         let encoded = ApiComponent::double_encode_uuid(uuid);
         assert!(!encoded.contains('/'));
     }
+
+    /// 拒否されると "fresh" に切り替わる供給元
+    struct RotatingToken(Mutex<String>);
+
+    #[async_trait]
+    impl TokenProvider for RotatingToken {
+        async fn access_token(&self) -> AppResult<String> {
+            Ok(self.0.lock().await.clone())
+        }
+
+        async fn refresh_rejected(&self, _rejected: &str) -> AppResult<String> {
+            let mut token = self.0.lock().await;
+            *token = "fresh".to_string();
+            Ok(token.clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rejected_request_is_reissued_with_refreshed_token() {
+        use wiremock::matchers::{header, method};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(header("Authorization", "Bearer fresh"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("{}"))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(401))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let mut api = ApiComponent::new(ApiConfig {
+            base_url: server.uri(),
+            ..ApiConfig::default()
+        });
+        api.set_token_provider(Arc::new(RotatingToken(Mutex::new("expired".into()))));
+
+        let summary = api.get_meeting_summary("meeting-1").await.unwrap();
+        assert!(summary.is_some());
+    }
+
+    #[test]
+    fn test_endpoints_map_to_zoom_rate_limit_categories() {
+        assert_eq!(
+            ApiEndpoint::ListRecordings.category(),
+            RateLimitCategory::Medium
+        );
+        assert_eq!(
+            ApiEndpoint::GetMeetingRecordings.category(),
+            RateLimitCategory::Light
+        );
+        assert!(ApiEndpoint::GetMeetingSummary.category().has_daily_quota());
+    }
+
+    #[tokio::test]
+    async fn test_exhausted_daily_quota_stops_requests_until_reset() {
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(wiremock::matchers::method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string("{}")
+                    .insert_header("X-RateLimit-Category", "Heavy")
+                    .insert_header("X-RateLimit-Type", "Daily-limit")
+                    .insert_header("X-RateLimit-Limit", "30000")
+                    .insert_header("X-RateLimit-Remaining", "0"),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let store = temp_dir.path().join("api_quota.json");
+        let mut api = ApiComponent::new(ApiConfig {
+            base_url: server.uri(),
+            ..ApiConfig::default()
+        });
+        api.set_token_provider(Arc::new(
+            crate::components::token_provider::StaticToken::new("token"),
+        ));
+        api.set_quota_store(&store);
+
+        assert!(api
+            .get_meeting_summary("meeting-1")
+            .await
+            .unwrap()
+            .is_some());
+        assert_eq!(api.daily_quota_usage().await.remaining(), 0);
+
+        // 同じ日に作り直したコンポーネントも上限に達した状態から始まり、送信しない
+        let mut next_run = ApiComponent::new(ApiConfig {
+            base_url: server.uri(),
+            ..ApiConfig::default()
+        });
+        next_run.set_quota_store(&store);
+        let error = next_run.get_meeting_summary("meeting-2").await.unwrap_err();
+        assert!(matches!(
+            error,
+            AppError::RateLimit {
+                retry_after: Some(_),
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_meeting_recordings_returns_fresh_download_urls() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/meetings/meeting-1/recordings"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "uuid": "meeting-1",
+                "id": 1,
+                "host_id": "host",
+                "topic": "Weekly",
+                "start_time": "2025-01-01T10:00:00Z",
+                "duration": 30,
+                "recording_files": [{
+                    "id": "file-1",
+                    "file_type": "MP4",
+                    "download_url": "https://zoom.example/rec/fresh"
+                }]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let mut api = ApiComponent::new(ApiConfig {
+            base_url: server.uri(),
+            ..ApiConfig::default()
+        });
+        api.set_token_provider(Arc::new(
+            crate::components::token_provider::StaticToken::new("token"),
+        ));
+
+        let meeting = api.get_meeting_recordings("meeting-1").await.unwrap();
+        assert_eq!(
+            meeting.recording_files[0].download_url,
+            "https://zoom.example/rec/fresh"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_account_recordings_and_paginated_users() {
+        use wiremock::matchers::{method, path, query_param, query_param_is_missing};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/accounts/me/recordings"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "from": "2025-01-01",
+                "to": "2025-01-31",
+                "page_size": 300,
+                "total_records": 1,
+                "meetings": [{
+                    "uuid": "meeting-1",
+                    "id": 1,
+                    "host_id": "u2",
+                    "topic": "Weekly",
+                    "start_time": "2025-01-01T10:00:00Z",
+                    "duration": 30
+                }]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/users"))
+            .and(query_param("status", "active"))
+            .and(query_param_is_missing("next_page_token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "page_size": 1,
+                "total_records": 2,
+                "next_page_token": "page-2",
+                "users": [{ "id": "u1", "email": "alice@example.com" }]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/users"))
+            .and(query_param("status", "active"))
+            .and(query_param("next_page_token", "page-2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "page_size": 1,
+                "total_records": 2,
+                "next_page_token": "",
+                "users": [{ "id": "u2", "email": "bob@example.com", "display_name": "Bob" }]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        // 無効化されたユーザーの録画も取得対象にする
+        Mock::given(method("GET"))
+            .and(path("/users"))
+            .and(query_param("status", "inactive"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "page_size": 300,
+                "total_records": 1,
+                "users": [{ "id": "u3", "email": "carol@example.com", "status": "inactive" }]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let mut api = ApiComponent::new(ApiConfig {
+            base_url: server.uri(),
+            page_interval_ms: 0,
+            ..ApiConfig::default()
+        });
+        api.set_token_provider(Arc::new(
+            crate::components::token_provider::StaticToken::new("token"),
+        ));
+
+        let meetings = api
+            .get_all_recordings(RecordingSearchRequest {
+                user_id: None,
+                account_id: Some("me".to_string()),
+                from: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+                to: NaiveDate::from_ymd_opt(2025, 1, 31).unwrap(),
+                page_size: None,
+                next_page_token: None,
+                trash: false,
+            })
+            .await
+            .unwrap();
+        assert_eq!(meetings.len(), 1);
+        assert_eq!(meetings[0].host_id, "u2");
+
+        let users = api.get_all_users().await.unwrap();
+        let labels: Vec<_> = users.iter().map(ZoomUser::label).collect();
+        assert_eq!(
+            labels,
+            vec!["alice@example.com", "Bob", "carol@example.com"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_trash_recordings_uses_trash_action() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("DELETE"))
+            .and(path("/meetings/meeting-1/recordings/file-1"))
+            .and(query_param("action", "trash"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/meetings/meeting-2/recordings"))
+            .and(query_param("action", "trash"))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let mut api = ApiComponent::new(ApiConfig {
+            base_url: server.uri(),
+            ..ApiConfig::default()
+        });
+        api.set_token_provider(Arc::new(
+            crate::components::token_provider::StaticToken::new("token"),
+        ));
+
+        api.trash_recordings("meeting-1", Some("file-1"))
+            .await
+            .unwrap();
+        assert!(api.trash_recordings("meeting-2", None).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_trashed_recordings_are_listed_recovered_and_deleted() {
+        use wiremock::matchers::{body_json, method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/users/me/recordings"))
+            .and(query_param("trash", "true"))
+            .and(query_param("trash_type", "meeting_recordings"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "from": "2025-01-01",
+                "to": "2025-01-31",
+                "page_size": 300,
+                "total_records": 1,
+                "meetings": [{
+                    "uuid": "meeting-1",
+                    "id": 1,
+                    "host_id": "me",
+                    "topic": "Weekly",
+                    "start_time": "2025-01-01T10:00:00Z",
+                    "duration": 30
+                }]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path("/meetings/meeting-1/recordings/file-1/status"))
+            .and(body_json(serde_json::json!({ "action": "recover" })))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/meetings/meeting-1/recordings"))
+            .and(query_param("action", "delete"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let mut api = ApiComponent::new(ApiConfig {
+            base_url: server.uri(),
+            ..ApiConfig::default()
+        });
+        api.set_token_provider(Arc::new(
+            crate::components::token_provider::StaticToken::new("token"),
+        ));
+
+        let request = RecordingSearchRequest {
+            user_id: None,
+            account_id: None,
+            from: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            to: NaiveDate::from_ymd_opt(2025, 1, 31).unwrap(),
+            page_size: None,
+            next_page_token: None,
+            trash: true,
+        };
+        let meetings = api.get_all_recordings(request.clone()).await.unwrap();
+        assert_eq!(meetings.len(), 1);
+        // アカウント全体のゴミ箱は一覧できない
+        let account_request = RecordingSearchRequest {
+            account_id: Some("me".to_string()),
+            ..request
+        };
+        assert!(api.search_recordings(account_request).await.is_err());
+
+        api.recover_recordings("meeting-1", Some("file-1"))
+            .await
+            .unwrap();
+        api.delete_recordings_permanently("meeting-1", None)
+            .await
+            .unwrap();
+    }
 }
@@ -0,0 +1,447 @@
+//! アーカイブ整合性コンポーネント
+//!
+//! # 責任
+//! - 保存フォルダーごとのアーカイブマニフェスト（manifest.json）へのミーティング単位の記録
+//! - マニフェストに基づくアーカイブの再ハッシュ検証（欠落・改変・余分なファイルの検出）
+
+use crate::components::api::{MeetingRecording, RecordingFile};
+use crate::components::manifest::sha256_file;
+use crate::errors::{AppError, AppResult};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+/// アーカイブマニフェストのファイル名（録画ファイルと同じフォルダーに作成）
+pub const ARCHIVE_MANIFEST_FILE_NAME: &str = "manifest.json";
+
+/// アーカイブマニフェスト形式のバージョン
+const ARCHIVE_MANIFEST_VERSION: u32 = 1;
+
+/// 検証対象外とする作業ファイルの拡張子
+const WORK_FILE_SUFFIXES: &[&str] = &[".part", ".part.segments", ".tmp"];
+
+/// アーカイブ内の1ファイルの記録
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArchivedFile {
+    /// フォルダー内のファイル名
+    pub file_name: String,
+    /// 取得元の安定ID（RecordingFile::stable_id）
+    pub stable_id: String,
+    /// ファイル種別（MP4, CHAT など）
+    pub file_type: String,
+    /// ファイルサイズ（バイト）
+    pub size: u64,
+    /// 内容の SHA-256（16進小文字）
+    pub sha256: String,
+    /// 録画開始日時（Zoom API の値）
+    #[serde(default)]
+    pub recording_start: String,
+    /// 録画終了日時（Zoom API の値）
+    #[serde(default)]
+    pub recording_end: String,
+    /// ダウンロード完了日時
+    pub downloaded_at: DateTime<Utc>,
+}
+
+/// ミーティング1件分の記録
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArchivedMeeting {
+    pub meeting_uuid: String,
+    pub topic: String,
+    /// ミーティング開始日時（Zoom API の値）
+    pub start_time: String,
+    /// stable_id → ファイルの記録
+    pub files: BTreeMap<String, ArchivedFile>,
+}
+
+/// manifest.json の内容
+#[derive(Debug, Serialize, Deserialize)]
+struct ArchiveManifestFile {
+    version: u32,
+    meetings: BTreeMap<String, ArchivedMeeting>,
+}
+
+/// 保存フォルダー単位のアーカイブマニフェスト
+///
+/// 録画は日付フォルダーに保存されるため、1つの manifest.json にそのフォルダーの
+/// ミーティングをミーティングUUIDごとにまとめて記録する。
+#[derive(Debug)]
+pub struct ArchiveManifest {
+    /// manifest.json を置くフォルダー
+    directory: PathBuf,
+    /// ミーティングUUID → 記録
+    meetings: BTreeMap<String, ArchivedMeeting>,
+}
+
+impl ArchiveManifest {
+    /// フォルダーのマニフェストファイルパスを取得
+    pub fn manifest_path(directory: impl AsRef<Path>) -> PathBuf {
+        directory.as_ref().join(ARCHIVE_MANIFEST_FILE_NAME)
+    }
+
+    /// フォルダーのマニフェストを開く
+    ///
+    /// # 副作用
+    /// - ファイルシステムからの読み込み
+    ///
+    /// # 事後条件
+    /// - ファイルが存在しない場合は空のマニフェストが返される
+    /// - ファイルが破損している場合は data_integrity エラーが返される
+    pub fn open(directory: impl AsRef<Path>) -> AppResult<Self> {
+        let directory = directory.as_ref().to_path_buf();
+        let path = Self::manifest_path(&directory);
+
+        if !path.exists() {
+            return Ok(Self {
+                directory,
+                meetings: BTreeMap::new(),
+            });
+        }
+
+        let content = std::fs::read_to_string(&path)
+            .map_err(|e| AppError::file_system("Failed to read archive manifest", Some(e)))?;
+        let file: ArchiveManifestFile = serde_json::from_str(&content).map_err(|e| {
+            AppError::data_integrity(format!(
+                "Archive manifest is corrupted ({}): {}",
+                path.display(),
+                e
+            ))
+        })?;
+
+        Ok(Self {
+            directory,
+            meetings: file.meetings,
+        })
+    }
+
+    /// ミーティングの記録を取得
+    pub fn meeting(&self, meeting_uuid: &str) -> Option<&ArchivedMeeting> {
+        self.meetings.get(meeting_uuid)
+    }
+
+    /// 全ファイルの記録を取得
+    pub fn files(&self) -> impl Iterator<Item = &ArchivedFile> {
+        self.meetings
+            .values()
+            .flat_map(|meeting| meeting.files.values())
+    }
+
+    /// ダウンロードしたファイルを記録する
+    ///
+    /// # 副作用
+    /// - manifest.json への書き込み
+    ///
+    /// # 事前条件
+    /// - file_name のファイルがこのフォルダー内に存在する
+    /// - sha256 はダウンロード時に計算したファイル内容のハッシュである
+    ///
+    /// # 事後条件
+    /// - 同じミーティング・stable_id の記録は上書きされる
+    pub fn record(
+        &mut self,
+        meeting: &MeetingRecording,
+        file: &RecordingFile,
+        file_name: &str,
+        sha256: String,
+    ) -> AppResult<ArchivedFile> {
+        let size = std::fs::metadata(self.directory.join(file_name))
+            .map_err(|e| AppError::file_system("Failed to read downloaded file", Some(e)))?
+            .len();
+        let entry = ArchivedFile {
+            file_name: file_name.to_string(),
+            stable_id: file.stable_id(),
+            file_type: file.file_type.to_string(),
+            size,
+            sha256,
+            recording_start: file.recording_start.clone(),
+            recording_end: file.recording_end.clone(),
+            downloaded_at: Utc::now(),
+        };
+
+        self.meetings
+            .entry(meeting.uuid.clone())
+            .or_insert_with(|| ArchivedMeeting {
+                meeting_uuid: meeting.uuid.clone(),
+                topic: meeting.topic.clone(),
+                start_time: meeting.start_time.clone(),
+                files: BTreeMap::new(),
+            })
+            .files
+            .insert(entry.stable_id.clone(), entry.clone());
+        self.save()?;
+        Ok(entry)
+    }
+
+    /// マニフェストをファイルへ保存する（一時ファイル経由で置き換え）
+    fn save(&self) -> AppResult<()> {
+        let file = ArchiveManifestFile {
+            version: ARCHIVE_MANIFEST_VERSION,
+            meetings: self.meetings.clone(),
+        };
+        let content = serde_json::to_string_pretty(&file).map_err(|e| {
+            AppError::data_integrity(format!("Failed to serialize archive manifest: {}", e))
+        })?;
+
+        let path = Self::manifest_path(&self.directory);
+        let mut tmp_path = path.clone().into_os_string();
+        tmp_path.push(".tmp");
+        let tmp_path = PathBuf::from(tmp_path);
+
+        std::fs::write(&tmp_path, content)
+            .map_err(|e| AppError::file_system("Failed to write archive manifest", Some(e)))?;
+        std::fs::rename(&tmp_path, &path)
+            .map_err(|e| AppError::file_system("Failed to replace archive manifest", Some(e)))?;
+        Ok(())
+    }
+}
+
+/// 記録と内容が一致しないファイル
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModifiedFile {
+    /// 出力ディレクトリからの相対パス
+    pub path: PathBuf,
+    pub reason: String,
+}
+
+/// アーカイブ検証の結果（パスはすべて出力ディレクトリからの相対パス）
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VerifyReport {
+    /// 記録どおりのファイル
+    pub verified: Vec<PathBuf>,
+    /// 記録にあるが存在しないファイル
+    pub missing: Vec<PathBuf>,
+    /// サイズまたはハッシュが記録と異なるファイル
+    pub modified: Vec<ModifiedFile>,
+    /// マニフェストのあるフォルダーにあるが記録にないファイル
+    pub extra: Vec<PathBuf>,
+}
+
+impl VerifyReport {
+    /// 問題が見つからなかったか
+    pub fn is_clean(&self) -> bool {
+        self.missing.is_empty() && self.modified.is_empty() && self.extra.is_empty()
+    }
+
+    /// 1行の要約
+    pub fn summary(&self) -> String {
+        format!(
+            "{} verified, {} missing, {} modified, {} extra",
+            self.verified.len(),
+            self.missing.len(),
+            self.modified.len(),
+            self.extra.len()
+        )
+    }
+}
+
+/// 出力ディレクトリ配下のアーカイブを検証する
+///
+/// # 副作用
+/// - 記録された全ファイルの読み込み（SHA-256 の再計算）
+///
+/// # 事後条件
+/// - manifest.json のある各フォルダーについて、記録されたファイルの欠落・改変と記録にないファイルを報告する
+/// - manifest.json のないフォルダーは検証対象外
+/// - 作業ファイル（.part など）は記録にないファイルとして扱わない
+/// - 破損したマニフェストがある場合は data_integrity エラー
+pub fn verify_archive(output_dir: impl AsRef<Path>) -> AppResult<VerifyReport> {
+    let output_dir = output_dir.as_ref();
+    let mut report = VerifyReport::default();
+    let mut directories = Vec::new();
+    collect_manifest_directories(output_dir, &mut directories)?;
+
+    for directory in directories {
+        let manifest = ArchiveManifest::open(&directory)?;
+        let relative_dir = directory.strip_prefix(output_dir).unwrap_or(&directory);
+        let mut recorded = BTreeSet::new();
+
+        for entry in manifest.files() {
+            recorded.insert(entry.file_name.clone());
+            let relative_path = relative_dir.join(&entry.file_name);
+            let full_path = directory.join(&entry.file_name);
+
+            let size = match std::fs::metadata(&full_path) {
+                Ok(metadata) if metadata.is_file() => metadata.len(),
+                _ => {
+                    report.missing.push(relative_path);
+                    continue;
+                }
+            };
+            if size != entry.size {
+                report.modified.push(ModifiedFile {
+                    path: relative_path,
+                    reason: format!("size changed: recorded {}, found {}", entry.size, size),
+                });
+                continue;
+            }
+            let sha256 = sha256_file(&full_path)?;
+            if sha256 != entry.sha256 {
+                report.modified.push(ModifiedFile {
+                    path: relative_path,
+                    reason: "SHA-256 does not match".to_string(),
+                });
+                continue;
+            }
+            report.verified.push(relative_path);
+        }
+
+        for name in file_names(&directory)? {
+            let is_work_file = name == ARCHIVE_MANIFEST_FILE_NAME
+                || WORK_FILE_SUFFIXES
+                    .iter()
+                    .any(|suffix| name.ends_with(suffix));
+            if !is_work_file && !recorded.contains(&name) {
+                report.extra.push(relative_dir.join(name));
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+/// manifest.json を含むフォルダーを再帰的に集める（パス順）
+fn collect_manifest_directories(directory: &Path, found: &mut Vec<PathBuf>) -> AppResult<()> {
+    if ArchiveManifest::manifest_path(directory).is_file() {
+        found.push(directory.to_path_buf());
+    }
+
+    let mut subdirectories = Vec::new();
+    for entry in std::fs::read_dir(directory)
+        .map_err(|e| AppError::file_system("Failed to read archive directory", Some(e)))?
+    {
+        let entry = entry
+            .map_err(|e| AppError::file_system("Failed to read archive directory", Some(e)))?;
+        if entry.file_type().is_ok_and(|file_type| file_type.is_dir()) {
+            subdirectories.push(entry.path());
+        }
+    }
+    subdirectories.sort();
+    for subdirectory in subdirectories {
+        collect_manifest_directories(&subdirectory, found)?;
+    }
+    Ok(())
+}
+
+/// フォルダー直下のファイル名（名前順）
+fn file_names(directory: &Path) -> AppResult<Vec<String>> {
+    let mut names = Vec::new();
+    for entry in std::fs::read_dir(directory)
+        .map_err(|e| AppError::file_system("Failed to read archive directory", Some(e)))?
+    {
+        let entry = entry
+            .map_err(|e| AppError::file_system("Failed to read archive directory", Some(e)))?;
+        if entry.file_type().is_ok_and(|file_type| file_type.is_file()) {
+            names.push(entry.file_name().to_string_lossy().into_owned());
+        }
+    }
+    names.sort();
+    Ok(names)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::api::RecordingFileType;
+    use tempfile::tempdir;
+
+    fn meeting() -> MeetingRecording {
+        serde_json::from_value(serde_json::json!({
+            "uuid": "meeting-uuid",
+            "id": 1,
+            "host_id": "host",
+            "topic": "Weekly",
+            "start_time": "2025-01-01T10:00:00Z",
+            "duration": 60,
+            "recording_files": []
+        }))
+        .unwrap()
+    }
+
+    fn recording_file(id: &str, file_type: RecordingFileType) -> RecordingFile {
+        RecordingFile {
+            id: id.to_string(),
+            meeting_id: String::new(),
+            recording_start: "2025-01-01T10:00:00Z".to_string(),
+            recording_end: "2025-01-01T11:00:00Z".to_string(),
+            file_type,
+            file_extension: String::new(),
+            file_size: 0,
+            play_url: None,
+            download_url: String::new(),
+            status: String::new(),
+            recording_type: String::new(),
+        }
+    }
+
+    fn record(directory: &Path, id: &str, name: &str, content: &[u8]) {
+        let path = directory.join(name);
+        std::fs::write(&path, content).unwrap();
+        ArchiveManifest::open(directory)
+            .unwrap()
+            .record(
+                &meeting(),
+                &recording_file(id, RecordingFileType::MP4),
+                name,
+                sha256_file(&path).unwrap(),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_record_groups_files_by_meeting() {
+        let temp_dir = tempdir().unwrap();
+        record(temp_dir.path(), "f1", "video.mp4", b"video");
+        record(temp_dir.path(), "f2", "audio.m4a", b"audio");
+
+        let manifest = ArchiveManifest::open(temp_dir.path()).unwrap();
+        let meeting = manifest.meeting("meeting-uuid").unwrap();
+        assert_eq!(meeting.topic, "Weekly");
+        assert_eq!(meeting.files.len(), 2);
+        let video = &meeting.files["f1"];
+        assert_eq!(video.file_name, "video.mp4");
+        assert_eq!(video.size, 5);
+        assert_eq!(video.recording_end, "2025-01-01T11:00:00Z");
+    }
+
+    #[test]
+    fn test_verify_reports_missing_modified_and_extra_files() {
+        let temp_dir = tempdir().unwrap();
+        let day = temp_dir.path().join("2025-01-01");
+        std::fs::create_dir_all(&day).unwrap();
+        record(&day, "f1", "intact.mp4", b"intact");
+        record(&day, "f2", "missing.mp4", b"missing");
+        record(&day, "f3", "resized.mp4", b"resized");
+        record(&day, "f4", "tampered.mp4", b"tampered");
+
+        std::fs::remove_file(day.join("missing.mp4")).unwrap();
+        std::fs::write(day.join("resized.mp4"), b"resized!").unwrap();
+        std::fs::write(day.join("tampered.mp4"), b"TAMPERED").unwrap();
+        std::fs::write(day.join("notes.txt"), b"extra").unwrap();
+        std::fs::write(day.join("next.mp4.part"), b"partial").unwrap();
+
+        let report = verify_archive(temp_dir.path()).unwrap();
+        assert!(!report.is_clean());
+        assert_eq!(
+            report.verified,
+            vec![PathBuf::from("2025-01-01/intact.mp4")]
+        );
+        assert_eq!(
+            report.missing,
+            vec![PathBuf::from("2025-01-01/missing.mp4")]
+        );
+        let modified: Vec<_> = report.modified.iter().map(|m| m.path.clone()).collect();
+        assert_eq!(
+            modified,
+            vec![
+                PathBuf::from("2025-01-01/resized.mp4"),
+                PathBuf::from("2025-01-01/tampered.mp4")
+            ]
+        );
+        assert_eq!(report.extra, vec![PathBuf::from("2025-01-01/notes.txt")]);
+        assert_eq!(
+            report.summary(),
+            "1 verified, 1 missing, 2 modified, 1 extra"
+        );
+    }
+}
@@ -6,7 +6,7 @@
 //! - トークンの更新・無効化
 //! - 認証状態の管理
 
-use crate::components::config::OAuthConfig;
+use crate::components::config::{EndpointProfile, OAuthConfig};
 use crate::components::crypto::{CryptoComponent, SecretData};
 use crate::components::{ComponentLifecycle, Configurable};
 use crate::errors::{AppError, AppResult};
@@ -148,10 +148,44 @@ impl AuthFlowState {
     }
 }
 
+/// エンドポイント設定から OAuth クライアントを作成する
+///
+/// # 事前条件
+/// - endpoints の認可URL・トークンURLが URL として解釈できる
+///
+/// # 事後条件
+/// - 成功時: endpoints の認可URL・トークンURLを使う OAuth クライアントが返される
+/// - 失敗時: configuration エラーが返される
+pub(crate) fn build_oauth_client(
+    client_id: &str,
+    client_secret: &str,
+    redirect_uri: &str,
+    endpoints: &EndpointProfile,
+) -> AppResult<BasicClient> {
+    let auth_url = AuthUrl::new(endpoints.auth_url())
+        .map_err(|e| AppError::configuration("Invalid auth URL", Some(e)))?;
+
+    let token_url = TokenUrl::new(endpoints.token_url())
+        .map_err(|e| AppError::configuration("Invalid token URL", Some(e)))?;
+
+    let redirect_url = RedirectUrl::new(redirect_uri.to_string())
+        .map_err(|e| AppError::configuration("Invalid redirect URI", Some(e)))?;
+
+    Ok(BasicClient::new(
+        ClientId::new(client_id.to_string()),
+        Some(ClientSecret::new(client_secret.to_string())),
+        auth_url,
+        Some(token_url),
+    )
+    .set_redirect_uri(redirect_url))
+}
+
 /// 認証コンポーネント
 pub struct AuthComponent {
     /// OAuth設定
     config: OAuthConfig,
+    /// 接続先のエンドポイント
+    endpoints: EndpointProfile,
     /// OAuth クライアント
     oauth_client: Option<BasicClient>,
     /// 現在のトークン（暗号化して保存）
@@ -159,7 +193,6 @@ pub struct AuthComponent {
     /// 進行中の認証フロー
     pending_flows: HashMap<String, AuthFlowState>,
     /// HTTPクライアント
-    #[allow(dead_code)]
     http_client: reqwest::Client,
     /// 暗号化コンポーネント
     crypto: CryptoComponent,
@@ -177,6 +210,7 @@ impl AuthComponent {
     pub fn new(config: OAuthConfig) -> Self {
         Self {
             config,
+            endpoints: EndpointProfile::default(),
             oauth_client: None,
             current_token: None,
             pending_flows: HashMap::new(),
@@ -194,29 +228,39 @@ impl AuthComponent {
     /// - OAuth クライアントが初期化される
     /// - 失敗時は適切なエラーが返される
     fn initialize_oauth_client(&mut self) -> AppResult<()> {
-        let auth_url = AuthUrl::new("https://zoom.us/oauth/authorize".to_string())
-            .map_err(|e| AppError::configuration("Invalid auth URL", Some(e)))?;
-
-        let token_url = TokenUrl::new("https://zoom.us/oauth/token".to_string())
-            .map_err(|e| AppError::configuration("Invalid token URL", Some(e)))?;
-
-        let redirect_url = RedirectUrl::new(self.config.redirect_uri.clone())
-            .map_err(|e| AppError::configuration("Invalid redirect URI", Some(e)))?;
-
-        self.oauth_client = Some(
-            BasicClient::new(
-                ClientId::new(self.config.client_id.clone()),
-                Some(ClientSecret::new(self.config.client_secret.clone())),
-                auth_url,
-                Some(token_url),
-            )
-            .set_redirect_uri(redirect_url),
-        );
+        self.oauth_client = Some(build_oauth_client(
+            &self.config.client_id,
+            &self.config.client_secret,
+            &self.config.redirect_uri,
+            &self.endpoints,
+        )?);
 
         log::info!("OAuth client initialized successfully");
         Ok(())
     }
 
+    /// 接続先のエンドポイントを設定する
+    ///
+    /// # 副作用
+    /// - OAuth クライアントの再初期化（初期化済みの場合）
+    ///
+    /// # 事後条件
+    /// - 成功時: 以降の認証・トークン更新・取り消しは endpoints の URL に対して行われる
+    /// - 失敗時: validation エラーが返され、設定は変更されない
+    pub fn set_endpoints(&mut self, endpoints: EndpointProfile) -> AppResult<()> {
+        endpoints.validate()?;
+        self.endpoints = endpoints;
+        if self.oauth_client.is_some() {
+            self.initialize_oauth_client()?;
+        }
+        Ok(())
+    }
+
+    /// 接続先のエンドポイント
+    pub fn endpoints(&self) -> &EndpointProfile {
+        &self.endpoints
+    }
+
     /// 認証URLを生成する
     ///
     /// # 副作用
@@ -415,6 +459,65 @@ impl AuthComponent {
         self.current_token.as_ref()
     }
 
+    /// 外部の認証フローで取得したトークンを設定する
+    ///
+    /// # 副作用
+    /// - OAuth クライアントの初期化（未初期化の場合）
+    ///
+    /// # 事前条件
+    /// - token.access_token が空でない
+    ///
+    /// # 事後条件
+    /// - token が現在のトークンとなり、リフレッシュトークンがあれば自動更新の対象になる
+    pub fn set_token(&mut self, token: AuthToken) -> AppResult<()> {
+        assert!(
+            !token.access_token.is_empty(),
+            "access_token must not be empty"
+        );
+
+        if self.oauth_client.is_none() {
+            self.initialize_oauth_client()?;
+        }
+        self.current_token = Some(token);
+        Ok(())
+    }
+
+    /// 現在のトークンを取り消し、認証状態をクリアする
+    ///
+    /// # 副作用
+    /// - トークン取り消しURLへの HTTP リクエストの送信
+    /// - 内部状態のクリアと永続化されたトークンの削除
+    ///
+    /// # 事後条件
+    /// - 成功時: サーバー側でトークンが無効化され、認証状態がクリアされる
+    /// - 失敗時: network または authentication エラーが返され、認証状態は保持される
+    pub async fn revoke_token(&mut self) -> AppResult<()> {
+        let Some(token) = self.current_token.as_ref() else {
+            self.clear_auth_state();
+            return Ok(());
+        };
+
+        let response = self
+            .http_client
+            .post(self.endpoints.revoke_url())
+            .basic_auth(&self.config.client_id, Some(&self.config.client_secret))
+            .query(&[("token", token.access_token.as_str())])
+            .send()
+            .await
+            .map_err(|e| AppError::network("Token revocation request failed", Some(e)))?;
+
+        if !response.status().is_success() {
+            return Err(AppError::authentication(
+                format!("Token revocation failed: HTTP {}", response.status()),
+                None::<std::io::Error>,
+            ));
+        }
+
+        self.clear_auth_state();
+        log::info!("Token revoked successfully");
+        Ok(())
+    }
+
     /// 認証状態をクリアする
     ///
     /// # 副作用
@@ -785,4 +888,49 @@ mod tests {
         // 終了処理テスト
         assert!(auth_component.shutdown().await.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_custom_endpoints_are_used_for_auth_and_revocation() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/oauth/revoke"))
+            .and(query_param("token", "stub_token"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let mut auth_component = AuthComponent::new(OAuthConfig {
+            client_id: "test_client_id".to_string(),
+            client_secret: "test_client_secret".to_string(),
+            ..OAuthConfig::default()
+        });
+        assert!(auth_component
+            .set_endpoints(EndpointProfile {
+                preset: crate::components::config::EndpointPreset::Custom,
+                ..EndpointProfile::default()
+            })
+            .is_err());
+        auth_component
+            .set_endpoints(EndpointProfile::custom(&server.uri()))
+            .unwrap();
+
+        auth_component
+            .set_token(AuthToken {
+                access_token: "stub_token".to_string(),
+                token_type: "Bearer".to_string(),
+                expires_at: Utc::now() + Duration::hours(1),
+                refresh_token: None,
+                scopes: vec!["recording:read".to_string()],
+            })
+            .unwrap();
+        let (auth_url, _) = auth_component.generate_auth_url().unwrap();
+        assert!(auth_url.starts_with(&format!("{}/oauth/authorize", server.uri())));
+
+        auth_component.revoke_token().await.unwrap();
+        assert!(auth_component.get_current_token().is_none());
+    }
 }
@@ -0,0 +1,349 @@
+//! 適応的並列度コンポーネント
+//!
+//! # 責任
+//! - 同時実行数の上限管理（ワーカーの実行枠）
+//! - スループットとサーバーからの抑制シグナルに基づく AIMD 制御
+
+use crate::errors::AppError;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+/// 適応的並列度の設定
+#[derive(Debug, Clone, PartialEq)]
+pub struct AdaptiveConcurrency {
+    /// 同時実行数の下限
+    pub min_workers: usize,
+    /// 同時実行数の上限（起動するワーカー数）
+    pub max_workers: usize,
+    /// スループットを評価する間隔
+    pub sample_interval: Duration,
+    /// 増加を続けるのに必要なスループットの改善率（0.1 は 10%）
+    pub min_improvement: f64,
+}
+
+impl Default for AdaptiveConcurrency {
+    fn default() -> Self {
+        Self {
+            min_workers: 1,
+            max_workers: 6,
+            sample_interval: Duration::from_secs(5),
+            min_improvement: 0.1,
+        }
+    }
+}
+
+/// 同時実行数を変更した理由
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConcurrencyChangeReason {
+    /// ダウンロード開始時の初期値
+    Initial,
+    /// 実行枠を1つ増やしてスループットの変化を確認する
+    Probe,
+    /// 前回の増加でスループットが改善した
+    ThroughputImproved { bytes_per_sec: f64 },
+    /// サーバーからレート制限（429）を受けた
+    RateLimited,
+    /// 接続エラー（切断・タイムアウトなど）が発生した
+    ConnectionErrors,
+    /// 停滞検出により転送を中断した
+    Stalled,
+}
+
+impl std::fmt::Display for ConcurrencyChangeReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Initial => write!(f, "initial"),
+            Self::Probe => write!(f, "probing for more throughput"),
+            Self::ThroughputImproved { bytes_per_sec } => {
+                write!(f, "throughput improved to {:.0} B/s", bytes_per_sec)
+            }
+            Self::RateLimited => write!(f, "rate limited by server"),
+            Self::ConnectionErrors => write!(f, "connection errors"),
+            Self::Stalled => write!(f, "transfers stalled"),
+        }
+    }
+}
+
+/// 評価間隔中に観測した抑制シグナル
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CongestionSignals {
+    /// レート制限（429）の回数
+    pub rate_limited: usize,
+    /// 接続エラーの回数
+    pub connection_errors: usize,
+    /// 停滞により中断した転送の数
+    pub stalled: usize,
+}
+
+/// ワーカーから報告される抑制シグナルの集計
+#[derive(Debug, Default)]
+pub(crate) struct CongestionCounters {
+    rate_limited: AtomicUsize,
+    connection_errors: AtomicUsize,
+    stalled: AtomicUsize,
+}
+
+impl CongestionCounters {
+    /// 失敗したタスクのエラーを集計する（抑制シグナル以外は無視）
+    pub(crate) fn record(&self, error: &AppError) {
+        match error {
+            AppError::RateLimit { .. } => {
+                self.rate_limited.fetch_add(1, Ordering::SeqCst);
+            }
+            AppError::Network { .. } => {
+                self.connection_errors.fetch_add(1, Ordering::SeqCst);
+            }
+            AppError::Stalled { .. } => {
+                self.stalled.fetch_add(1, Ordering::SeqCst);
+            }
+            _ => {}
+        }
+    }
+
+    /// 前回の取得以降の集計を取り出してリセットする
+    pub(crate) fn take(&self) -> CongestionSignals {
+        CongestionSignals {
+            rate_limited: self.rate_limited.swap(0, Ordering::SeqCst),
+            connection_errors: self.connection_errors.swap(0, Ordering::SeqCst),
+            stalled: self.stalled.swap(0, Ordering::SeqCst),
+        }
+    }
+}
+
+/// ワーカーの実行枠（実行中タスク数を上限以下に保つ）
+#[derive(Debug)]
+pub(crate) struct ConcurrencyGate {
+    limit: AtomicUsize,
+    active: AtomicUsize,
+}
+
+impl ConcurrencyGate {
+    pub(crate) fn new(limit: usize) -> Self {
+        Self {
+            limit: AtomicUsize::new(limit),
+            active: AtomicUsize::new(0),
+        }
+    }
+
+    /// 実行枠を取得する（上限に達している場合は false）
+    pub(crate) fn try_acquire(&self) -> bool {
+        self.active
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |active| {
+                (active < self.limit.load(Ordering::SeqCst)).then_some(active + 1)
+            })
+            .is_ok()
+    }
+
+    /// 実行枠を返却する
+    pub(crate) fn release(&self) {
+        let _ = self
+            .active
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |active| {
+                active.checked_sub(1)
+            });
+    }
+
+    pub(crate) fn limit(&self) -> usize {
+        self.limit.load(Ordering::SeqCst)
+    }
+
+    /// 上限を変更する（実行中のタスクは中断せず、完了後の取り出しから反映される）
+    pub(crate) fn set_limit(&self, limit: usize) {
+        self.limit.store(limit, Ordering::SeqCst);
+    }
+
+    pub(crate) fn active(&self) -> usize {
+        self.active.load(Ordering::SeqCst)
+    }
+}
+
+/// AIMD（加算増加・乗算減少）による同時実行数の制御
+///
+/// # 制御規則
+/// - 抑制シグナル（429・接続エラー・停滞）があれば上限を半分にする（下限まで）
+/// - 実行枠がすべて使われ待機タスクがある間は1つずつ増やし、
+///   スループットが min_improvement 以上改善しなくなったら増加をやめる
+#[derive(Debug)]
+pub(crate) struct AimdController {
+    config: AdaptiveConcurrency,
+    limit: usize,
+    /// 直前の増加前後で比較するスループット（bytes/sec）
+    last_throughput: Option<f64>,
+    /// 増加しても改善しなくなった
+    plateaued: bool,
+}
+
+impl AimdController {
+    pub(crate) fn new(config: AdaptiveConcurrency, initial: usize) -> Self {
+        let limit = initial.clamp(config.min_workers.max(1), config.max_workers.max(1));
+        Self {
+            config,
+            limit,
+            last_throughput: None,
+            plateaued: false,
+        }
+    }
+
+    pub(crate) fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// 評価間隔ごとの観測結果から次の上限を決める
+    ///
+    /// # 事後条件
+    /// - 上限を変更した場合のみ Some((新しい上限, 理由)) を返す
+    /// - スループットが 0 の間隔だけでは下げず、停滞検出による中断（signals.stalled）を停滞とみなす
+    pub(crate) fn on_sample(
+        &mut self,
+        throughput: f64,
+        signals: CongestionSignals,
+        active: usize,
+        has_pending: bool,
+    ) -> Option<(usize, ConcurrencyChangeReason)> {
+        let congestion = if signals.rate_limited > 0 {
+            Some(ConcurrencyChangeReason::RateLimited)
+        } else if signals.connection_errors > 0 {
+            Some(ConcurrencyChangeReason::ConnectionErrors)
+        } else if signals.stalled > 0 {
+            Some(ConcurrencyChangeReason::Stalled)
+        } else {
+            None
+        };
+
+        if let Some(reason) = congestion {
+            self.last_throughput = None;
+            self.plateaued = false;
+            let decreased = (self.limit / 2).max(self.config.min_workers.max(1));
+            if decreased == self.limit {
+                return None;
+            }
+            self.limit = decreased;
+            return Some((decreased, reason));
+        }
+
+        // データが届かなかった間隔（ヘッダー待ち・バックオフ中・帯域制限など）は判断材料にしない
+        if throughput <= 0.0 {
+            return None;
+        }
+
+        // 実行枠に余裕がある・待機タスクがない場合は増やしても効果がない
+        let saturated = active >= self.limit && has_pending;
+        if !saturated || self.plateaued || self.limit >= self.config.max_workers {
+            return None;
+        }
+
+        let reason = match self.last_throughput {
+            None => ConcurrencyChangeReason::Probe,
+            Some(previous) if throughput >= previous * (1.0 + self.config.min_improvement) => {
+                ConcurrencyChangeReason::ThroughputImproved {
+                    bytes_per_sec: throughput,
+                }
+            }
+            Some(_) => {
+                self.plateaued = true;
+                self.last_throughput = Some(throughput);
+                return None;
+            }
+        };
+        self.last_throughput = Some(throughput);
+        self.limit += 1;
+        Some((self.limit, reason))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> AdaptiveConcurrency {
+        AdaptiveConcurrency {
+            min_workers: 1,
+            max_workers: 4,
+            ..AdaptiveConcurrency::default()
+        }
+    }
+
+    #[test]
+    fn test_gate_limits_active_tasks() {
+        let gate = ConcurrencyGate::new(2);
+        assert!(gate.try_acquire());
+        assert!(gate.try_acquire());
+        assert!(!gate.try_acquire());
+
+        gate.set_limit(1);
+        gate.release();
+        assert!(!gate.try_acquire());
+        gate.release();
+        assert_eq!(gate.active(), 0);
+        assert!(gate.try_acquire());
+        assert_eq!(gate.limit(), 1);
+    }
+
+    #[test]
+    fn test_controller_increases_while_throughput_improves() {
+        let mut controller = AimdController::new(config(), 1);
+        let quiet = CongestionSignals::default();
+
+        assert_eq!(
+            controller.on_sample(1000.0, quiet, 1, true),
+            Some((2, ConcurrencyChangeReason::Probe))
+        );
+        assert_eq!(
+            controller.on_sample(1800.0, quiet, 2, true),
+            Some((
+                3,
+                ConcurrencyChangeReason::ThroughputImproved {
+                    bytes_per_sec: 1800.0
+                }
+            ))
+        );
+        // 改善が 10% 未満なら増加をやめる
+        assert_eq!(controller.on_sample(1850.0, quiet, 3, true), None);
+        assert_eq!(controller.on_sample(2500.0, quiet, 3, true), None);
+        assert_eq!(controller.limit(), 3);
+    }
+
+    #[test]
+    fn test_controller_backs_off_on_pushback() {
+        let mut controller = AimdController::new(config(), 4);
+
+        let rate_limited = CongestionSignals {
+            rate_limited: 1,
+            ..CongestionSignals::default()
+        };
+        assert_eq!(
+            controller.on_sample(5000.0, rate_limited, 4, true),
+            Some((2, ConcurrencyChangeReason::RateLimited))
+        );
+        let stalled = CongestionSignals {
+            stalled: 1,
+            ..CongestionSignals::default()
+        };
+        assert_eq!(
+            controller.on_sample(0.0, stalled, 2, true),
+            Some((1, ConcurrencyChangeReason::Stalled))
+        );
+        // 下限より下げない
+        let errors = CongestionSignals {
+            connection_errors: 3,
+            ..CongestionSignals::default()
+        };
+        assert_eq!(controller.on_sample(0.0, errors, 1, true), None);
+        assert_eq!(controller.limit(), 1);
+    }
+
+    #[test]
+    fn test_controller_keeps_concurrency_while_throttled() {
+        let mut controller = AimdController::new(config(), 3);
+        let quiet = CongestionSignals::default();
+
+        // 帯域制限やヘッダー待ちでデータが届かない間隔があっても、停滞の報告がなければ下げない
+        for throughput in [0.0, 200.0, 0.0, 0.0, 150.0, 0.0] {
+            assert_eq!(controller.on_sample(throughput, quiet, 3, false), None);
+        }
+        assert_eq!(controller.limit(), 3);
+        // データが届かない間隔では増やしもしない
+        assert_eq!(controller.on_sample(0.0, quiet, 3, true), None);
+        assert_eq!(controller.limit(), 3);
+    }
+}
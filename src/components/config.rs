@@ -6,6 +6,12 @@
 //! - 設定変更の監視
 //! - デフォルト設定の提供
 
+use crate::components::api::{MeetingRecording, RecordingFile};
+use crate::components::concurrency::AdaptiveConcurrency;
+use crate::components::download::ConflictPolicy;
+use crate::components::rate_limit::BandwidthWindow;
+use crate::components::segmented::SegmentedDownload;
+use crate::components::watchdog::StallDetection;
 use crate::components::{ComponentLifecycle, Configurable};
 use crate::errors::{AppError, AppResult};
 use async_trait::async_trait;
@@ -45,6 +51,7 @@ impl Default for OAuthConfig {
             redirect_uri: "http://localhost:8080/callback".to_string(),
             scopes: vec![
                 "recording:read".to_string(),
+                "recording:write".to_string(),
                 "user:read".to_string(),
                 "meeting:read".to_string(),
             ],
@@ -91,13 +98,19 @@ pub struct AppConfig {
 
     /// API設定
     pub api: ApiSettings,
+
+    /// 接続先（OAuth・API）のエンドポイント
+    #[serde(default)]
+    pub endpoints: EndpointProfile,
+
+    /// ダウンロード動作設定
+    #[serde(default)]
+    pub download: DownloadSettings,
 }
 
 /// API設定
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApiSettings {
-    /// ベースURL
-    pub base_url: String,
     /// タイムアウト（秒）
     pub timeout_seconds: u64,
     /// リトライ回数
@@ -122,7 +135,6 @@ fn default_page_interval_ms() -> u64 {
 impl Default for ApiSettings {
     fn default() -> Self {
         Self {
-            base_url: "https://api.zoom.us/v2".to_string(),
             timeout_seconds: 30,
             max_retries: 3,
             default_page_size: 300,
@@ -132,6 +144,248 @@ impl Default for ApiSettings {
     }
 }
 
+/// エンドポイントのプリセット
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EndpointPreset {
+    /// zoom.us（商用環境）
+    #[default]
+    Zoom,
+    /// ZoomGov（zoomgov.com）
+    ZoomGov,
+    /// すべての URL を個別に指定（ローカルのスタブサーバー等）
+    Custom,
+}
+
+impl EndpointPreset {
+    /// プリセットの (認可URL, トークンURL, 取り消しURL, APIベースURL)
+    fn urls(self) -> Option<(&'static str, &'static str, &'static str, &'static str)> {
+        match self {
+            Self::Zoom => Some((
+                "https://zoom.us/oauth/authorize",
+                "https://zoom.us/oauth/token",
+                "https://zoom.us/oauth/revoke",
+                "https://api.zoom.us/v2",
+            )),
+            Self::ZoomGov => Some((
+                "https://zoomgov.com/oauth/authorize",
+                "https://zoomgov.com/oauth/token",
+                "https://zoomgov.com/oauth/revoke",
+                "https://api.zoomgov.com/v2",
+            )),
+            Self::Custom => None,
+        }
+    }
+}
+
+/// 接続先のエンドポイント設定
+///
+/// 個別の URL を指定した場合はプリセットの値より優先される。
+/// Custom プリセットではすべての URL の指定が必要。
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EndpointProfile {
+    pub preset: EndpointPreset,
+    /// OAuth 認可URL
+    pub auth_url: Option<String>,
+    /// OAuth トークンURL
+    pub token_url: Option<String>,
+    /// OAuth トークン取り消しURL
+    pub revoke_url: Option<String>,
+    /// API ベースURL（例: https://api.zoom.us/v2）
+    pub api_base_url: Option<String>,
+}
+
+impl EndpointProfile {
+    /// プリセットのエンドポイントを作成
+    pub fn preset(preset: EndpointPreset) -> Self {
+        Self {
+            preset,
+            ..Self::default()
+        }
+    }
+
+    /// すべての URL を指定したエンドポイントを作成
+    ///
+    /// # 事前条件
+    /// - base はスキームとホストを含む URL（末尾の / は不要）である
+    ///
+    /// # 事後条件
+    /// - base 配下の Zoom と同じパス構成を指すエンドポイントが返される
+    pub fn custom(base: &str) -> Self {
+        let base = base.trim_end_matches('/');
+        Self {
+            preset: EndpointPreset::Custom,
+            auth_url: Some(format!("{}/oauth/authorize", base)),
+            token_url: Some(format!("{}/oauth/token", base)),
+            revoke_url: Some(format!("{}/oauth/revoke", base)),
+            api_base_url: Some(format!("{}/v2", base)),
+        }
+    }
+
+    /// 認可URL
+    pub fn auth_url(&self) -> String {
+        self.resolve(&self.auth_url, |urls| urls.0)
+    }
+
+    /// トークンURL
+    pub fn token_url(&self) -> String {
+        self.resolve(&self.token_url, |urls| urls.1)
+    }
+
+    /// トークン取り消しURL
+    pub fn revoke_url(&self) -> String {
+        self.resolve(&self.revoke_url, |urls| urls.2)
+    }
+
+    /// API ベースURL（末尾の / を除く）
+    pub fn api_base_url(&self) -> String {
+        self.resolve(&self.api_base_url, |urls| urls.3)
+            .trim_end_matches('/')
+            .to_string()
+    }
+
+    /// エンドポイント設定を検証する
+    ///
+    /// # 事後条件
+    /// - Custom プリセットで URL が欠けている場合、または URL として解釈できない値がある場合は
+    ///   validation エラーが返される
+    pub fn validate(&self) -> AppResult<()> {
+        let fields = [
+            ("auth_url", &self.auth_url),
+            ("token_url", &self.token_url),
+            ("revoke_url", &self.revoke_url),
+            ("api_base_url", &self.api_base_url),
+        ];
+        for (field, value) in fields {
+            match value {
+                Some(url) => {
+                    reqwest::Url::parse(url).map_err(|e| {
+                        AppError::validation(
+                            format!("Invalid endpoint URL '{}': {}", url, e),
+                            Some(field.to_string()),
+                        )
+                    })?;
+                }
+                None if self.preset == EndpointPreset::Custom => {
+                    return Err(AppError::validation(
+                        "Custom endpoint profile requires every URL",
+                        Some(field.to_string()),
+                    ));
+                }
+                None => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn resolve(
+        &self,
+        value: &Option<String>,
+        pick: impl Fn((&'static str, &'static str, &'static str, &'static str)) -> &'static str,
+    ) -> String {
+        value
+            .clone()
+            .or_else(|| self.preset.urls().map(|urls| pick(urls).to_string()))
+            .unwrap_or_default()
+    }
+}
+
+/// ダウンロード動作設定
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DownloadSettings {
+    /// キャンセル時に不完全なファイル（.part）を保持するか
+    pub keep_partial_on_cancel: bool,
+    /// 全ダウンロード合計の帯域上限（bytes/sec、未指定は無制限）
+    pub max_bytes_per_sec: Option<u64>,
+    /// 出力先に同名ファイルが存在する場合の扱い
+    pub conflict_policy: ConflictPolicy,
+    /// 出力先に確保しておく空き容量（MB、下回るとダウンロードを一時停止、0 は監視しない）
+    pub min_free_space_mb: u64,
+    /// スループットとサーバーの抑制に応じて同時実行数を自動調整するか
+    pub adaptive_concurrency: bool,
+    /// 自動調整時の同時実行数の下限
+    pub min_concurrent_downloads: usize,
+    /// 自動調整時の同時実行数の上限
+    pub max_concurrent_downloads: usize,
+    /// 大きなファイルの分割数（1 は分割しない、既定は分割しない）
+    pub segment_count: usize,
+    /// 分割を行う最小ファイルサイズ（MB）
+    pub segment_threshold_mb: u64,
+    /// 停滞とみなす転送速度の下限（KB/s、0 は停滞を検出しない、既定は検出しない）
+    pub stall_min_kb_per_sec: u64,
+    /// 停滞を判定する期間（秒）
+    pub stall_window_secs: u64,
+    /// 時間帯別の帯域上限（例: 09:00〜18:00 は 2MB/s）
+    pub bandwidth_schedule: Vec<BandwidthWindow>,
+    /// ホストごとのサブフォルダーに保存するか（アカウント単位の取得向け）
+    pub per_host_subfolders: bool,
+}
+
+impl Default for DownloadSettings {
+    fn default() -> Self {
+        Self {
+            keep_partial_on_cancel: false,
+            max_bytes_per_sec: None,
+            conflict_policy: ConflictPolicy::default(),
+            min_free_space_mb: 1024,
+            adaptive_concurrency: false,
+            min_concurrent_downloads: 1,
+            max_concurrent_downloads: 6,
+            segment_count: 1,
+            segment_threshold_mb: 256,
+            stall_min_kb_per_sec: 0,
+            stall_window_secs: 60,
+            bandwidth_schedule: Vec::new(),
+            per_host_subfolders: false,
+        }
+    }
+}
+
+impl DownloadSettings {
+    /// 確保しておく空き容量（バイト）
+    pub fn min_free_space_bytes(&self) -> u64 {
+        self.min_free_space_mb.saturating_mul(1024 * 1024)
+    }
+
+    /// 分割ダウンロード設定（無効な場合は None）
+    pub fn segmented(&self) -> Option<SegmentedDownload> {
+        (self.segment_count > 1).then(|| SegmentedDownload {
+            segments: self.segment_count,
+            min_size_bytes: self.segment_threshold_mb.saturating_mul(1024 * 1024),
+        })
+    }
+
+    /// 停滞検出設定（無効な場合は None）
+    pub fn stall_detection(&self) -> Option<StallDetection> {
+        (self.stall_min_kb_per_sec > 0 && self.stall_window_secs > 0).then(|| StallDetection {
+            min_bytes_per_sec: self.stall_min_kb_per_sec.saturating_mul(1024),
+            window: std::time::Duration::from_secs(self.stall_window_secs),
+        })
+    }
+
+    /// 録画ファイルの出力先（出力ディレクトリからの相対パス）
+    pub fn file_path(&self, meeting: &MeetingRecording, file: &RecordingFile) -> String {
+        if self.per_host_subfolders {
+            crate::generate_host_file_path(meeting, file)
+        } else {
+            crate::generate_file_path(meeting, file)
+        }
+    }
+
+    /// 同時実行数の自動調整設定（無効な場合は None）
+    pub fn adaptive(&self) -> Option<AdaptiveConcurrency> {
+        self.adaptive_concurrency.then(|| AdaptiveConcurrency {
+            min_workers: self.min_concurrent_downloads.max(1),
+            max_workers: self
+                .max_concurrent_downloads
+                .max(self.min_concurrent_downloads.max(1)),
+            ..AdaptiveConcurrency::default()
+        })
+    }
+}
+
 impl Default for AppConfig {
     fn default() -> Self {
         Self {
@@ -143,6 +397,8 @@ impl Default for AppConfig {
             debug_mode: false,
             log_level: "info".to_string(),
             api: ApiSettings::default(),
+            endpoints: EndpointProfile::default(),
+            download: DownloadSettings::default(),
         }
     }
 }
@@ -167,6 +423,7 @@ impl AppConfig {
         config
             .validate()
             .map_err(|e| AppError::validation(format!("Config validation failed: {}", e), None))?;
+        config.endpoints.validate()?;
 
         Ok(config)
     }
@@ -262,6 +519,7 @@ impl ConfigManager {
         config
             .validate()
             .map_err(|e| AppError::validation(format!("Config validation failed: {}", e), None))?;
+        config.endpoints.validate()?;
 
         self.config = config;
         self.is_modified = false;
@@ -412,6 +670,7 @@ impl Configurable<AppConfig> for ConfigManager {
         config
             .validate()
             .map_err(|e| AppError::validation(format!("Config validation failed: {}", e), None))?;
+        config.endpoints.validate()?;
 
         self.config = config;
         self.is_modified = true;
@@ -479,4 +738,72 @@ mod tests {
         config.oauth.client_id = String::new();
         assert!(config.oauth.validate().is_err());
     }
+
+    #[test]
+    fn test_download_settings_segmented_is_opt_in() {
+        let mut settings = DownloadSettings::default();
+        assert_eq!(settings.segmented(), None);
+
+        settings.segment_count = 4;
+        let segmented = settings.segmented().unwrap();
+        assert_eq!(segmented.segments, 4);
+        assert_eq!(segmented.min_size_bytes, 256 * 1024 * 1024);
+    }
+
+    #[test]
+    fn test_download_settings_stall_detection_is_opt_in() {
+        let mut settings = DownloadSettings::default();
+        assert_eq!(settings.stall_detection(), None);
+
+        settings.stall_min_kb_per_sec = 16;
+        let stall = settings.stall_detection().unwrap();
+        assert_eq!(stall.min_bytes_per_sec, 16 * 1024);
+        assert_eq!(stall.window, std::time::Duration::from_secs(60));
+    }
+
+    #[test]
+    fn test_endpoint_profile_presets_and_custom_overrides() {
+        let zoom = EndpointProfile::default();
+        assert_eq!(zoom.auth_url(), "https://zoom.us/oauth/authorize");
+        assert_eq!(zoom.api_base_url(), "https://api.zoom.us/v2");
+        assert!(zoom.validate().is_ok());
+
+        let gov = EndpointProfile::preset(EndpointPreset::ZoomGov);
+        assert_eq!(gov.token_url(), "https://zoomgov.com/oauth/token");
+        assert_eq!(gov.api_base_url(), "https://api.zoomgov.com/v2");
+
+        // 個別指定はプリセットより優先される
+        let overridden = EndpointProfile {
+            api_base_url: Some("http://127.0.0.1:9000/v2/".to_string()),
+            ..EndpointProfile::default()
+        };
+        assert_eq!(overridden.api_base_url(), "http://127.0.0.1:9000/v2");
+        assert_eq!(overridden.revoke_url(), "https://zoom.us/oauth/revoke");
+
+        let custom = EndpointProfile::custom("http://127.0.0.1:9000/");
+        assert_eq!(custom.auth_url(), "http://127.0.0.1:9000/oauth/authorize");
+        assert!(custom.validate().is_ok());
+
+        // Custom では URL の欠落や不正な URL は拒否される
+        let incomplete = EndpointProfile {
+            token_url: None,
+            ..custom.clone()
+        };
+        assert!(incomplete.validate().is_err());
+        let invalid = EndpointProfile {
+            auth_url: Some("not a url".to_string()),
+            ..custom
+        };
+        assert!(invalid.validate().is_err());
+
+        // 既存の設定ファイル（endpoints なし）は zoom.us として読み込まれる
+        let content = toml::to_string(&AppConfig::default()).unwrap();
+        let without_endpoints: String = content
+            .lines()
+            .take_while(|line| !line.starts_with("[endpoints]"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let parsed: AppConfig = toml::from_str(&without_endpoints).unwrap();
+        assert_eq!(parsed.endpoints, EndpointProfile::default());
+    }
 }
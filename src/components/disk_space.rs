@@ -0,0 +1,161 @@
+//! ディスク空き容量コンポーネント
+//!
+//! # 責任
+//! - 出力先ボリュームの空き容量取得
+//! - ダウンロード開始前の容量チェック（プリフライト）
+
+use crate::errors::{AppError, AppResult};
+use std::path::Path;
+
+/// 容量チェックの結果
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SpaceCheck {
+    /// 必要なバイト数（サイズ判明分の合計）
+    pub required_bytes: u64,
+    /// 出力先ボリュームの空き容量
+    pub available_bytes: u64,
+    /// ダウンロード後も確保しておく容量
+    pub reserve_bytes: u64,
+    /// サイズ不明のため合計に含めていないファイル数
+    pub unknown_size_files: usize,
+}
+
+impl SpaceCheck {
+    /// 不足しているバイト数（足りている場合は 0）
+    pub fn shortfall(&self) -> u64 {
+        self.required_bytes
+            .saturating_add(self.reserve_bytes)
+            .saturating_sub(self.available_bytes)
+    }
+
+    /// 空き容量が足りているか
+    pub fn is_sufficient(&self) -> bool {
+        self.shortfall() == 0
+    }
+}
+
+/// 出力先の容量をチェックする
+///
+/// # 副作用
+/// - ファイルシステム情報の取得
+///
+/// # 事前条件
+/// - file_sizes には各ファイルの期待サイズ（不明なら None）を渡す
+pub fn preflight(
+    output_dir: &Path,
+    file_sizes: impl IntoIterator<Item = Option<u64>>,
+    reserve_bytes: u64,
+) -> AppResult<SpaceCheck> {
+    let mut check = SpaceCheck {
+        available_bytes: available_space(output_dir)?,
+        reserve_bytes,
+        ..SpaceCheck::default()
+    };
+    for size in file_sizes {
+        match size {
+            Some(size) => check.required_bytes = check.required_bytes.saturating_add(size),
+            None => check.unknown_size_files += 1,
+        }
+    }
+    Ok(check)
+}
+
+/// 指定パスを含むボリュームの空き容量（呼び出しユーザーが利用可能なバイト数）を取得
+///
+/// # 事後条件
+/// - パスが未作成の場合は存在する最も近い親ディレクトリのボリュームを調べる
+pub fn available_space(path: &Path) -> AppResult<u64> {
+    let existing = path
+        .ancestors()
+        .find(|ancestor| !ancestor.as_os_str().is_empty() && ancestor.exists())
+        .unwrap_or_else(|| Path::new("."));
+    platform_available_space(existing)
+}
+
+#[cfg(unix)]
+fn platform_available_space(path: &Path) -> AppResult<u64> {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+
+    let c_path = CString::new(path.as_os_str().as_bytes())
+        .map_err(|_| AppError::validation("Path contains a NUL byte", Some("path".to_string())))?;
+    let mut stat: libc::statvfs = unsafe { std::mem::zeroed() };
+    // SAFETY: c_path は NUL 終端された有効な文字列、stat は書き込み可能な領域
+    let result = unsafe { libc::statvfs(c_path.as_ptr(), &mut stat) };
+    if result != 0 {
+        return Err(AppError::file_system(
+            "Failed to query free disk space",
+            Some(std::io::Error::last_os_error()),
+        ));
+    }
+    Ok((stat.f_bavail as u64).saturating_mul(stat.f_frsize as u64))
+}
+
+#[cfg(windows)]
+fn platform_available_space(path: &Path) -> AppResult<u64> {
+    use std::os::windows::ffi::OsStrExt;
+    use winapi::um::fileapi::GetDiskFreeSpaceExW;
+    use winapi::um::winnt::ULARGE_INTEGER;
+
+    let wide: Vec<u16> = path.as_os_str().encode_wide().chain(Some(0)).collect();
+    let mut available: ULARGE_INTEGER = unsafe { std::mem::zeroed() };
+    // SAFETY: wide は NUL 終端されたUTF-16文字列、available は書き込み可能な領域
+    let result = unsafe {
+        GetDiskFreeSpaceExW(
+            wide.as_ptr(),
+            &mut available,
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+        )
+    };
+    if result == 0 {
+        return Err(AppError::file_system(
+            "Failed to query free disk space",
+            Some(std::io::Error::last_os_error()),
+        ));
+    }
+    Ok(unsafe { *available.QuadPart() })
+}
+
+#[cfg(not(any(unix, windows)))]
+fn platform_available_space(_path: &Path) -> AppResult<u64> {
+    Err(AppError::file_system(
+        "Free disk space query is not supported on this platform",
+        None::<std::io::Error>,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shortfall_includes_reserve() {
+        let check = SpaceCheck {
+            required_bytes: 800,
+            available_bytes: 1000,
+            reserve_bytes: 300,
+            unknown_size_files: 0,
+        };
+        assert_eq!(check.shortfall(), 100);
+        assert!(!check.is_sufficient());
+
+        let check = SpaceCheck {
+            reserve_bytes: 200,
+            ..check
+        };
+        assert_eq!(check.shortfall(), 0);
+        assert!(check.is_sufficient());
+    }
+
+    #[test]
+    fn test_preflight_sums_known_sizes_for_missing_directory() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let output_dir = temp_dir.path().join("not/created/yet");
+
+        let check = preflight(&output_dir, [Some(100), None, Some(50)], 10).unwrap();
+        assert_eq!(check.required_bytes, 150);
+        assert_eq!(check.unknown_size_files, 1);
+        assert!(check.available_bytes > 0);
+    }
+}
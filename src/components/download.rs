@@ -6,45 +6,123 @@
 //! - 進捗監視
 //! - エラー回復処理
 
+use crate::components::api::RecordingFileType;
+use crate::components::concurrency::{
+    AdaptiveConcurrency, AimdController, ConcurrencyChangeReason, ConcurrencyGate,
+    CongestionCounters,
+};
+use crate::components::disk_space;
+use crate::components::download_client::{network_error, DownloadClient};
+use crate::components::event_bus::{EventBus, EventEnvelope, EventSubscriber};
+use crate::components::journal::DownloadJournal;
+use crate::components::manifest::sha256_hasher;
+use crate::components::rate_limit::{BandwidthLimiter, BandwidthSchedule, BandwidthWindow};
+use crate::components::redaction::{redact_text, truncate_text};
+use crate::components::retry::{ExponentialBackoff, RetryAttempt, RetryDecision, RetryPolicy};
+use crate::components::segmented::{Segment, SegmentState, SegmentedDownload};
+use crate::components::token_provider::{StaticToken, TokenProvider};
+use crate::components::watchdog::{StallDetection, StallWatchdog};
 use crate::components::{ComponentLifecycle, Configurable};
 use crate::errors::{AppError, AppResult};
 use async_trait::async_trait;
 use futures::stream::StreamExt;
 use log;
 use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::collections::{HashMap, VecDeque};
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
-use tokio::fs::File;
-use tokio::io::AsyncWriteExt;
-use tokio::sync::{mpsc, Mutex, RwLock};
+use tokio::fs::{File, OpenOptions};
+use tokio::io::{AsyncSeekExt, AsyncWriteExt};
+use tokio::sync::{watch, Mutex, RwLock};
+
+/// 転送中に空き容量を確認する間隔
+const DISK_SPACE_CHECK_INTERVAL: Duration = Duration::from_secs(1);
 
 /// ダウンロード設定
 #[derive(Debug, Clone)]
 pub struct DownloadConfig {
-    /// 同時ダウンロード数
+    /// 同時ダウンロード数（適応モードでは初期値）
     pub concurrent_downloads: usize,
+    /// 適応的並列度（None は concurrent_downloads で固定）
+    pub adaptive_concurrency: Option<AdaptiveConcurrency>,
     /// チャンクサイズ
     pub chunk_size: usize,
     /// タイムアウト
     pub timeout: Duration,
-    /// リトライ回数
+    /// 最大試行回数（初回を含む、リトライポリシー未設定時に使用）
     pub max_retries: u32,
     /// 出力ディレクトリ
     pub output_directory: PathBuf,
+    /// キャンセル時に不完全なファイル（.part）を保持するか
+    pub keep_partial_on_cancel: bool,
+    /// 全ワーカー合計の帯域上限（bytes/sec、None は無制限）
+    pub max_bytes_per_sec: Option<u64>,
+    /// 時間帯別の帯域上限（該当時間帯では max_bytes_per_sec より優先）
+    pub bandwidth_schedule: Vec<BandwidthWindow>,
+    /// 出力先に同名ファイルが存在する場合の扱い
+    pub conflict_policy: ConflictPolicy,
+    /// 出力先に確保しておく空き容量（下回ると一時停止、0 は監視しない）
+    pub min_free_space_bytes: u64,
+    /// 大きなファイルの分割ダウンロード（None は常に単一ストリーム）
+    pub segmented: Option<SegmentedDownload>,
+    /// 転送停滞の検出（None はチャンク待機のタイムアウトのみ）
+    pub stall_detection: Option<StallDetection>,
 }
 
 impl Default for DownloadConfig {
     fn default() -> Self {
         Self {
             concurrent_downloads: 3,
+            adaptive_concurrency: None,
             chunk_size: 8192 * 1024, // 8MB
             timeout: Duration::from_secs(300),
             max_retries: 3,
             output_directory: PathBuf::from("downloads"),
+            keep_partial_on_cancel: false,
+            max_bytes_per_sec: None,
+            bandwidth_schedule: Vec::new(),
+            conflict_policy: ConflictPolicy::default(),
+            min_free_space_bytes: 0,
+            segmented: None,
+            stall_detection: None,
+        }
+    }
+}
+
+/// 出力先に同名ファイルが存在する場合の扱い
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictPolicy {
+    /// ダウンロードせずに既存ファイルを残す
+    Skip,
+    /// 既存ファイルを上書きする
+    #[default]
+    Overwrite,
+    /// 連番を付けた別名で保存する（例: "video (1).mp4"）
+    Rename,
+    /// サイズが期待サイズと異なる場合のみ上書きする（期待サイズ不明時は上書き）
+    OverwriteIfSizeDiffers,
+}
+
+impl DownloadConfig {
+    /// 帯域スケジュールを取得
+    pub fn bandwidth(&self) -> BandwidthSchedule {
+        BandwidthSchedule {
+            base_limit: self.max_bytes_per_sec,
+            windows: self.bandwidth_schedule.clone(),
         }
     }
+
+    /// 1チャンクの待機時間の上限（停滞検出の評価期間を超えて待たない）
+    fn chunk_timeout(&self) -> Duration {
+        self.stall_detection
+            .as_ref()
+            .map_or(self.timeout, |stall| stall.window.min(self.timeout))
+    }
 }
 
 /// ダウンロードタスク
@@ -68,10 +146,87 @@ pub struct DownloadTask {
     pub error: Option<String>,
     /// リトライ数
     pub retry_count: u32,
+    /// 優先度（高いものから取り出される）
+    pub priority: TaskPriority,
+    /// 失敗した試行の履歴
+    pub attempts: Vec<RetryAttempt>,
+    /// 次の試行を開始してよい時刻（バックオフ中のみ Some）
+    pub retry_at: Option<Instant>,
+    /// 取得したファイル内容の SHA-256（16進小文字、完了時のみ Some）
+    pub sha256: Option<String>,
+}
+
+impl DownloadTask {
+    /// ダウンロード中の一時ファイル（.part）のパスを返す
+    ///
+    /// # 事後条件
+    /// - output_path の末尾に ".part" を付与したパスが返される
+    pub fn part_path(&self) -> PathBuf {
+        let mut path = self.output_path.clone().into_os_string();
+        path.push(".part");
+        PathBuf::from(path)
+    }
+
+    /// 試行を開始できる状態か（バックオフ待機中でないか）
+    fn is_ready(&self, now: Instant) -> bool {
+        self.retry_at.map_or(true, |retry_at| retry_at <= now)
+    }
+}
+
+/// タスクの優先度
+///
+/// キューからは優先度の高いタスクから取り出され、同じ優先度では追加順となる。
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskPriority {
+    /// 後回し（他のタスクがすべて取り出された後に実行）
+    Deferred,
+    /// 低
+    Low,
+    /// 通常
+    #[default]
+    Normal,
+    /// 高
+    High,
+}
+
+impl TaskPriority {
+    /// ファイル種別ごとの既定の優先度
+    ///
+    /// 容量の小さいテキスト系ファイル（文字起こし・チャット・要約・字幕）を動画より先に取得する。
+    pub fn for_file_type(file_type: &RecordingFileType) -> Self {
+        match file_type {
+            RecordingFileType::Transcript
+            | RecordingFileType::Chat
+            | RecordingFileType::Summary
+            | RecordingFileType::ClosedCaption => Self::High,
+            _ => Self::Normal,
+        }
+    }
+
+    /// 1段階上げた優先度（後回しは低に戻す）
+    pub fn raised(self) -> Self {
+        match self {
+            Self::Deferred => Self::Low,
+            Self::Low => Self::Normal,
+            Self::Normal | Self::High => Self::High,
+        }
+    }
+
+    /// 1段階下げた優先度（低より下へは下げない、後回しは defer で指定する）
+    pub fn lowered(self) -> Self {
+        match self {
+            Self::High => Self::Normal,
+            Self::Normal | Self::Low => Self::Low,
+            Self::Deferred => Self::Deferred,
+        }
+    }
 }
 
 /// タスク状態
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TaskState {
     /// 待機中
     Pending,
@@ -86,7 +241,7 @@ pub enum TaskState {
 }
 
 /// ダウンロード進捗
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct DownloadProgress {
     /// ダウンロード済みバイト数
     pub downloaded_bytes: u64,
@@ -131,7 +286,7 @@ impl DownloadProgress {
 }
 
 /// 全体進捗情報
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct OverallProgress {
     /// 総タスク数
     pub total_tasks: u32,
@@ -141,8 +296,168 @@ pub struct OverallProgress {
     pub failed_tasks: u32,
     /// アクティブタスク数
     pub active_tasks: u32,
-    /// 全体進捗率
+    /// 全体進捗率（0.0-1.0、バイト数で重み付け）
     pub overall_percentage: f64,
+    /// ダウンロード済みバイト数（失敗・キャンセル分を除く）
+    pub downloaded_bytes: u64,
+    /// 総バイト数（サイズ判明分のみ）
+    pub total_bytes: u64,
+    /// 全体の転送速度（bytes/sec）
+    pub current_speed: f64,
+    /// 全体の推定残り時間
+    pub eta: Option<Duration>,
+}
+
+/// 集計対象タスクの進捗
+#[derive(Debug, Clone)]
+struct TrackedTask {
+    state: TaskState,
+    downloaded_bytes: u64,
+    total_bytes: Option<u64>,
+    current_speed: f64,
+}
+
+/// 全体進捗の集計器
+///
+/// タスクごとの最新進捗を保持し、OverallProgress を算出する。
+#[derive(Debug, Clone, Default)]
+struct ProgressTracker {
+    inner: Arc<std::sync::Mutex<ProgressTrackerState>>,
+    /// 受信したバイト数の累計（失敗・再試行でも減らない）
+    transferred: Arc<AtomicU64>,
+}
+
+#[derive(Debug, Default)]
+struct ProgressTrackerState {
+    /// タスクID → 進捗（追加順は保持しない）
+    tasks: HashMap<String, TrackedTask>,
+    /// 最後に全体進捗を通知した時刻
+    last_emitted: Option<Instant>,
+}
+
+impl ProgressTracker {
+    /// 全体進捗の通知間隔
+    const EMIT_INTERVAL: Duration = Duration::from_millis(100);
+
+    /// タスクの現在状態を反映する
+    fn track(&self, task: &DownloadTask) {
+        let tracked = TrackedTask {
+            state: task.state.clone(),
+            downloaded_bytes: task.progress.downloaded_bytes,
+            total_bytes: task.progress.total_bytes.or(task.expected_size),
+            current_speed: if task.state == TaskState::InProgress {
+                task.progress.current_speed
+            } else {
+                0.0
+            },
+        };
+        if let Ok(mut state) = self.inner.lock() {
+            state.tasks.insert(task.task_id.clone(), tracked);
+        }
+    }
+
+    /// 受信したバイト数を累計に加える
+    fn record_transferred(&self, bytes: u64) {
+        self.transferred.fetch_add(bytes, Ordering::Relaxed);
+    }
+
+    /// 受信したバイト数の累計（スループットの算出用、単調増加）
+    fn transferred_bytes(&self) -> u64 {
+        self.transferred.load(Ordering::Relaxed)
+    }
+
+    /// 前回の通知から一定時間経過していれば true を返し、通知時刻を更新する
+    fn should_emit(&self) -> bool {
+        let Ok(mut state) = self.inner.lock() else {
+            return false;
+        };
+        let now = Instant::now();
+        match state.last_emitted {
+            Some(last) if now.duration_since(last) < Self::EMIT_INTERVAL => false,
+            _ => {
+                state.last_emitted = Some(now);
+                true
+            }
+        }
+    }
+
+    /// 全体進捗を算出する
+    ///
+    /// # 事後条件
+    /// - 失敗・キャンセルしたタスクのバイト数は集計から除外される
+    /// - サイズ不明のタスクしかない場合はタスク数ベースの進捗率になる
+    fn snapshot(&self) -> OverallProgress {
+        let Ok(state) = self.inner.lock() else {
+            return OverallProgress::default();
+        };
+
+        let mut overall = OverallProgress {
+            total_tasks: state.tasks.len() as u32,
+            ..OverallProgress::default()
+        };
+        let mut known_downloaded = 0u64;
+
+        for task in state.tasks.values() {
+            match task.state {
+                TaskState::Completed => overall.completed_tasks += 1,
+                TaskState::Failed => overall.failed_tasks += 1,
+                TaskState::InProgress => overall.active_tasks += 1,
+                TaskState::Pending | TaskState::Cancelled => {}
+            }
+            if matches!(task.state, TaskState::Failed | TaskState::Cancelled) {
+                continue;
+            }
+
+            overall.downloaded_bytes += task.downloaded_bytes;
+            overall.current_speed += task.current_speed;
+
+            let total = match (task.total_bytes, &task.state) {
+                (Some(total), _) => Some(total),
+                (None, TaskState::Completed) => Some(task.downloaded_bytes),
+                (None, _) => None,
+            };
+            if let Some(total) = total {
+                overall.total_bytes += total;
+                known_downloaded += task.downloaded_bytes.min(total);
+            }
+        }
+
+        if overall.total_bytes > 0 {
+            overall.overall_percentage =
+                (known_downloaded as f64 / overall.total_bytes as f64).min(1.0);
+
+            if overall.current_speed > 0.0 {
+                let remaining = overall.total_bytes.saturating_sub(known_downloaded);
+                overall.eta = Some(Duration::from_secs_f64(
+                    remaining as f64 / overall.current_speed,
+                ));
+            }
+        } else if overall.total_tasks > 0 {
+            overall.overall_percentage =
+                overall.completed_tasks as f64 / overall.total_tasks as f64;
+        }
+
+        overall
+    }
+}
+
+/// タスクが失敗した理由
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FailureReason {
+    /// 転送速度が下限を下回り続けた（停滞）
+    Stalled,
+    /// その他のエラー
+    Error,
+}
+
+impl FailureReason {
+    /// 最後に発生したエラーから理由を判定
+    pub fn from_error(error: &AppError) -> Self {
+        match error {
+            AppError::Stalled { .. } => Self::Stalled,
+            _ => Self::Error,
+        }
+    }
 }
 
 /// ダウンロードイベント
@@ -159,11 +474,278 @@ pub enum DownloadEvent {
     TaskCompleted {
         task_id: String,
         output_path: PathBuf,
+        /// ファイル内容の SHA-256（16進小文字）
+        sha256: Option<String>,
     },
     /// タスク失敗
-    TaskFailed { task_id: String, error: String },
+    TaskFailed {
+        task_id: String,
+        error: String,
+        reason: FailureReason,
+    },
+    /// 実行中タスクのキャンセル
+    TaskCancelled { task_id: String },
+    /// 出力先に既存ファイルがあるためスキップした
+    TaskSkipped {
+        task_id: String,
+        output_path: PathBuf,
+    },
     /// 全体進捗更新
     OverallProgressUpdate(OverallProgress),
+    /// 出力先の空き容量が確保容量を下回ったため一時停止を要求した
+    LowDiskSpace {
+        available_bytes: u64,
+        reserve_bytes: u64,
+    },
+    /// 同時実行数の上限を変更した（適応モードのみ）
+    ConcurrencyChanged {
+        concurrency: usize,
+        reason: ConcurrencyChangeReason,
+    },
+    /// 全ワーカーが一時停止した
+    Paused,
+    /// 一時停止から再開した
+    Resumed,
+    /// キャンセルにより全ワーカーが停止した（未着手タスクIDを含む）
+    Cancelled { pending_tasks: Vec<String> },
+}
+
+impl DownloadEvent {
+    /// タスクの最終結果のイベントか（DownloadComponent::task_outcomes に記録される）
+    pub fn is_outcome(&self) -> bool {
+        matches!(
+            self,
+            Self::TaskCompleted { .. }
+                | Self::TaskFailed { .. }
+                | Self::TaskCancelled { .. }
+                | Self::TaskSkipped { .. }
+                | Self::Cancelled { .. }
+        )
+    }
+}
+
+/// ダウンロード制御ハンドル
+///
+/// GUI等の呼び出し側とダウンロードワーカー間で共有し、一時停止・再開・キャンセルを指示する。
+/// クローンしたハンドルはすべて同じ状態を参照する。
+#[derive(Debug, Clone)]
+pub struct DownloadControl {
+    /// 一時停止要求（true: 一時停止中）
+    paused: Arc<watch::Sender<bool>>,
+    /// キャンセル要求（一度 true になると戻らない）
+    cancelled: Arc<watch::Sender<bool>>,
+    /// 全ワーカーで共有する帯域制限
+    bandwidth: BandwidthLimiter,
+    /// 実行中に変更されたタスクの優先度（task_id → 優先度）
+    priorities: Arc<std::sync::Mutex<HashMap<String, TaskPriority>>>,
+}
+
+impl Default for DownloadControl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DownloadControl {
+    /// 新しい制御ハンドルを作成（初期状態は実行中）
+    pub fn new() -> Self {
+        let (paused, _) = watch::channel(false);
+        let (cancelled, _) = watch::channel(false);
+        Self {
+            paused: Arc::new(paused),
+            cancelled: Arc::new(cancelled),
+            bandwidth: BandwidthLimiter::default(),
+            priorities: Arc::new(std::sync::Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// 一時停止を要求する
+    ///
+    /// # 事後条件
+    /// - 実行中のストリームはチャンク境界で中断され、新しいタスクは取り出されなくなる
+    /// - 実際に停止したかは DownloadEvent::Paused で通知される
+    pub fn pause(&self) {
+        self.paused.send_replace(true);
+    }
+
+    /// 一時停止を解除する
+    ///
+    /// # 事後条件
+    /// - 一時停止中のワーカーは同じタスクを中断位置から再開する
+    pub fn resume(&self) {
+        self.paused.send_replace(false);
+    }
+
+    /// キャンセルを要求する
+    ///
+    /// # 事後条件
+    /// - 実行中のストリームは直ちに中断され、ワーカーは新しいタスクを取り出さずに終了する
+    /// - 一時停止中のワーカーも待機を解除して終了する
+    /// - 全ワーカーの終了は DownloadEvent::Cancelled で通知される
+    pub fn cancel(&self) {
+        self.cancelled.send_replace(true);
+    }
+
+    /// 一時停止が要求されているか
+    pub fn is_paused(&self) -> bool {
+        *self.paused.borrow()
+    }
+
+    /// キャンセルが要求されているか
+    pub fn is_cancelled(&self) -> bool {
+        *self.cancelled.borrow()
+    }
+
+    /// 実行中に帯域上限を変更する（None は無制限）
+    ///
+    /// # 事後条件
+    /// - 転送中のワーカーにも次のチャンクから適用される
+    /// - 以降は設定のスケジュールより優先される
+    pub fn set_bandwidth_limit(&self, max_bytes_per_sec: Option<u64>) {
+        self.bandwidth.set_limit(max_bytes_per_sec);
+    }
+
+    /// 現在適用されている帯域上限（bytes/sec、None は無制限）
+    pub fn bandwidth_limit(&self) -> Option<u64> {
+        self.bandwidth.current_limit()
+    }
+
+    /// 待機中のタスクの優先度を変更する
+    ///
+    /// # 事後条件
+    /// - 次にワーカーがタスクを取り出す時点から反映される
+    /// - 実行中・完了済みのタスクには影響しない
+    pub fn set_task_priority(&self, task_id: &str, priority: TaskPriority) {
+        if let Ok(mut priorities) = self.priorities.lock() {
+            priorities.insert(task_id.to_string(), priority);
+        }
+    }
+
+    /// 実行中に変更されたタスクの優先度
+    pub fn task_priority(&self, task_id: &str) -> Option<TaskPriority> {
+        self.priorities
+            .lock()
+            .ok()
+            .and_then(|priorities| priorities.get(task_id).copied())
+    }
+
+    /// 一時停止が解除されるか、キャンセルされるまで待機する
+    async fn wait_while_paused(&self) {
+        let mut rx = self.paused.subscribe();
+        tokio::select! {
+            _ = rx.wait_for(|paused| !*paused) => {}
+            _ = self.cancelled_signal() => {}
+        }
+    }
+
+    /// キャンセルが要求されるまで待機する
+    async fn cancelled_signal(&self) {
+        let mut rx = self.cancelled.subscribe();
+        if rx.wait_for(|cancelled| *cancelled).await.is_err() {
+            std::future::pending::<()>().await;
+        }
+    }
+
+    /// 一時停止が要求されるまで待機する
+    async fn wait_for_pause(rx: &mut watch::Receiver<bool>) {
+        if rx.wait_for(|paused| *paused).await.is_err() {
+            std::future::pending::<()>().await;
+        }
+    }
+}
+
+/// 1回の転送試行の結果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TransferStatus {
+    /// ファイルの取得が完了した
+    Completed,
+    /// 一時停止要求により中断した（.partファイルは保持される）
+    Paused,
+    /// キャンセル要求により中断した
+    Cancelled,
+    /// 競合ポリシーにより既存ファイルを残してスキップした
+    Skipped,
+}
+
+/// 分割ダウンロードの各セグメントで共有する転送情報
+struct SegmentTransfer<'a> {
+    http_client: &'a DownloadClient,
+    download_url: &'a str,
+    part_path: &'a std::path::Path,
+    /// 並行して取得するセグメント数
+    streams: usize,
+    config: &'a DownloadConfig,
+    control: &'a DownloadControl,
+    /// いずれかのセグメントが失敗したときに他のセグメントを中断させる
+    abort: &'a watch::Sender<bool>,
+    tracker: &'a ProgressTracker,
+}
+
+/// タスクの最終結果イベントの記録
+///
+/// イベントバスは受信が追いつかない購読者から古いイベントを破棄するため、
+/// 完了・失敗・スキップ・キャンセルのイベントはここにも残し、取りこぼした購読者が参照できるようにする。
+#[derive(Debug, Clone, Default)]
+struct OutcomeLog {
+    entries: Arc<std::sync::Mutex<Vec<EventEnvelope<DownloadEvent>>>>,
+}
+
+impl OutcomeLog {
+    /// イベントをバスへ発行し、同じ通番で記録する
+    ///
+    /// # 事後条件
+    /// - 購読者がバスで取りこぼしを検知した時点で、取りこぼしたイベントは記録済みである
+    fn publish(&self, events: &EventBus<DownloadEvent>, event: DownloadEvent) {
+        // 発行から記録までロックを保持し、取りこぼしの検知後に記録が欠けないようにする
+        let mut entries = self
+            .entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let sequence = events.publish(event.clone());
+        entries.push(EventEnvelope {
+            sequence,
+            timestamp: chrono::Utc::now(),
+            event,
+        });
+    }
+
+    /// 記録したイベント（発行順）
+    fn entries(&self) -> Vec<EventEnvelope<DownloadEvent>> {
+        self.entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+}
+
+/// ワーカー間で共有される実行コンテキスト
+#[derive(Clone)]
+struct WorkerContext {
+    task_queue: Arc<Mutex<VecDeque<DownloadTask>>>,
+    active_tasks: Arc<RwLock<HashMap<String, DownloadTask>>>,
+    http_client: DownloadClient,
+    events: EventBus<DownloadEvent>,
+    /// タスクの最終結果イベントの記録
+    outcomes: OutcomeLog,
+    shutdown_signal: Arc<RwLock<bool>>,
+    config: DownloadConfig,
+    control: DownloadControl,
+    /// 一時停止中のワーカー数
+    paused_workers: Arc<AtomicUsize>,
+    /// 稼働中のワーカー数
+    running_workers: Arc<AtomicUsize>,
+    /// タスク状態の永続化先
+    journal: Option<Arc<std::sync::Mutex<DownloadJournal>>>,
+    /// 全体進捗の集計器
+    progress_tracker: ProgressTracker,
+    /// 失敗時のリトライ判定
+    retry_policy: Arc<dyn RetryPolicy>,
+    /// 起動したワーカー数
+    worker_count: usize,
+    /// 同時実行数の上限管理
+    concurrency: Arc<ConcurrencyGate>,
+    /// 抑制シグナルの集計
+    congestion: Arc<CongestionCounters>,
 }
 
 /// ダウンロード実行コンポーネント
@@ -176,10 +758,28 @@ pub struct DownloadComponent {
     task_queue: Arc<Mutex<VecDeque<DownloadTask>>>,
     /// アクティブタスク
     active_tasks: Arc<RwLock<HashMap<String, DownloadTask>>>,
-    /// イベント送信チャネル
-    event_sender: Option<mpsc::UnboundedSender<DownloadEvent>>,
+    /// イベントの配信先
+    events: EventBus<DownloadEvent>,
+    /// タスクの最終結果イベントの記録
+    outcomes: OutcomeLog,
     /// シャットダウンシグナル
     shutdown_signal: Arc<RwLock<bool>>,
+    /// 一時停止・再開の制御ハンドル
+    control: DownloadControl,
+    /// 一時停止中のワーカー数
+    paused_workers: Arc<AtomicUsize>,
+    /// 稼働中のワーカー数
+    running_workers: Arc<AtomicUsize>,
+    /// タスク状態の永続化先
+    journal: Option<Arc<std::sync::Mutex<DownloadJournal>>>,
+    /// 全体進捗の集計器
+    progress_tracker: ProgressTracker,
+    /// 失敗時のリトライ判定（None は max_retries による指数バックオフ）
+    retry_policy: Option<Arc<dyn RetryPolicy>>,
+    /// 同時実行数の上限管理
+    concurrency: Arc<ConcurrencyGate>,
+    /// ダウンロードの認証に使うトークンの供給元（None は認証なし）
+    token_provider: Option<Arc<dyn TokenProvider>>,
 }
 
 impl DownloadComponent {
@@ -202,27 +802,108 @@ impl DownloadComponent {
             http_client,
             task_queue: Arc::new(Mutex::new(VecDeque::new())),
             active_tasks: Arc::new(RwLock::new(HashMap::new())),
-            event_sender: None,
+            events: EventBus::default(),
+            outcomes: OutcomeLog::default(),
             shutdown_signal: Arc::new(RwLock::new(false)),
+            control: DownloadControl::new(),
+            paused_workers: Arc::new(AtomicUsize::new(0)),
+            running_workers: Arc::new(AtomicUsize::new(0)),
+            journal: None,
+            progress_tracker: ProgressTracker::default(),
+            retry_policy: None,
+            token_provider: None,
+            concurrency: Arc::new(ConcurrencyGate::new(0)),
         }
     }
 
-    /// イベントリスナーを設定
+    /// 現在の同時実行数の上限
+    pub fn current_concurrency(&self) -> usize {
+        self.concurrency.limit()
+    }
+
+    /// ダウンロードに使う access_token を設定
+    ///
+    /// # 副作用
+    /// - 以降のダウンロードリクエストに Authorization: Bearer ヘッダーが付与される
+    ///
+    /// # 事前条件
+    /// - start_downloads の呼び出し前である
+    pub fn set_access_token(&mut self, access_token: impl Into<String>) {
+        self.token_provider = Some(Arc::new(StaticToken::new(access_token)));
+    }
+
+    /// ダウンロードに使うトークンの供給元を設定
+    ///
+    /// # 副作用
+    /// - 以降のダウンロードリクエストはリクエストごとに供給元からトークンを取得する
+    /// - 401 で拒否されたリクエストは更新したトークンで再送される
+    ///
+    /// # 事前条件
+    /// - start_downloads の呼び出し前である
+    pub fn set_token_provider(&mut self, provider: Arc<dyn TokenProvider>) {
+        self.token_provider = Some(provider);
+    }
+
+    /// リトライポリシーを設定
+    ///
+    /// # 事前条件
+    /// - start_downloads の呼び出し前である
+    pub fn set_retry_policy(&mut self, policy: Arc<dyn RetryPolicy>) {
+        self.retry_policy = Some(policy);
+    }
+
+    /// ジャーナルを設定
+    ///
+    /// # 副作用
+    /// - 以降のタスク追加・状態変化がジャーナルファイルへ記録される
+    pub fn set_journal(&mut self, journal: Arc<std::sync::Mutex<DownloadJournal>>) {
+        self.journal = Some(journal);
+    }
+
+    /// 制御ハンドルを設定
     ///
     /// # 副作用
-    /// - 内部の event_sender が更新される
+    /// - 以降に起動するワーカーは指定したハンドルの一時停止要求に従う
     ///
     /// # 事前条件
-    /// - sender は有効な UnboundedSender である
+    /// - start_downloads の呼び出し前である
+    pub fn set_control(&mut self, control: DownloadControl) {
+        self.control = control;
+    }
+
+    /// 制御ハンドルを取得
+    pub fn control(&self) -> DownloadControl {
+        self.control.clone()
+    }
+
+    /// ダウンロードイベントの購読を開始する
+    ///
+    /// GUI・ログ・ジャーナルなど、複数の購読者が同じ実行のイベントを受信できる。
     ///
     /// # 事後条件
-    /// - イベント通知が有効になる
-    /// - 以降のダウンロード進捗がイベントとして送信される
+    /// - 呼び出し以降に発行されたイベントを通番・発行時刻付きで受信する
+    /// - 受信が追いつかない購読者には取りこぼした件数が BusMessage::Lagged で通知される
     ///
     /// # 不変条件
     /// - コンポーネントの他の設定は変更されない
-    pub fn set_event_listener(&mut self, sender: mpsc::UnboundedSender<DownloadEvent>) {
-        self.event_sender = Some(sender);
+    pub fn subscribe(&self) -> EventSubscriber<DownloadEvent> {
+        self.events.subscribe()
+    }
+
+    /// 購読者ごとに保持する未受信イベント数を設定
+    ///
+    /// # 事前条件
+    /// - subscribe・start_downloads より前に呼び出す（既存の購読者は新しいバスのイベントを受信しない）
+    pub fn set_event_capacity(&mut self, capacity: usize) {
+        self.events = EventBus::new(capacity);
+    }
+
+    /// これまでに発行したタスクの最終結果イベント（発行順）
+    ///
+    /// TaskCompleted・TaskFailed・TaskSkipped・TaskCancelled・Cancelled はバスでの取りこぼしに関係なく
+    /// ここから参照できる。通番はバスで配信されたものと同じ。
+    pub fn task_outcomes(&self) -> Vec<EventEnvelope<DownloadEvent>> {
+        self.outcomes.entries()
     }
 
     /// ダウンロードタスクを追加
@@ -244,21 +925,11 @@ impl DownloadComponent {
         file_name: String,
         expected_size: Option<u64>,
     ) -> AppResult<()> {
-        if task_id.is_empty() {
-            return Err(AppError::validation("task_id must not be empty", None));
-        }
-        if download_url.is_empty() {
-            return Err(AppError::validation("download_url must not be empty", None));
-        }
-        if file_name.is_empty() {
-            return Err(AppError::validation("file_name must not be empty", None));
-        }
-
         // 出力パスの構築
         let output_path = self.config.output_directory.join(&file_name);
 
-        let task = DownloadTask {
-            task_id: task_id.clone(),
+        self.add_task(DownloadTask {
+            task_id,
             download_url,
             output_path,
             expected_size,
@@ -267,8 +938,43 @@ impl DownloadComponent {
             progress: DownloadProgress::new(),
             error: None,
             retry_count: 0,
-        };
+            priority: TaskPriority::default(),
+            attempts: Vec::new(),
+            retry_at: None,
+            sha256: None,
+        })
+        .await
+    }
+
+    /// 構築済みのダウンロードタスクを追加（ジャーナルからの復元用）
+    ///
+    /// # 副作用
+    /// - タスクキューへの追加
+    /// - ジャーナルへの記録
+    ///
+    /// # 事前条件
+    /// - task.task_id は一意である
+    /// - task.download_url は有効なURLである
+    /// - task.file_name は空でない
+    ///
+    /// # 事後条件
+    /// - タスクが Pending 状態でキューに追加される
+    pub async fn add_task(&self, mut task: DownloadTask) -> AppResult<()> {
+        if task.task_id.is_empty() {
+            return Err(AppError::validation("task_id must not be empty", None));
+        }
+        if task.download_url.is_empty() {
+            return Err(AppError::validation("download_url must not be empty", None));
+        }
+        if task.file_name.is_empty() {
+            return Err(AppError::validation("file_name must not be empty", None));
+        }
 
+        task.state = TaskState::Pending;
+        Self::record_journal(&self.journal, &task);
+        self.progress_tracker.track(&task);
+
+        let task_id = task.task_id.clone();
         let mut queue = self.task_queue.lock().await;
         queue.push_back(task);
 
@@ -276,6 +982,27 @@ impl DownloadComponent {
         Ok(())
     }
 
+    /// タスクの現在状態をジャーナルへ記録（失敗してもダウンロードは継続する）
+    fn record_journal(
+        journal: &Option<Arc<std::sync::Mutex<DownloadJournal>>>,
+        task: &DownloadTask,
+    ) {
+        let Some(journal) = journal else {
+            return;
+        };
+        let result = match journal.lock() {
+            Ok(mut journal) => journal.record(task),
+            Err(_) => Err(AppError::data_integrity("Download journal lock poisoned")),
+        };
+        if let Err(e) = result {
+            log::warn!(
+                "Failed to record download journal: {} ({})",
+                task.task_id,
+                e
+            );
+        }
+    }
+
     /// ダウンロード処理を開始
     ///
     /// # 副作用
@@ -289,138 +1016,331 @@ impl DownloadComponent {
     /// # 事後条件
     /// - ダウンロードワーカーが起動される
     pub async fn start_downloads(&self) -> AppResult<()> {
-        let concurrent_downloads = self.config.concurrent_downloads;
-
-        for worker_id in 0..concurrent_downloads {
-            let task_queue = self.task_queue.clone();
-            let active_tasks = self.active_tasks.clone();
-            let http_client = self.http_client.clone();
-            let event_sender = self.event_sender.clone();
-            let shutdown_signal = self.shutdown_signal.clone();
-            let config = self.config.clone();
+        let adaptive = self.config.adaptive_concurrency.clone();
+        let controller = adaptive
+            .clone()
+            .map(|adaptive| AimdController::new(adaptive, self.config.concurrent_downloads));
+        let (worker_count, initial_limit) = match (&adaptive, &controller) {
+            (Some(adaptive), Some(controller)) => (
+                adaptive.max_workers.max(controller.limit()),
+                controller.limit(),
+            ),
+            _ => (
+                self.config.concurrent_downloads,
+                self.config.concurrent_downloads,
+            ),
+        };
+        self.control.bandwidth.set_schedule(self.config.bandwidth());
+        self.concurrency.set_limit(initial_limit);
+
+        let context = WorkerContext {
+            task_queue: self.task_queue.clone(),
+            active_tasks: self.active_tasks.clone(),
+            http_client: DownloadClient::new(self.http_client.clone(), self.token_provider.clone()),
+            events: self.events.clone(),
+            outcomes: self.outcomes.clone(),
+            shutdown_signal: self.shutdown_signal.clone(),
+            config: self.config.clone(),
+            control: self.control.clone(),
+            paused_workers: self.paused_workers.clone(),
+            running_workers: self.running_workers.clone(),
+            journal: self.journal.clone(),
+            progress_tracker: self.progress_tracker.clone(),
+            retry_policy: self.retry_policy.clone().unwrap_or_else(|| {
+                Arc::new(ExponentialBackoff::with_max_attempts(
+                    self.config.max_retries,
+                ))
+            }),
+            worker_count,
+            concurrency: self.concurrency.clone(),
+            congestion: Arc::new(CongestionCounters::default()),
+        };
 
+        for worker_id in 0..worker_count {
+            let context = context.clone();
+            context.running_workers.fetch_add(1, Ordering::SeqCst);
             tokio::spawn(async move {
-                Self::download_worker(
-                    worker_id,
-                    task_queue,
-                    active_tasks,
-                    http_client,
-                    event_sender,
-                    shutdown_signal,
-                    config,
-                )
-                .await;
+                Self::download_worker(worker_id, context).await;
             });
         }
 
-        log::info!("Started {} download workers", concurrent_downloads);
+        if let (Some(adaptive), Some(controller)) = (adaptive, controller) {
+            context.events.publish(DownloadEvent::ConcurrencyChanged {
+                concurrency: initial_limit,
+                reason: ConcurrencyChangeReason::Initial,
+            });
+            tokio::spawn(Self::concurrency_controller(
+                context,
+                controller,
+                adaptive.sample_interval,
+            ));
+        }
+
+        log::info!(
+            "Started {} download workers (concurrency {})",
+            worker_count,
+            initial_limit
+        );
         Ok(())
     }
 
-    /// ダウンロードワーカー
-    async fn download_worker(
-        worker_id: usize,
-        task_queue: Arc<Mutex<VecDeque<DownloadTask>>>,
-        active_tasks: Arc<RwLock<HashMap<String, DownloadTask>>>,
-        http_client: Client,
-        event_sender: Option<mpsc::UnboundedSender<DownloadEvent>>,
-        shutdown_signal: Arc<RwLock<bool>>,
-        config: DownloadConfig,
+    /// 同時実行数を定期的に見直す（適応モード）
+    ///
+    /// # 副作用
+    /// - 実行枠の上限を変更し、DownloadEvent::ConcurrencyChanged を送信する
+    ///
+    /// # 事後条件
+    /// - シャットダウン・キャンセルで終了する
+    async fn concurrency_controller(
+        ctx: WorkerContext,
+        mut controller: AimdController,
+        interval: Duration,
     ) {
-        log::info!("Download worker {} started", worker_id);
+        // 失敗したタスクの分が集計から外れても低下と誤認しないよう、単調増加の累計から求める
+        let mut last_bytes = ctx.progress_tracker.transferred_bytes();
 
         loop {
-            // シャットダウンチェック
-            if *shutdown_signal.read().await {
-                log::info!("Download worker {} shutting down", worker_id);
+            tokio::select! {
+                _ = tokio::time::sleep(interval) => {}
+                _ = ctx.control.cancelled_signal() => break,
+            }
+            if *ctx.shutdown_signal.read().await {
                 break;
             }
 
-            // タスク取得
-            let task = {
-                let mut queue = task_queue.lock().await;
-                queue.pop_front()
-            };
+            let downloaded_bytes = ctx.progress_tracker.transferred_bytes();
+            let throughput =
+                downloaded_bytes.saturating_sub(last_bytes) as f64 / interval.as_secs_f64();
+            last_bytes = downloaded_bytes;
+            let signals = ctx.congestion.take();
 
-            match task {
-                Some(mut task) => {
-                    let task_id = task.task_id.clone();
+            // 一時停止中の観測値は評価しない
+            if ctx.control.is_paused() {
+                continue;
+            }
 
-                    // タスク開始イベント
-                    if let Some(sender) = &event_sender {
-                        let _ = sender.send(DownloadEvent::TaskStarted {
-                            task_id: task_id.clone(),
-                        });
-                    }
+            let has_pending = !ctx.task_queue.lock().await.is_empty();
+            let Some((concurrency, reason)) =
+                controller.on_sample(throughput, signals, ctx.concurrency.active(), has_pending)
+            else {
+                continue;
+            };
 
-                    // アクティブタスクに追加
-                    task.state = TaskState::InProgress;
-                    active_tasks
-                        .write()
+            ctx.concurrency.set_limit(concurrency);
+            log::info!("Download concurrency set to {} ({})", concurrency, reason);
+            ctx.events.publish(DownloadEvent::ConcurrencyChanged {
+                concurrency,
+                reason,
+            });
+        }
+    }
+
+    /// ダウンロードワーカー
+    async fn download_worker(worker_id: usize, ctx: WorkerContext) {
+        log::info!("Download worker {} started", worker_id);
+
+        loop {
+            // シャットダウンチェック
+            if *ctx.shutdown_signal.read().await {
+                log::info!("Download worker {} shutting down", worker_id);
+                break;
+            }
+
+            // キャンセルされた場合は新しいタスクを取り出さずに終了
+            if ctx.control.is_cancelled() {
+                log::info!("Download worker {} cancelled", worker_id);
+                break;
+            }
+
+            // 一時停止中は新しいタスクを取り出さない
+            if ctx.control.is_paused() {
+                Self::pause_worker(worker_id, &ctx).await;
+                continue;
+            }
+
+            // 同時実行数の上限に達している場合は空きを待つ
+            if !ctx.concurrency.try_acquire() {
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                continue;
+            }
+
+            // タスク取得（バックオフ待機中のタスクは飛ばす）
+            let task = {
+                let mut queue = ctx.task_queue.lock().await;
+                Self::next_task(&mut queue, &ctx.control, Instant::now())
+            };
+            if task.is_none() {
+                ctx.concurrency.release();
+            }
+
+            match task {
+                Some(mut task) => {
+                    let task_id = task.task_id.clone();
+
+                    // タスク開始イベント
+                    ctx.events.publish(DownloadEvent::TaskStarted {
+                        task_id: task_id.clone(),
+                    });
+
+                    // アクティブタスクに追加
+                    let attempt_started_at = chrono::Utc::now();
+                    task.retry_at = None;
+                    task.state = TaskState::InProgress;
+                    Self::record_journal(&ctx.journal, &task);
+                    ctx.progress_tracker.track(&task);
+                    Self::update_overall_progress(&ctx.progress_tracker, &ctx.events);
+                    ctx.active_tasks
+                        .write()
                         .await
                         .insert(task_id.clone(), task.clone());
 
-                    // ダウンロード実行
-                    let result =
-                        Self::download_file(&http_client, &mut task, &event_sender, &config).await;
+                    // ダウンロード実行（一時停止で中断した場合は再開後に同じタスクを続行）
+                    let result = loop {
+                        match Self::download_file(
+                            &ctx.http_client,
+                            &mut task,
+                            &ctx.events,
+                            &ctx.config,
+                            &ctx.control,
+                            &ctx.progress_tracker,
+                        )
+                        .await
+                        {
+                            Ok(TransferStatus::Paused) => {
+                                task.progress.current_speed = 0.0;
+                                ctx.progress_tracker.track(&task);
+                                Self::update_overall_progress(&ctx.progress_tracker, &ctx.events);
+                                Self::record_journal(&ctx.journal, &task);
+                                Self::pause_worker(worker_id, &ctx).await;
+                                // 一時停止中にキャンセルされた場合は転送を再開せずに終了する
+                                if ctx.control.is_cancelled() {
+                                    break Ok(TransferStatus::Cancelled);
+                                }
+                            }
+                            other => break other,
+                        }
+                    };
 
                     // 結果処理
                     match result {
-                        Ok(_) => {
+                        Ok(TransferStatus::Paused) => {
+                            unreachable!("paused transfers are resumed or cancelled above")
+                        }
+                        Ok(TransferStatus::Cancelled) => {
+                            task.state = TaskState::Cancelled;
+                            if !ctx.config.keep_partial_on_cancel {
+                                Self::remove_part_file(&task.part_path()).await;
+                            }
+                            ctx.outcomes.publish(
+                                &ctx.events,
+                                DownloadEvent::TaskCancelled {
+                                    task_id: task_id.clone(),
+                                },
+                            );
+                            log::info!("Download cancelled: {}", task_id);
+                        }
+                        Ok(TransferStatus::Skipped) => {
                             task.state = TaskState::Completed;
-                            if let Some(sender) = &event_sender {
-                                let _ = sender.send(DownloadEvent::TaskCompleted {
+                            ctx.outcomes.publish(
+                                &ctx.events,
+                                DownloadEvent::TaskSkipped {
                                     task_id: task_id.clone(),
                                     output_path: task.output_path.clone(),
-                                });
-                            }
+                                },
+                            );
+                            log::info!(
+                                "Download skipped, file exists: {}",
+                                task.output_path.display()
+                            );
+                        }
+                        Ok(TransferStatus::Completed) => {
+                            task.state = TaskState::Completed;
+                            ctx.outcomes.publish(
+                                &ctx.events,
+                                DownloadEvent::TaskCompleted {
+                                    task_id: task_id.clone(),
+                                    output_path: task.output_path.clone(),
+                                    sha256: task.sha256.clone(),
+                                },
+                            );
                             log::info!("Download completed: {}", task_id);
                         }
                         Err(e) => {
-                            task.error = Some(e.to_string());
+                            // エラー本文に認証情報が含まれていても外部へ出さない
+                            let error = redact_text(&e.to_string());
+                            task.error = Some(error.clone());
                             task.retry_count += 1;
+                            ctx.congestion.record(&e);
 
-                            // 不完全なファイルを削除
-                            if task.output_path.exists() {
-                                let _ = tokio::fs::remove_file(&task.output_path).await;
+                            // 不完全なファイル（.part）は再開用に保持する
+                            if task.part_path().exists() {
                                 log::info!(
-                                    "Removed incomplete file: {}",
-                                    task.output_path.display()
+                                    "Keeping partial file for resume: {}",
+                                    task.part_path().display()
                                 );
                             }
 
-                            if task.retry_count < config.max_retries {
-                                // リトライ
-                                task.state = TaskState::Pending;
-                                task_queue.lock().await.push_back(task.clone());
-                                log::warn!(
-                                    "Download failed, retrying: {} (attempt {})",
-                                    task_id,
-                                    task.retry_count + 1
-                                );
-                            } else {
-                                // 最終的に失敗
-                                task.state = TaskState::Failed;
-                                if let Some(sender) = &event_sender {
-                                    let _ = sender.send(DownloadEvent::TaskFailed {
-                                        task_id: task_id.clone(),
-                                        error: e.to_string(),
-                                    });
+                            let first_started_at = task
+                                .attempts
+                                .first()
+                                .map_or(attempt_started_at, |attempt| attempt.started_at);
+                            let elapsed = (chrono::Utc::now() - first_started_at)
+                                .to_std()
+                                .unwrap_or_default();
+                            let decision = ctx.retry_policy.decide(&e, task.retry_count, elapsed);
+                            task.attempts.push(RetryAttempt {
+                                attempt: task.retry_count,
+                                started_at: attempt_started_at,
+                                failed_at: chrono::Utc::now(),
+                                error: error.clone(),
+                                decision,
+                            });
+
+                            match decision {
+                                RetryDecision::Retry(delay) => {
+                                    // バックオフ後に再試行（待機中も他のタスクは処理される）
+                                    task.state = TaskState::Pending;
+                                    task.retry_at = Some(Instant::now() + delay);
+                                    ctx.task_queue.lock().await.push_back(task.clone());
+                                    log::warn!(
+                                        "Download failed, retrying in {:.1}s: {} (attempt {}): {}",
+                                        delay.as_secs_f64(),
+                                        task_id,
+                                        task.retry_count + 1,
+                                        error
+                                    );
+                                }
+                                RetryDecision::GiveUp => {
+                                    // 最終的に失敗
+                                    task.state = TaskState::Failed;
+                                    ctx.outcomes.publish(
+                                        &ctx.events,
+                                        DownloadEvent::TaskFailed {
+                                            task_id: task_id.clone(),
+                                            error: error.clone(),
+                                            reason: FailureReason::from_error(&e),
+                                        },
+                                    );
+                                    log::error!(
+                                        "Download failed after {} attempt(s): {} ({})",
+                                        task.retry_count,
+                                        task_id,
+                                        error
+                                    );
                                 }
-                                log::error!(
-                                    "Download failed after {} retries: {}",
-                                    config.max_retries,
-                                    task_id
-                                );
                             }
                         }
                     }
 
+                    Self::record_journal(&ctx.journal, &task);
+                    ctx.progress_tracker.track(&task);
+
                     // アクティブタスクから削除
-                    active_tasks.write().await.remove(&task_id);
+                    ctx.active_tasks.write().await.remove(&task_id);
+                    ctx.concurrency.release();
 
                     // 全体進捗更新
-                    Self::update_overall_progress(&active_tasks, &event_sender).await;
+                    Self::update_overall_progress(&ctx.progress_tracker, &ctx.events);
                 }
                 None => {
                     // タスクがない場合は少し待機
@@ -428,15 +1348,138 @@ impl DownloadComponent {
                 }
             }
         }
+
+        // 最後に終了したワーカーがキャンセル完了を通知
+        if ctx.running_workers.fetch_sub(1, Ordering::SeqCst) == 1 && ctx.control.is_cancelled() {
+            Self::finish_cancellation(&ctx).await;
+        }
+    }
+
+    /// 次に実行するタスクをキューから取り出す
+    ///
+    /// # 事後条件
+    /// - 開始可能なタスクのうち優先度が最も高く、最も早く追加されたものが返される
+    /// - 制御ハンドル経由で変更された優先度はタスクに反映される
+    fn next_task(
+        queue: &mut VecDeque<DownloadTask>,
+        control: &DownloadControl,
+        now: Instant,
+    ) -> Option<DownloadTask> {
+        let mut selected: Option<(usize, TaskPriority)> = None;
+        for (index, task) in queue.iter_mut().enumerate() {
+            if let Some(priority) = control.task_priority(&task.task_id) {
+                task.priority = priority;
+            }
+            if !task.is_ready(now) {
+                continue;
+            }
+            if selected.map_or(true, |(_, best)| task.priority > best) {
+                selected = Some((index, task.priority));
+            }
+        }
+        selected.and_then(|(index, _)| queue.remove(index))
+    }
+
+    /// キャンセル完了処理
+    ///
+    /// # 副作用
+    /// - 未着手タスクをキューから取り除き、ジャーナル上はキャンセル扱いにする
+    /// - keep_partial_on_cancel が false の場合は未着手タスクの .part ファイルを削除する
+    /// - DownloadEvent::Cancelled を送信する
+    async fn finish_cancellation(ctx: &WorkerContext) {
+        let mut pending: Vec<DownloadTask> = ctx.task_queue.lock().await.drain(..).collect();
+
+        for task in &mut pending {
+            if !ctx.config.keep_partial_on_cancel {
+                Self::remove_part_file(&task.part_path()).await;
+            }
+            task.state = TaskState::Cancelled;
+            Self::record_journal(&ctx.journal, task);
+            ctx.progress_tracker.track(task);
+        }
+        Self::update_overall_progress(&ctx.progress_tracker, &ctx.events);
+
+        log::info!(
+            "All download workers stopped by cancellation ({} pending tasks discarded)",
+            pending.len()
+        );
+        ctx.outcomes.publish(
+            &ctx.events,
+            DownloadEvent::Cancelled {
+                pending_tasks: pending.into_iter().map(|task| task.task_id).collect(),
+            },
+        );
+    }
+
+    /// ワーカーを一時停止状態にし、再開されるまで待機する
+    ///
+    /// # 副作用
+    /// - 全ワーカーが停止した時点で DownloadEvent::Paused を送信する
+    /// - 全停止状態から最初に再開したワーカーが DownloadEvent::Resumed を送信する
+    async fn pause_worker(worker_id: usize, ctx: &WorkerContext) {
+        let worker_count = ctx.worker_count;
+        log::info!("Download worker {} paused", worker_id);
+
+        if ctx.paused_workers.fetch_add(1, Ordering::SeqCst) + 1 == worker_count {
+            ctx.events.publish(DownloadEvent::Paused);
+            log::info!("All {} download workers paused", worker_count);
+        }
+
+        ctx.control.wait_while_paused().await;
+
+        if ctx.paused_workers.fetch_sub(1, Ordering::SeqCst) == worker_count
+            && !ctx.control.is_cancelled()
+        {
+            ctx.events.publish(DownloadEvent::Resumed);
+        }
+        log::info!("Download worker {} resumed", worker_id);
+    }
+
+    /// 一時停止・キャンセルにより転送を中断する
+    ///
+    /// # 事後条件
+    /// - 書き込み済みのデータは .part ファイルにフラッシュされる
+    async fn interrupt_transfer(
+        file: &mut File,
+        task: &mut DownloadTask,
+        downloaded: u64,
+        status: TransferStatus,
+    ) -> AppResult<TransferStatus> {
+        file.flush()
+            .await
+            .map_err(|e| AppError::io("Failed to flush file", Some(e)))?;
+        log::info!(
+            "Download interrupted ({:?}): task={}, offset={}",
+            status,
+            task.task_id,
+            downloaded
+        );
+        task.progress.downloaded_bytes = downloaded;
+        Ok(status)
     }
 
     /// ファイルをダウンロード
+    ///
+    /// # 副作用
+    /// - HTTPリクエストの送信
+    /// - 一時ファイル（.part）への書き込みと最終パスへのリネーム
+    ///
+    /// # 事前条件
+    /// - task.download_url は有効なURLである
+    ///
+    /// # 事後条件
+    /// - 完了時: output_path にファイルが配置され、.part ファイルは残らない
+    /// - 一時停止時: 書き込み済みの .part ファイルを保持して Paused を返す
+    /// - キャンセル時: ストリームを直ちに中断して Cancelled を返す（.part の扱いは呼び出し側が決める）
+    /// - 失敗時: 再開可能な .part ファイルは保持される
     async fn download_file(
-        http_client: &Client,
+        http_client: &DownloadClient,
         task: &mut DownloadTask,
-        event_sender: &Option<mpsc::UnboundedSender<DownloadEvent>>,
+        events: &EventBus<DownloadEvent>,
         config: &DownloadConfig,
-    ) -> AppResult<()> {
+        control: &DownloadControl,
+        tracker: &ProgressTracker,
+    ) -> AppResult<TransferStatus> {
         // 出力ディレクトリの作成
         if let Some(parent) = task.output_path.parent() {
             tokio::fs::create_dir_all(parent)
@@ -444,93 +1487,276 @@ impl DownloadComponent {
                 .map_err(|e| AppError::io("Failed to create output directory", Some(e)))?;
         }
 
-        // HTTPリクエスト
-        let response = http_client
-            .get(&task.download_url)
-            .send()
+        // 出力先の既存ファイルとの競合を解決
+        if let Some(existing_size) = Self::existing_file_size(&task.output_path).await {
+            match config.conflict_policy {
+                ConflictPolicy::Skip => {
+                    task.progress
+                        .update(existing_size, Some(existing_size), 0.0);
+                    return Ok(TransferStatus::Skipped);
+                }
+                ConflictPolicy::OverwriteIfSizeDiffers
+                    if task.expected_size == Some(existing_size) =>
+                {
+                    task.progress
+                        .update(existing_size, Some(existing_size), 0.0);
+                    return Ok(TransferStatus::Skipped);
+                }
+                ConflictPolicy::Rename => {
+                    task.output_path = unique_output_path(&task.output_path);
+                    log::info!(
+                        "Output file exists, saving as: {}",
+                        task.output_path.display()
+                    );
+                }
+                ConflictPolicy::Overwrite | ConflictPolicy::OverwriteIfSizeDiffers => {}
+            }
+        }
+
+        if Self::guard_disk_space(&task.output_path, config, control, events) {
+            return Ok(TransferStatus::Paused);
+        }
+
+        let part_path = task.part_path();
+
+        // 範囲リクエストに対応した大きなファイルは分割して並行取得する
+        if let Some(state) = Self::segment_plan(http_client, task, config).await {
+            return Self::download_segmented(
+                http_client,
+                task,
+                state,
+                events,
+                config,
+                control,
+                tracker,
+            )
+            .await;
+        }
+
+        // 既存の.partファイルから再開位置を決定
+        let mut resume_from = tokio::fs::metadata(&part_path)
             .await
-            .map_err(|e| AppError::network("Failed to send download request", Some(e)))?;
+            .map(|m| m.len())
+            .unwrap_or(0);
+
+        if let Some(expected_size) = task.expected_size {
+            if resume_from > expected_size {
+                // 期待サイズを超える.partは破損とみなして破棄
+                log::warn!(
+                    "Partial file larger than expected ({} > {}), restarting: {}",
+                    resume_from,
+                    expected_size,
+                    part_path.display()
+                );
+                Self::remove_part_file(&part_path).await;
+                resume_from = 0;
+            } else if resume_from == expected_size && expected_size > 0 {
+                // 前回の実行で全バイト取得済み: 確定処理のみ行う
+                log::info!(
+                    "Partial file already complete, finalizing: {}",
+                    part_path.display()
+                );
+                task.progress.update(resume_from, Some(expected_size), 0.0);
+                Self::finalize_part_file(task, None).await?;
+                return Ok(TransferStatus::Completed);
+            }
+        }
+
+        // HTTPリクエスト（.partがあればRangeリクエスト）
+        // 応答待ちの間もキャンセル・一時停止要求を優先し、.part を開く前に中断する
+        let mut pause_rx = control.paused.subscribe();
+        let mut response = tokio::select! {
+            biased;
+            _ = control.cancelled_signal() => return Ok(TransferStatus::Cancelled),
+            _ = DownloadControl::wait_for_pause(&mut pause_rx) => return Ok(TransferStatus::Paused),
+            response = Self::send_download_request(http_client, task, resume_from) => response?,
+        };
+
+        // 416: .partが不整合 → 破棄して最初から取得し直す
+        if resume_from > 0 && response.status() == reqwest::StatusCode::RANGE_NOT_SATISFIABLE {
+            log::warn!(
+                "Range not satisfiable for task={}, restarting from byte 0",
+                task.task_id
+            );
+            Self::remove_part_file(&part_path).await;
+            resume_from = 0;
+            response = tokio::select! {
+                biased;
+                _ = control.cancelled_signal() => return Ok(TransferStatus::Cancelled),
+                _ = DownloadControl::wait_for_pause(&mut pause_rx) => return Ok(TransferStatus::Paused),
+                response = Self::send_download_request(http_client, task, 0) => response?,
+            };
+        }
 
         // ステータスチェック
         if !response.status().is_success() {
             let status = response.status();
-            let error_body = response.text().await.unwrap_or_default();
+            let retry_after = response
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.parse::<u64>().ok());
+            let error_body = redact_text(&response.text().await.unwrap_or_default());
             log::error!(
                 "[DL-DIAG] Download HTTP error: task={}, status={}, body={}",
                 task.task_id,
                 status,
-                if error_body.len() > 500 {
-                    &error_body[..500]
-                } else {
-                    &error_body
-                }
+                truncate_text(&error_body, 500)
             );
-            return Err(AppError::external_service(format!(
+            let message = format!(
                 "Download failed with status: {} - {}",
                 status,
-                if error_body.len() > 200 {
-                    &error_body[..200]
-                } else {
-                    &error_body
-                }
-            )));
+                truncate_text(&error_body, 200)
+            );
+            return Err(Self::status_error(status, retry_after, message));
         }
 
-        // ファイルサイズ取得
-        let total_size = response
+        let content_length = response
             .headers()
             .get(reqwest::header::CONTENT_LENGTH)
             .and_then(|ct_len| ct_len.to_str().ok())
             .and_then(|ct_len| ct_len.parse::<u64>().ok());
 
+        // 206なら追記で再開、200ならサーバーがRangeを無視したため先頭から書き直す
+        let (mut file, offset, total_size) =
+            if resume_from > 0 && response.status() == reqwest::StatusCode::PARTIAL_CONTENT {
+                let content_range = response
+                    .headers()
+                    .get(reqwest::header::CONTENT_RANGE)
+                    .and_then(|v| v.to_str().ok())
+                    .and_then(parse_content_range);
+
+                let total_size = match content_range {
+                    Some((start, _, _)) if start != resume_from => {
+                        Self::remove_part_file(&part_path).await;
+                        return Err(AppError::data_integrity(format!(
+                            "Content-Range start mismatch: requested {}, got {}",
+                            resume_from, start
+                        )));
+                    }
+                    Some((_, _, total)) => total.or(content_length.map(|len| resume_from + len)),
+                    None => content_length.map(|len| resume_from + len),
+                };
+
+                log::info!(
+                    "Resuming download: task={}, offset={}",
+                    task.task_id,
+                    resume_from
+                );
+                let file = OpenOptions::new()
+                    .append(true)
+                    .open(&part_path)
+                    .await
+                    .map_err(|e| AppError::io("Failed to open partial file", Some(e)))?;
+                (file, resume_from, total_size)
+            } else {
+                if resume_from > 0 {
+                    log::info!(
+                        "Server ignored Range request (status {}), restarting from byte 0: task={}",
+                        response.status(),
+                        task.task_id
+                    );
+                }
+                let file = File::create(&part_path)
+                    .await
+                    .map_err(|e| AppError::io("Failed to create output file", Some(e)))?;
+                (file, 0, content_length)
+            };
+
         task.progress.total_bytes = total_size;
+        task.progress.downloaded_bytes = offset;
 
-        // ファイル作成
-        let mut file = File::create(&task.output_path)
-            .await
-            .map_err(|e| AppError::io("Failed to create output file", Some(e)))?;
+        // 受信しながら SHA-256 を計算する（再開時は取得済み部分から続ける）
+        let mut hasher = if offset > 0 {
+            Self::hash_file(part_path.clone()).await?
+        } else {
+            Sha256::new()
+        };
 
         // ストリームダウンロード
         let mut stream = response.bytes_stream();
-        let mut downloaded = 0u64;
+        let mut downloaded = offset;
+        let mut session_bytes = 0u64;
         let start_time = Instant::now();
         let mut last_update_time = start_time;
+        let mut last_space_check = start_time;
+        let mut watchdog = config.stall_detection.clone().map(StallWatchdog::new);
 
-        while let Some(chunk_result) = tokio::time::timeout(config.timeout, stream.next())
-            .await
-            .map_err(|_| {
+        loop {
+            // チャンク待機中でもキャンセル・一時停止要求を優先する
+            let wait_started = Instant::now();
+            let next_chunk = tokio::select! {
+                biased;
+                _ = control.cancelled_signal() => Err(TransferStatus::Cancelled),
+                _ = DownloadControl::wait_for_pause(&mut pause_rx) => Err(TransferStatus::Paused),
+                next = tokio::time::timeout(config.chunk_timeout(), stream.next()) => Ok(next),
+            };
+
+            let next_chunk = match next_chunk {
+                Ok(next_chunk) => next_chunk,
+                Err(status) => {
+                    return Self::interrupt_transfer(&mut file, task, downloaded, status).await;
+                }
+            };
+            Self::watch_stall(&mut watchdog, wait_started.elapsed(), &next_chunk)?;
+
+            let Some(chunk_result) = next_chunk.map_err(|_| {
                 AppError::network::<reqwest::Error>(
                     "Chunk read timed out: no data received within timeout period",
                     None,
                 )
             })?
-        {
-            let chunk =
-                chunk_result.map_err(|e| AppError::network("Failed to download chunk", Some(e)))?;
+            else {
+                break;
+            };
+
+            let chunk = chunk_result.map_err(|e| network_error("Failed to download chunk", e))?;
 
             file.write_all(&chunk)
                 .await
                 .map_err(|e| AppError::io("Failed to write chunk", Some(e)))?;
+            hasher.update(&chunk);
+            tracker.record_transferred(chunk.len() as u64);
 
             downloaded += chunk.len() as u64;
+            session_bytes += chunk.len() as u64;
+
+            // 空き容量の監視（不足時は一時停止を要求し、次のチャンク待機で中断する）
+            if last_space_check.elapsed() >= DISK_SPACE_CHECK_INTERVAL {
+                last_space_check = Instant::now();
+                Self::guard_disk_space(&task.output_path, config, control, events);
+            }
+
+            // 帯域制限（待機中もキャンセル・一時停止要求を優先する）
+            let throttled = tokio::select! {
+                biased;
+                _ = control.cancelled_signal() => Some(TransferStatus::Cancelled),
+                _ = DownloadControl::wait_for_pause(&mut pause_rx) => Some(TransferStatus::Paused),
+                _ = control.bandwidth.acquire(chunk.len() as u64) => None,
+            };
+            if let Some(status) = throttled {
+                return Self::interrupt_transfer(&mut file, task, downloaded, status).await;
+            }
 
             // 進捗更新（100msごと）
             let now = Instant::now();
             if now.duration_since(last_update_time) > Duration::from_millis(100) {
                 let elapsed = now.duration_since(start_time).as_secs_f64();
                 let speed = if elapsed > 0.0 {
-                    downloaded as f64 / elapsed
+                    session_bytes as f64 / elapsed
                 } else {
                     0.0
                 };
 
                 task.progress.update(downloaded, total_size, speed);
-
-                if let Some(sender) = event_sender {
-                    let _ = sender.send(DownloadEvent::ProgressUpdate {
-                        task_id: task.task_id.clone(),
-                        progress: task.progress.clone(),
-                    });
+                tracker.track(task);
+
+                events.publish(DownloadEvent::ProgressUpdate {
+                    task_id: task.task_id.clone(),
+                    progress: task.progress.clone(),
+                });
+                if tracker.should_emit() {
+                    events.publish(DownloadEvent::OverallProgressUpdate(tracker.snapshot()));
                 }
 
                 last_update_time = now;
@@ -541,6 +1767,7 @@ impl DownloadComponent {
         file.flush()
             .await
             .map_err(|e| AppError::io("Failed to flush file", Some(e)))?;
+        drop(file);
 
         // 最終進捗更新
         task.progress.downloaded_bytes = downloaded;
@@ -549,6 +1776,10 @@ impl DownloadComponent {
         // サイズ検証
         if let Some(expected_size) = task.expected_size {
             if downloaded != expected_size {
+                if downloaded > expected_size {
+                    // 超過分は再開できないため破棄
+                    Self::remove_part_file(&part_path).await;
+                }
                 return Err(AppError::data_integrity(format!(
                     "File size mismatch: expected {}, got {}",
                     expected_size, downloaded
@@ -556,88 +1787,617 @@ impl DownloadComponent {
             }
         }
 
-        Ok(())
+        Self::finalize_part_file(task, Some(hasher)).await?;
+        Ok(TransferStatus::Completed)
     }
 
-    /// 全体進捗を更新
-    async fn update_overall_progress(
-        _active_tasks: &Arc<RwLock<HashMap<String, DownloadTask>>>,
-        _event_sender: &Option<mpsc::UnboundedSender<DownloadEvent>>,
-    ) {
-        // TODO: 全体進捗の計算・通知
+    /// ダウンロードリクエストを送信（offset > 0 の場合はRangeヘッダー付き）
+    async fn send_download_request(
+        http_client: &DownloadClient,
+        task: &DownloadTask,
+        offset: u64,
+    ) -> AppResult<reqwest::Response> {
+        let range = (offset > 0).then(|| format!("bytes={}-", offset));
+        http_client.get(&task.download_url, range).await
     }
 
-    /// ダウンロードを停止
+    /// 分割ダウンロードの計画を決める
     ///
     /// # 副作用
-    /// - shutdown_signal が true に設定される
-    /// - ログ出力が実行される
-    /// - 実行中のダウンロードワーカーが停止される
-    ///
-    /// # 事前条件
-    /// - コンポーネントが初期化されている
+    /// - 新規の分割時はサーバーへ HEAD リクエストを送信する
     ///
     /// # 事後条件
-    /// - 成功時: ダウンロードが停止される
-    /// - 新しいダウンロードが開始されなくなる
-    /// - 実行中のダウンロードは完了まで継続される
-    ///
-    /// # 不変条件
-    /// - 既存のタスクキューは保持される
-    pub async fn stop_downloads(&self) -> AppResult<()> {
-        *self.shutdown_signal.write().await = true;
-        log::info!("Download component shutdown initiated");
-        Ok(())
-    }
-}
+    /// - 保存済みの分割状態があり .part と整合する場合はその状態を返す（再開）
+    /// - 期待サイズが閾値以上で、サーバーが Accept-Ranges: bytes と同じサイズを返した場合は新しい計画を返す
+    /// - それ以外（単一ストリームで取得する場合）は None
+    async fn segment_plan(
+        http_client: &DownloadClient,
+        task: &DownloadTask,
+        config: &DownloadConfig,
+    ) -> Option<SegmentState> {
+        let part_path = task.part_path();
+        let part_size = Self::existing_file_size(&part_path).await;
 
-#[async_trait]
-impl ComponentLifecycle for DownloadComponent {
-    async fn initialize(&mut self) -> AppResult<()> {
-        log::info!("Initializing DownloadComponent");
+        if let Some(state) = SegmentState::load(&part_path) {
+            if part_size == Some(state.total_size) {
+                return Some(state);
+            }
+            // .part と一致しない状態は破棄して取得し直す
+            log::warn!(
+                "Segment state does not match partial file, restarting: {}",
+                part_path.display()
+            );
+            Self::remove_part_file(&part_path).await;
+        } else if part_size.unwrap_or(0) > 0 {
+            // 単一ストリームで取得途中の .part はそのまま再開する
+            return None;
+        }
 
-        // 出力ディレクトリの作成
-        tokio::fs::create_dir_all(&self.config.output_directory)
+        let segmented = config.segmented.as_ref()?;
+        let expected_size = task
+            .expected_size
+            .filter(|size| segmented.applies_to(*size))?;
+
+        let response = tokio::time::timeout(config.timeout, http_client.head(&task.download_url))
             .await
-            .map_err(|e| AppError::io("Failed to create output directory", Some(e)))?;
+            .ok()?
+            .ok()?;
+        let accepts_ranges = response
+            .headers()
+            .get(reqwest::header::ACCEPT_RANGES)
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|v| v.split(',').any(|unit| unit.trim() == "bytes"));
+        let content_length = response
+            .headers()
+            .get(reqwest::header::CONTENT_LENGTH)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<u64>().ok());
 
-        log::info!("DownloadComponent initialized successfully");
-        Ok(())
+        if !response.status().is_success()
+            || !accepts_ranges
+            || content_length != Some(expected_size)
+        {
+            log::debug!(
+                "Range requests unavailable (status {}, length {:?}), using a single stream: task={}",
+                response.status(),
+                content_length,
+                task.task_id
+            );
+            return None;
+        }
+        Some(SegmentState::plan(expected_size, segmented.segments))
     }
 
-    async fn shutdown(&mut self) -> AppResult<()> {
-        log::info!("Shutting down DownloadComponent");
+    /// 複数のバイト範囲を並行取得して1つのファイルにまとめる
+    ///
+    /// # 副作用
+    /// - 新規時は .part ファイルを全体サイズで確保し、状態ファイル（.part.segments）を作成する
+    /// - セグメントごとに Range リクエストを送信し、対応する位置へ書き込む
+    ///
+    /// # 事後条件
+    /// - 完了時: 全セグメントとファイルサイズを検証してから output_path へ配置し、状態ファイルを削除する
+    /// - 一時停止・キャンセル・失敗時: セグメントごとの取得済みバイト数を保存する（次回はそこから再開）
+    async fn download_segmented(
+        http_client: &DownloadClient,
+        task: &mut DownloadTask,
+        mut state: SegmentState,
+        events: &EventBus<DownloadEvent>,
+        config: &DownloadConfig,
+        control: &DownloadControl,
+        tracker: &ProgressTracker,
+    ) -> AppResult<TransferStatus> {
+        let part_path = task.part_path();
+        let download_url = task.download_url.clone();
+
+        if !SegmentState::state_path(&part_path).exists() {
+            let file = File::create(&part_path)
+                .await
+                .map_err(|e| AppError::io("Failed to create output file", Some(e)))?;
+            file.set_len(state.total_size)
+                .await
+                .map_err(|e| AppError::io("Failed to allocate output file", Some(e)))?;
+            state.save(&part_path)?;
+        }
 
-        // ダウンロード停止
-        self.stop_downloads().await?;
+        let resumed_bytes = state.downloaded();
+        log::info!(
+            "Segmented download: task={}, segments={}, offset={}",
+            task.task_id,
+            state.segments.len(),
+            resumed_bytes
+        );
+        task.progress.total_bytes = Some(state.total_size);
+        task.progress.downloaded_bytes = resumed_bytes;
+
+        let progress: Vec<AtomicU64> = state
+            .segments
+            .iter()
+            .map(|segment| AtomicU64::new(segment.downloaded))
+            .collect();
+        let (abort, _) = watch::channel(false);
+        let transfer = SegmentTransfer {
+            http_client,
+            download_url: &download_url,
+            part_path: &part_path,
+            streams: state.segments.len(),
+            config,
+            control,
+            abort: &abort,
+            tracker,
+        };
+        let transfers = futures::future::join_all(
+            state
+                .segments
+                .iter()
+                .zip(&progress)
+                .filter(|(segment, _)| !segment.is_complete())
+                .map(|(segment, downloaded)| {
+                    Self::download_segment(&transfer, *segment, downloaded)
+                }),
+        );
+        let mut transfers = std::pin::pin!(transfers);
+
+        // 全セグメントの合計で進捗を通知する（100msごと）
+        let start_time = Instant::now();
+        let mut last_space_check = start_time;
+        let mut ticker = tokio::time::interval(Duration::from_millis(100));
+        let results = loop {
+            tokio::select! {
+                results = &mut transfers => break results,
+                _ = ticker.tick() => {
+                    let downloaded: u64 = progress.iter().map(|p| p.load(Ordering::SeqCst)).sum();
+                    let elapsed = start_time.elapsed().as_secs_f64();
+                    let speed = if elapsed > 0.0 {
+                        downloaded.saturating_sub(resumed_bytes) as f64 / elapsed
+                    } else {
+                        0.0
+                    };
+                    task.progress.update(downloaded, Some(state.total_size), speed);
+                    tracker.track(task);
+
+                    events.publish(DownloadEvent::ProgressUpdate {
+                        task_id: task.task_id.clone(),
+                        progress: task.progress.clone(),
+                    });
+                    if tracker.should_emit() {
+                        events.publish(DownloadEvent::OverallProgressUpdate(tracker.snapshot()));
+                    }
 
-        // アクティブタスクのログ
-        let active_tasks = self.active_tasks.read().await;
-        if !active_tasks.is_empty() {
-            log::warn!("Shutting down with {} active downloads", active_tasks.len());
+                    if last_space_check.elapsed() >= DISK_SPACE_CHECK_INTERVAL {
+                        last_space_check = Instant::now();
+                        Self::guard_disk_space(&task.output_path, config, control, events);
+                    }
+                }
+            }
+        };
+
+        for (segment, downloaded) in state.segments.iter_mut().zip(&progress) {
+            segment.downloaded = downloaded.load(Ordering::SeqCst);
+        }
+        task.progress.downloaded_bytes = state.downloaded();
+
+        let mut interrupted = None;
+        let mut failure = None;
+        for result in results {
+            match result {
+                Ok(None) => {}
+                Ok(Some(status)) => {
+                    if interrupted.is_none() || status == TransferStatus::Cancelled {
+                        interrupted = Some(status);
+                    }
+                }
+                Err(e) => {
+                    failure.get_or_insert(e);
+                }
+            }
+        }
+        if let Some(e) = failure {
+            state.save(&part_path)?;
+            return Err(e);
+        }
+        if let Some(status) = interrupted {
+            state.save(&part_path)?;
+            log::info!(
+                "Download interrupted ({:?}): task={}, downloaded={}",
+                status,
+                task.task_id,
+                state.downloaded()
+            );
+            return Ok(status);
         }
 
-        log::info!("DownloadComponent shut down successfully");
-        Ok(())
-    }
+        // 全セグメントの取得済みバイト数とファイルサイズを検証
+        let file_size = Self::existing_file_size(&part_path).await.unwrap_or(0);
+        if let Err(e) = state.verify(file_size) {
+            Self::remove_part_file(&part_path).await;
+            return Err(e);
+        }
+        SegmentState::remove(&part_path);
 
-    async fn health_check(&self) -> bool {
-        // シャットダウン中でないことを確認
-        !*self.shutdown_signal.read().await
+        task.progress.downloaded_bytes = state.total_size;
+        task.progress.percentage = 1.0;
+        // セグメントは順不同に書き込まれるため、ハッシュは完成したファイルから計算する
+        Self::finalize_part_file(task, None).await?;
+        Ok(TransferStatus::Completed)
     }
-}
 
-impl Configurable<DownloadConfig> for DownloadComponent {
-    fn update_config(&mut self, config: DownloadConfig) -> AppResult<()> {
-        self.config = config;
+    /// 1つのセグメントを取得する
+    ///
+    /// # 事後条件
+    /// - 完了時は Ok(None)、一時停止・キャンセル・他セグメントの失敗で中断した場合は Ok(Some(状態))
+    /// - downloaded には書き込み済みのバイト数が反映され、ファイルはフラッシュされる
+    /// - 失敗時は他のセグメントへ中断を通知する
+    async fn download_segment(
+        transfer: &SegmentTransfer<'_>,
+        segment: Segment,
+        downloaded: &AtomicU64,
+    ) -> AppResult<Option<TransferStatus>> {
+        let mut file = OpenOptions::new()
+            .write(true)
+            .open(transfer.part_path)
+            .await
+            .map_err(|e| AppError::io("Failed to open partial file", Some(e)))?;
+        file.seek(std::io::SeekFrom::Start(segment.offset()))
+            .await
+            .map_err(|e| AppError::io("Failed to seek partial file", Some(e)))?;
 
-        // HTTPクライアントの再構築
-        self.http_client = Client::builder()
-            .connect_timeout(Duration::from_secs(30))
-            .build()
-            .map_err(|e| AppError::configuration("Failed to create HTTP client", Some(e)))?;
+        let result = Self::stream_segment(transfer, segment, downloaded, &mut file).await;
+        let flushed = file
+            .flush()
+            .await
+            .map_err(|e| AppError::io("Failed to flush file", Some(e)));
+        if result.is_err() {
+            transfer.abort.send_replace(true);
+        }
+        let status = result?;
+        flushed?;
+        Ok(status)
+    }
 
-        log::info!("DownloadComponent configuration updated");
+    /// セグメントの Range リクエストを送信し、受信したデータをファイルへ書き込む
+    async fn stream_segment(
+        transfer: &SegmentTransfer<'_>,
+        segment: Segment,
+        downloaded: &AtomicU64,
+        file: &mut File,
+    ) -> AppResult<Option<TransferStatus>> {
+        let control = transfer.control;
+        let mut pause_rx = control.paused.subscribe();
+        let mut abort_rx = transfer.abort.subscribe();
+        let mut position = segment.offset();
+
+        let request = transfer.http_client.get(
+            transfer.download_url,
+            Some(format!("bytes={}-{}", position, segment.end - 1)),
+        );
+        let response = tokio::select! {
+            biased;
+            _ = control.cancelled_signal() => return Ok(Some(TransferStatus::Cancelled)),
+            _ = DownloadControl::wait_for_pause(&mut abort_rx) => return Ok(Some(TransferStatus::Paused)),
+            response = request => response?,
+        };
+
+        let status = response.status();
+        if status != reqwest::StatusCode::PARTIAL_CONTENT {
+            if status.is_success() {
+                return Err(AppError::data_integrity(format!(
+                    "Server ignored range request for segment {}-{} (status {})",
+                    segment.start, segment.end, status
+                )));
+            }
+            let retry_after = response
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.parse::<u64>().ok());
+            return Err(Self::status_error(
+                status,
+                retry_after,
+                format!("Segment download failed with status: {}", status),
+            ));
+        }
+        let content_range = response
+            .headers()
+            .get(reqwest::header::CONTENT_RANGE)
+            .and_then(|v| v.to_str().ok())
+            .and_then(parse_content_range);
+        if let Some((start, _, _)) = content_range.filter(|(start, _, _)| *start != position) {
+            return Err(AppError::data_integrity(format!(
+                "Content-Range start mismatch: requested {}, got {}",
+                position, start
+            )));
+        }
+
+        let mut stream = response.bytes_stream();
+        let mut watchdog = transfer
+            .config
+            .stall_detection
+            .as_ref()
+            .map(|stall| StallWatchdog::new(stall.per_stream(transfer.streams)));
+        loop {
+            let wait_started = Instant::now();
+            let next_chunk = tokio::select! {
+                biased;
+                _ = control.cancelled_signal() => return Ok(Some(TransferStatus::Cancelled)),
+                _ = DownloadControl::wait_for_pause(&mut pause_rx) => return Ok(Some(TransferStatus::Paused)),
+                _ = DownloadControl::wait_for_pause(&mut abort_rx) => return Ok(Some(TransferStatus::Paused)),
+                next = tokio::time::timeout(transfer.config.chunk_timeout(), stream.next()) => next,
+            };
+            Self::watch_stall(&mut watchdog, wait_started.elapsed(), &next_chunk)?;
+            let Some(chunk_result) = next_chunk.map_err(|_| {
+                AppError::network::<reqwest::Error>(
+                    "Chunk read timed out: no data received within timeout period",
+                    None,
+                )
+            })?
+            else {
+                break;
+            };
+            let chunk = chunk_result.map_err(|e| network_error("Failed to download chunk", e))?;
+
+            if position + chunk.len() as u64 > segment.end {
+                return Err(AppError::data_integrity(format!(
+                    "Segment {}-{} received more data than requested",
+                    segment.start, segment.end
+                )));
+            }
+            file.write_all(&chunk)
+                .await
+                .map_err(|e| AppError::io("Failed to write chunk", Some(e)))?;
+            position += chunk.len() as u64;
+            downloaded.fetch_add(chunk.len() as u64, Ordering::SeqCst);
+            transfer.tracker.record_transferred(chunk.len() as u64);
+
+            let throttled = tokio::select! {
+                biased;
+                _ = control.cancelled_signal() => Some(TransferStatus::Cancelled),
+                _ = DownloadControl::wait_for_pause(&mut pause_rx) => Some(TransferStatus::Paused),
+                _ = control.bandwidth.acquire(chunk.len() as u64) => None,
+            };
+            if throttled.is_some() {
+                return Ok(throttled);
+            }
+        }
+
+        if position != segment.end {
+            // 途中で切断された: 取得済みの位置から再開できる
+            return Err(AppError::network::<reqwest::Error>(
+                format!(
+                    "Segment {}-{} ended early at byte {}",
+                    segment.start, segment.end, position
+                ),
+                None,
+            ));
+        }
+        Ok(None)
+    }
+
+    /// チャンク待機の結果を停滞監視に記録する
+    ///
+    /// # 事後条件
+    /// - 評価期間の転送速度が下限を下回った場合は AppError::Stalled
+    /// - ストリーム終端は記録しない（完了した転送を停滞と判定しない）
+    fn watch_stall<B: AsRef<[u8]>, E>(
+        watchdog: &mut Option<StallWatchdog>,
+        waited: Duration,
+        next_chunk: &Result<Option<Result<B, E>>, tokio::time::error::Elapsed>,
+    ) -> AppResult<()> {
+        let Some(watchdog) = watchdog else {
+            return Ok(());
+        };
+        match next_chunk {
+            Ok(Some(Ok(chunk))) => watchdog.record(waited, chunk.as_ref().len() as u64),
+            Err(_) => watchdog.record(waited, 0),
+            Ok(Some(Err(_))) | Ok(None) => return Ok(()),
+        }
+        watchdog.check()
+    }
+
+    /// .partファイルをディスクへ同期してから最終パスへリネームして確定する
+    ///
+    /// # 事前条件
+    /// - サイズ検証が完了している
+    /// - hasher は .part の全内容を計算済みである（None の場合は .part を読み込んで計算する）
+    ///
+    /// # 事後条件
+    /// - 最終パスには常に全バイトが書き込まれたファイルのみが現れる
+    /// - task.sha256 にファイル内容の SHA-256 が設定される
+    async fn finalize_part_file(task: &mut DownloadTask, hasher: Option<Sha256>) -> AppResult<()> {
+        let part_path = task.part_path();
+        let hasher = match hasher {
+            Some(hasher) => hasher,
+            None => Self::hash_file(part_path.clone()).await?,
+        };
+        task.sha256 = Some(format!("{:x}", hasher.finalize()));
+
+        let part_file = OpenOptions::new()
+            .write(true)
+            .open(&part_path)
+            .await
+            .map_err(|e| AppError::io("Failed to open partial file", Some(e)))?;
+        part_file
+            .sync_all()
+            .await
+            .map_err(|e| AppError::io("Failed to sync downloaded file", Some(e)))?;
+        drop(part_file);
+
+        tokio::fs::rename(&part_path, &task.output_path)
+            .await
+            .map_err(|e| AppError::io("Failed to finalize downloaded file", Some(e)))
+    }
+
+    /// ファイル内容を読み込んだ SHA-256 の計算状態を返す（ブロッキングスレッドで実行）
+    async fn hash_file(path: PathBuf) -> AppResult<Sha256> {
+        tokio::task::spawn_blocking(move || sha256_hasher(&path))
+            .await
+            .map_err(|e| AppError::io("Failed to hash downloaded file", Some(e)))?
+    }
+
+    /// 出力先の空き容量が確保容量を下回っていれば一時停止を要求する
+    ///
+    /// # 副作用
+    /// - 容量不足時は DownloadControl::pause を呼び、DownloadEvent::LowDiskSpace を送信する
+    ///
+    /// # 事後条件
+    /// - 容量不足で一時停止を要求した場合は true
+    /// - 監視が無効、または空き容量を取得できない場合は false
+    fn guard_disk_space(
+        output_path: &std::path::Path,
+        config: &DownloadConfig,
+        control: &DownloadControl,
+        events: &EventBus<DownloadEvent>,
+    ) -> bool {
+        if config.min_free_space_bytes == 0 {
+            return false;
+        }
+        let directory = output_path.parent().unwrap_or(&config.output_directory);
+        let available_bytes = match disk_space::available_space(directory) {
+            Ok(available_bytes) => available_bytes,
+            Err(e) => {
+                log::warn!("Free disk space check failed: {}", e);
+                return false;
+            }
+        };
+        if available_bytes >= config.min_free_space_bytes {
+            return false;
+        }
+
+        // 複数ワーカーが同時に検出しても通知は一度だけ
+        if !control.is_paused() {
+            log::warn!(
+                "Low disk space ({} bytes free, reserve {}), pausing downloads",
+                available_bytes,
+                config.min_free_space_bytes
+            );
+            control.pause();
+            events.publish(DownloadEvent::LowDiskSpace {
+                available_bytes,
+                reserve_bytes: config.min_free_space_bytes,
+            });
+        }
+        true
+    }
+
+    /// HTTPエラーステータスをリトライ判定可能なエラー種別に変換する
+    ///
+    /// # 事後条件
+    /// - 401 は認証エラー、404 は not_found、429 はレート制限（Retry-After 付き）
+    /// - その他はステータスコードを保持した API エラー（5xx のみ回復可能）
+    fn status_error(
+        status: reqwest::StatusCode,
+        retry_after: Option<u64>,
+        message: String,
+    ) -> AppError {
+        match status {
+            reqwest::StatusCode::UNAUTHORIZED => {
+                AppError::authentication(message, None::<std::io::Error>)
+            }
+            reqwest::StatusCode::NOT_FOUND => AppError::not_found(message),
+            reqwest::StatusCode::TOO_MANY_REQUESTS => {
+                AppError::rate_limit_with_retry(message, retry_after)
+            }
+            _ => AppError::api(status.as_u16(), message, None::<std::io::Error>),
+        }
+    }
+
+    /// 出力先に既存ファイルがあればそのサイズを返す
+    async fn existing_file_size(path: &std::path::Path) -> Option<u64> {
+        tokio::fs::metadata(path)
+            .await
+            .ok()
+            .filter(|metadata| metadata.is_file())
+            .map(|metadata| metadata.len())
+    }
+
+    /// .partファイルを削除（存在しない場合は何もしない、分割ダウンロードの状態ファイルも削除）
+    async fn remove_part_file(part_path: &std::path::Path) {
+        SegmentState::remove(part_path);
+        if tokio::fs::remove_file(part_path).await.is_ok() {
+            log::info!("Removed partial file: {}", part_path.display());
+        }
+    }
+
+    /// 全体進捗を通知（タスク状態の変化時は間引かずに送信する）
+    fn update_overall_progress(tracker: &ProgressTracker, events: &EventBus<DownloadEvent>) {
+        events.publish(DownloadEvent::OverallProgressUpdate(tracker.snapshot()));
+    }
+
+    /// 現在の全体進捗を取得
+    pub fn overall_progress(&self) -> OverallProgress {
+        self.progress_tracker.snapshot()
+    }
+
+    /// ダウンロードを停止
+    ///
+    /// # 副作用
+    /// - shutdown_signal が true に設定される
+    /// - ログ出力が実行される
+    /// - 実行中のダウンロードワーカーが停止される
+    ///
+    /// # 事前条件
+    /// - コンポーネントが初期化されている
+    ///
+    /// # 事後条件
+    /// - 成功時: ダウンロードが停止される
+    /// - 新しいダウンロードが開始されなくなる
+    /// - 実行中のダウンロードは完了まで継続される
+    ///
+    /// # 不変条件
+    /// - 既存のタスクキューは保持される
+    pub async fn stop_downloads(&self) -> AppResult<()> {
+        *self.shutdown_signal.write().await = true;
+        log::info!("Download component shutdown initiated");
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ComponentLifecycle for DownloadComponent {
+    async fn initialize(&mut self) -> AppResult<()> {
+        log::info!("Initializing DownloadComponent");
+
+        // 出力ディレクトリの作成
+        tokio::fs::create_dir_all(&self.config.output_directory)
+            .await
+            .map_err(|e| AppError::io("Failed to create output directory", Some(e)))?;
+
+        log::info!("DownloadComponent initialized successfully");
+        Ok(())
+    }
+
+    async fn shutdown(&mut self) -> AppResult<()> {
+        log::info!("Shutting down DownloadComponent");
+
+        // ダウンロード停止
+        self.stop_downloads().await?;
+
+        // アクティブタスクのログ
+        let active_tasks = self.active_tasks.read().await;
+        if !active_tasks.is_empty() {
+            log::warn!("Shutting down with {} active downloads", active_tasks.len());
+        }
+
+        log::info!("DownloadComponent shut down successfully");
+        Ok(())
+    }
+
+    async fn health_check(&self) -> bool {
+        // シャットダウン中でないことを確認
+        !*self.shutdown_signal.read().await
+    }
+}
+
+impl Configurable<DownloadConfig> for DownloadComponent {
+    fn update_config(&mut self, config: DownloadConfig) -> AppResult<()> {
+        self.config = config;
+
+        // HTTPクライアントの再構築
+        self.http_client = Client::builder()
+            .connect_timeout(Duration::from_secs(30))
+            .build()
+            .map_err(|e| AppError::configuration("Failed to create HTTP client", Some(e)))?;
+
+        log::info!("DownloadComponent configuration updated");
         Ok(())
     }
 
@@ -646,9 +2406,51 @@ impl Configurable<DownloadConfig> for DownloadComponent {
     }
 }
 
+/// 既存ファイルと重ならない連番付きのパスを返す（例: "video (1).mp4"）
+///
+/// # 事後条件
+/// - 返すパスと、その .part ファイルはどちらも存在しない
+fn unique_output_path(path: &std::path::Path) -> PathBuf {
+    let stem = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let extension = path
+        .extension()
+        .map(|ext| format!(".{}", ext.to_string_lossy()))
+        .unwrap_or_default();
+
+    (1u32..)
+        .map(|n| path.with_file_name(format!("{} ({}){}", stem, n, extension)))
+        .find(|candidate| {
+            let mut part = candidate.clone().into_os_string();
+            part.push(".part");
+            !candidate.exists() && !PathBuf::from(part).exists()
+        })
+        .expect("unbounded candidate sequence")
+}
+
+/// Content-Range ヘッダーを解析する
+///
+/// # 事後条件
+/// - "bytes start-end/total" 形式の場合 Some((start, end, total)) を返す
+/// - total が "*" の場合は None として返す
+/// - 形式が不正な場合は None を返す
+fn parse_content_range(value: &str) -> Option<(u64, u64, Option<u64>)> {
+    let range = value.trim().strip_prefix("bytes ")?;
+    let (span, total) = range.split_once('/')?;
+    let (start, end) = span.split_once('-')?;
+    let total = match total.trim() {
+        "*" => None,
+        t => Some(t.parse().ok()?),
+    };
+    Some((start.trim().parse().ok()?, end.trim().parse().ok()?, total))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::components::event_bus::{BusMessage, EventEnvelope};
 
     #[test]
     fn test_download_progress_calculation() {
@@ -725,4 +2527,1238 @@ mod tests {
             .await;
         assert!(result.is_err());
     }
+
+    /// 次のイベントを最大5秒待って受信する（取りこぼしはテスト失敗とする）
+    async fn next_event(rx: &mut EventSubscriber<DownloadEvent>) -> Option<DownloadEvent> {
+        match tokio::time::timeout(Duration::from_secs(5), rx.recv()).await {
+            Ok(Some(BusMessage::Event(envelope))) => Some(envelope.event),
+            Ok(Some(BusMessage::Lagged { skipped })) => panic!("{} event(s) skipped", skipped),
+            Ok(None) | Err(_) => None,
+        }
+    }
+
+    fn make_test_task(
+        output_dir: &std::path::Path,
+        url: String,
+        expected: Option<u64>,
+    ) -> DownloadTask {
+        DownloadTask {
+            task_id: "task-1".to_string(),
+            download_url: url,
+            output_path: output_dir.join("video.mp4"),
+            expected_size: expected,
+            file_name: "video.mp4".to_string(),
+            state: TaskState::Pending,
+            progress: DownloadProgress::new(),
+            error: None,
+            retry_count: 0,
+            priority: TaskPriority::default(),
+            attempts: Vec::new(),
+            retry_at: None,
+            sha256: None,
+        }
+    }
+
+    #[test]
+    fn test_next_task_orders_by_priority_then_insertion() {
+        let dir = std::path::Path::new("downloads");
+        let make = |task_id: &str, priority: TaskPriority| DownloadTask {
+            task_id: task_id.to_string(),
+            priority,
+            ..make_test_task(dir, "https://example.com".to_string(), None)
+        };
+        let mut queue: VecDeque<DownloadTask> = VecDeque::from(vec![
+            make("video-1", TaskPriority::Normal),
+            make("video-2", TaskPriority::Normal),
+            make("chat", TaskPriority::High),
+            make("transcript", TaskPriority::High),
+        ]);
+        let control = DownloadControl::new();
+        let now = Instant::now();
+
+        // 実行中の変更: video-1 を後回し、video-2 をバックオフ中にする
+        control.set_task_priority("video-1", TaskPriority::Deferred);
+        queue[1].retry_at = Some(now + Duration::from_secs(60));
+
+        let order: Vec<String> =
+            std::iter::from_fn(|| DownloadComponent::next_task(&mut queue, &control, now))
+                .map(|task| task.task_id)
+                .collect();
+        assert_eq!(order, vec!["chat", "transcript", "video-1"]);
+        assert_eq!(queue.len(), 1);
+        assert_eq!(queue[0].task_id, "video-2");
+    }
+
+    #[test]
+    fn test_task_priority_defaults_and_steps() {
+        assert_eq!(
+            TaskPriority::for_file_type(&RecordingFileType::Transcript),
+            TaskPriority::High
+        );
+        assert_eq!(
+            TaskPriority::for_file_type(&RecordingFileType::ClosedCaption),
+            TaskPriority::High
+        );
+        assert_eq!(
+            TaskPriority::for_file_type(&RecordingFileType::MP4),
+            TaskPriority::Normal
+        );
+        assert_eq!(TaskPriority::Deferred.raised(), TaskPriority::Low);
+        assert_eq!(TaskPriority::High.raised(), TaskPriority::High);
+        assert_eq!(TaskPriority::High.lowered(), TaskPriority::Normal);
+        assert_eq!(TaskPriority::Low.lowered(), TaskPriority::Low);
+    }
+
+    #[test]
+    fn test_progress_tracker_weights_by_bytes_and_skips_failed() {
+        let dir = std::path::Path::new("downloads");
+        let tracker = ProgressTracker::default();
+
+        let mut active = make_test_task(dir, "https://example.com/a".to_string(), Some(300));
+        active.task_id = "active".to_string();
+        active.state = TaskState::InProgress;
+        active.progress.update(100, Some(300), 50.0);
+        tracker.track(&active);
+
+        let mut done = make_test_task(dir, "https://example.com/b".to_string(), Some(100));
+        done.task_id = "done".to_string();
+        done.state = TaskState::Completed;
+        done.progress.update(100, Some(100), 0.0);
+        tracker.track(&done);
+
+        let mut failed = make_test_task(dir, "https://example.com/c".to_string(), Some(600));
+        failed.task_id = "failed".to_string();
+        failed.state = TaskState::Failed;
+        failed.progress.update(200, Some(600), 0.0);
+        tracker.track(&failed);
+
+        let overall = tracker.snapshot();
+        assert_eq!(overall.total_tasks, 3);
+        // 受信累計は状態の変化で減らない
+        tracker.record_transferred(200);
+        failed.progress.update(0, Some(600), 0.0);
+        tracker.track(&failed);
+        assert_eq!(tracker.transferred_bytes(), 200);
+        assert_eq!(overall.completed_tasks, 1);
+        assert_eq!(overall.failed_tasks, 1);
+        assert_eq!(overall.active_tasks, 1);
+        assert_eq!(overall.total_bytes, 400);
+        assert_eq!(overall.downloaded_bytes, 200);
+        assert_eq!(overall.overall_percentage, 0.5);
+        assert_eq!(overall.current_speed, 50.0);
+        assert_eq!(overall.eta, Some(Duration::from_secs(4)));
+    }
+
+    #[test]
+    fn test_parse_content_range() {
+        assert_eq!(
+            parse_content_range("bytes 5-10/11"),
+            Some((5, 10, Some(11)))
+        );
+        assert_eq!(parse_content_range("bytes 0-99/*"), Some((0, 99, None)));
+        assert_eq!(parse_content_range("items 0-1/2"), None);
+    }
+
+    #[tokio::test]
+    async fn test_download_file_resumes_from_part_file() {
+        use wiremock::matchers::{header, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/video.mp4"))
+            .and(header("Range", "bytes=5-"))
+            .respond_with(
+                ResponseTemplate::new(206)
+                    .insert_header("Content-Range", "bytes 5-10/11")
+                    .set_body_bytes(b" world".to_vec()),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut task = make_test_task(
+            temp_dir.path(),
+            format!("{}/video.mp4", server.uri()),
+            Some(11),
+        );
+        std::fs::write(task.part_path(), b"hello").unwrap();
+
+        let client = DownloadClient::from(Client::new());
+        let config = DownloadConfig::default();
+        let control = DownloadControl::new();
+        DownloadComponent::download_file(
+            &client,
+            &mut task,
+            &EventBus::default(),
+            &config,
+            &control,
+            &ProgressTracker::default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(std::fs::read(&task.output_path).unwrap(), b"hello world");
+        assert!(!task.part_path().exists());
+        // 再開前の部分ファイルも含めたファイル全体のハッシュ
+        assert_eq!(
+            task.sha256.as_deref(),
+            Some("b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9")
+        );
+    }
+
+    /// Range ヘッダー（bytes=a-b）に従って本文の一部を返すテスト用レスポンダー
+    struct RangeResponder(Vec<u8>);
+
+    impl wiremock::Respond for RangeResponder {
+        fn respond(&self, request: &wiremock::Request) -> wiremock::ResponseTemplate {
+            let range = range_header(request);
+            let range = range
+                .as_deref()
+                .and_then(|v| v.strip_prefix("bytes="))
+                .and_then(|v| v.split_once('-'))
+                .and_then(|(start, end)| Some((start.parse::<usize>().ok()?, end.parse().ok()?)));
+            match range {
+                Some((start, end)) => wiremock::ResponseTemplate::new(206)
+                    .insert_header(
+                        "Content-Range",
+                        format!("bytes {}-{}/{}", start, end, self.0.len()).as_str(),
+                    )
+                    .set_body_bytes(self.0[start..=end].to_vec()),
+                None => wiremock::ResponseTemplate::new(200).set_body_bytes(self.0.clone()),
+            }
+        }
+    }
+
+    fn range_header(request: &wiremock::Request) -> Option<String> {
+        request
+            .headers
+            .get(&"Range".into())
+            .map(|values| values.as_str().to_string())
+    }
+
+    async fn mount_range_server(body: &[u8]) -> wiremock::MockServer {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("HEAD"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("Accept-Ranges", "bytes")
+                    .set_body_bytes(body.to_vec()),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(RangeResponder(body.to_vec()))
+            .mount(&server)
+            .await;
+        server
+    }
+
+    fn segmented_config(segments: usize) -> DownloadConfig {
+        DownloadConfig {
+            segmented: Some(SegmentedDownload {
+                segments,
+                min_size_bytes: 1,
+            }),
+            ..DownloadConfig::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_download_file_fetches_segments_in_parallel() {
+        let body: Vec<u8> = (0..1000u32).map(|i| (i % 251) as u8).collect();
+        let server = mount_range_server(&body).await;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut task = make_test_task(
+            temp_dir.path(),
+            format!("{}/video.mp4", server.uri()),
+            Some(body.len() as u64),
+        );
+        let status = DownloadComponent::download_file(
+            &Client::new().into(),
+            &mut task,
+            &EventBus::default(),
+            &segmented_config(3),
+            &DownloadControl::new(),
+            &ProgressTracker::default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(status, TransferStatus::Completed);
+        assert_eq!(std::fs::read(&task.output_path).unwrap(), body);
+        assert!(!task.part_path().exists());
+        assert!(!SegmentState::state_path(&task.part_path()).exists());
+        assert_eq!(task.progress.downloaded_bytes, 1000);
+
+        let mut ranges: Vec<String> = server
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .filter_map(range_header)
+            .collect();
+        ranges.sort();
+        assert_eq!(
+            ranges,
+            vec!["bytes=0-333", "bytes=334-666", "bytes=667-999"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_download_file_resumes_segments_from_saved_state() {
+        let body: Vec<u8> = (0..100u8).collect();
+        let server = mount_range_server(&body).await;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut task = make_test_task(
+            temp_dir.path(),
+            format!("{}/video.mp4", server.uri()),
+            Some(body.len() as u64),
+        );
+        // 前半は取得済み、後半は 10 バイトのみ取得済みの状態
+        let mut partial = vec![0u8; 100];
+        partial[..60].copy_from_slice(&body[..60]);
+        std::fs::write(task.part_path(), &partial).unwrap();
+        let mut state = SegmentState::plan(100, 2);
+        state.segments[0].downloaded = 50;
+        state.segments[1].downloaded = 10;
+        state.save(&task.part_path()).unwrap();
+
+        DownloadComponent::download_file(
+            &Client::new().into(),
+            &mut task,
+            &EventBus::default(),
+            &segmented_config(2),
+            &DownloadControl::new(),
+            &ProgressTracker::default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(std::fs::read(&task.output_path).unwrap(), body);
+        let requests = server.received_requests().await.unwrap();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(range_header(&requests[0]).as_deref(), Some("bytes=60-99"));
+    }
+
+    #[tokio::test]
+    async fn test_download_file_restarts_when_range_ignored() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"hello world".to_vec()))
+            .mount(&server)
+            .await;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut task = make_test_task(
+            temp_dir.path(),
+            format!("{}/video.mp4", server.uri()),
+            Some(11),
+        );
+        std::fs::write(task.part_path(), b"stale").unwrap();
+
+        let client = DownloadClient::from(Client::new());
+        let config = DownloadConfig::default();
+        let control = DownloadControl::new();
+        DownloadComponent::download_file(
+            &client,
+            &mut task,
+            &EventBus::default(),
+            &config,
+            &control,
+            &ProgressTracker::default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(std::fs::read(&task.output_path).unwrap(), b"hello world");
+        assert!(!task.part_path().exists());
+    }
+
+    #[tokio::test]
+    async fn test_download_file_keeps_part_file_on_short_body() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"hello".to_vec()))
+            .mount(&server)
+            .await;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut task = make_test_task(
+            temp_dir.path(),
+            format!("{}/video.mp4", server.uri()),
+            Some(11),
+        );
+
+        let client = DownloadClient::from(Client::new());
+        let config = DownloadConfig::default();
+        let control = DownloadControl::new();
+        let result = DownloadComponent::download_file(
+            &client,
+            &mut task,
+            &EventBus::default(),
+            &config,
+            &control,
+            &ProgressTracker::default(),
+        )
+        .await;
+
+        assert!(result.is_err());
+        assert!(!task.output_path.exists());
+        assert_eq!(std::fs::read(task.part_path()).unwrap(), b"hello");
+    }
+
+    #[tokio::test]
+    async fn test_download_file_aborts_stalled_transfer() {
+        use tokio::io::AsyncReadExt;
+
+        // 1バイトずつ送り続ける低速サーバー
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = [0u8; 1024];
+            let _ = socket.read(&mut request).await;
+            let _ = socket
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 1000\r\n\r\n")
+                .await;
+            for _ in 0..1000 {
+                if socket.write_all(b"x").await.is_err() {
+                    break;
+                }
+                tokio::time::sleep(Duration::from_millis(20)).await;
+            }
+        });
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut task = make_test_task(
+            temp_dir.path(),
+            format!("http://{}/video.mp4", address),
+            Some(1000),
+        );
+        let config = DownloadConfig {
+            stall_detection: Some(StallDetection {
+                min_bytes_per_sec: 1000,
+                window: Duration::from_millis(300),
+            }),
+            ..DownloadConfig::default()
+        };
+        let result = tokio::time::timeout(
+            Duration::from_secs(5),
+            DownloadComponent::download_file(
+                &Client::new().into(),
+                &mut task,
+                &EventBus::default(),
+                &config,
+                &DownloadControl::new(),
+                &ProgressTracker::default(),
+            ),
+        )
+        .await
+        .expect("stalled transfer was not aborted");
+
+        let error = result.unwrap_err();
+        assert!(matches!(error, AppError::Stalled { .. }));
+        assert!(error.is_recoverable());
+        assert_eq!(FailureReason::from_error(&error), FailureReason::Stalled);
+        // 受信済みのデータは再開用に保持される
+        assert!(task.part_path().exists());
+    }
+
+    #[tokio::test]
+    async fn test_download_file_returns_paused_and_continues_after_resume() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"hello world".to_vec()))
+            .mount(&server)
+            .await;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut task = make_test_task(
+            temp_dir.path(),
+            format!("{}/video.mp4", server.uri()),
+            Some(11),
+        );
+
+        let client = DownloadClient::from(Client::new());
+        let config = DownloadConfig::default();
+        let control = DownloadControl::new();
+        control.pause();
+
+        let status = DownloadComponent::download_file(
+            &client,
+            &mut task,
+            &EventBus::default(),
+            &config,
+            &control,
+            &ProgressTracker::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(status, TransferStatus::Paused);
+        assert!(!task.output_path.exists());
+
+        control.resume();
+        let status = DownloadComponent::download_file(
+            &client,
+            &mut task,
+            &EventBus::default(),
+            &config,
+            &control,
+            &ProgressTracker::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(status, TransferStatus::Completed);
+        assert_eq!(std::fs::read(&task.output_path).unwrap(), b"hello world");
+    }
+
+    #[tokio::test]
+    async fn test_every_subscriber_observes_the_same_run() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"hello".to_vec()))
+            .mount(&server)
+            .await;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let component = DownloadComponent::new(DownloadConfig {
+            output_directory: temp_dir.path().to_path_buf(),
+            ..DownloadConfig::default()
+        });
+        let mut subscribers = [component.subscribe(), component.subscribe()];
+        component
+            .add_download_task(
+                "task-1".to_string(),
+                format!("{}/video.mp4", server.uri()),
+                "video.mp4".to_string(),
+                Some(5),
+            )
+            .await
+            .unwrap();
+        component.start_downloads().await.unwrap();
+
+        let mut received = Vec::new();
+        for rx in subscribers.iter_mut() {
+            let mut sequences = Vec::new();
+            while let Ok(Some(BusMessage::Event(envelope))) =
+                tokio::time::timeout(Duration::from_secs(5), rx.recv()).await
+            {
+                sequences.push(envelope.sequence);
+                if matches!(envelope.event, DownloadEvent::TaskCompleted { .. }) {
+                    break;
+                }
+            }
+            received.push(sequences);
+        }
+
+        assert!(received[0].windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(received[0], received[1]);
+        let _ = component.stop_downloads().await;
+    }
+
+    #[tokio::test]
+    async fn test_paused_workers_do_not_dequeue_until_resumed() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"hello".to_vec()))
+            .mount(&server)
+            .await;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let config = DownloadConfig {
+            concurrent_downloads: 2,
+            output_directory: temp_dir.path().to_path_buf(),
+            ..DownloadConfig::default()
+        };
+        let component = DownloadComponent::new(config);
+        let mut rx = component.subscribe();
+        let control = component.control();
+        control.pause();
+
+        component
+            .add_download_task(
+                "task-1".to_string(),
+                format!("{}/video.mp4", server.uri()),
+                "video.mp4".to_string(),
+                Some(5),
+            )
+            .await
+            .unwrap();
+        component.start_downloads().await.unwrap();
+
+        let event = next_event(&mut rx).await.unwrap();
+        assert!(matches!(event, DownloadEvent::Paused));
+        assert_eq!(component.task_queue.lock().await.len(), 1);
+
+        control.resume();
+        let mut events = Vec::new();
+        while let Some(event) = next_event(&mut rx).await {
+            let done = matches!(event, DownloadEvent::TaskCompleted { .. });
+            events.push(event);
+            if done {
+                break;
+            }
+        }
+        assert!(matches!(events.first(), Some(DownloadEvent::Resumed)));
+        assert!(matches!(
+            events.last(),
+            Some(DownloadEvent::TaskCompleted { .. })
+        ));
+        assert_eq!(
+            std::fs::read(temp_dir.path().join("video.mp4")).unwrap(),
+            b"hello"
+        );
+
+        component.stop_downloads().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_download_file_returns_cancelled_when_cancelled() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut task = make_test_task(
+            temp_dir.path(),
+            "http://127.0.0.1:9/video.mp4".to_string(),
+            Some(11),
+        );
+
+        let client = DownloadClient::from(Client::new());
+        let config = DownloadConfig::default();
+        let control = DownloadControl::new();
+        control.cancel();
+
+        let status = DownloadComponent::download_file(
+            &client,
+            &mut task,
+            &EventBus::default(),
+            &config,
+            &control,
+            &ProgressTracker::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(status, TransferStatus::Cancelled);
+        assert!(!task.output_path.exists());
+    }
+
+    #[test]
+    fn test_unique_output_path_skips_existing_names() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("video.mp4");
+        std::fs::write(&path, b"old").unwrap();
+        std::fs::write(temp_dir.path().join("video (1).mp4.part"), b"partial").unwrap();
+
+        assert_eq!(
+            unique_output_path(&path),
+            temp_dir.path().join("video (2).mp4")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_conflict_policy_skip_and_size_check_keep_existing_file() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let client = DownloadClient::from(Client::new());
+        let control = DownloadControl::new();
+
+        for (policy, expected_size) in [
+            (ConflictPolicy::Skip, Some(11)),
+            (ConflictPolicy::OverwriteIfSizeDiffers, Some(3)),
+        ] {
+            // 接続できないURL: 要求を送ればエラーになる
+            let mut task = make_test_task(
+                temp_dir.path(),
+                "http://127.0.0.1:9/video.mp4".to_string(),
+                expected_size,
+            );
+            std::fs::write(&task.output_path, b"old").unwrap();
+            let config = DownloadConfig {
+                conflict_policy: policy,
+                ..DownloadConfig::default()
+            };
+
+            let status = DownloadComponent::download_file(
+                &client,
+                &mut task,
+                &EventBus::default(),
+                &config,
+                &control,
+                &ProgressTracker::default(),
+            )
+            .await
+            .unwrap();
+            assert_eq!(status, TransferStatus::Skipped);
+            assert_eq!(std::fs::read(&task.output_path).unwrap(), b"old");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_conflict_policy_rename_and_overwrite() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"new".to_vec()))
+            .mount(&server)
+            .await;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let client = DownloadClient::from(Client::new());
+        let control = DownloadControl::new();
+        let original = temp_dir.path().join("video.mp4");
+
+        // Rename: 既存ファイルを残して連番付きで保存
+        std::fs::write(&original, b"old").unwrap();
+        let mut task = make_test_task(temp_dir.path(), server.uri(), Some(3));
+        let config = DownloadConfig {
+            conflict_policy: ConflictPolicy::Rename,
+            ..DownloadConfig::default()
+        };
+        let status = DownloadComponent::download_file(
+            &client,
+            &mut task,
+            &EventBus::default(),
+            &config,
+            &control,
+            &ProgressTracker::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(status, TransferStatus::Completed);
+        assert_eq!(task.output_path, temp_dir.path().join("video (1).mp4"));
+        assert_eq!(std::fs::read(&original).unwrap(), b"old");
+        assert_eq!(std::fs::read(&task.output_path).unwrap(), b"new");
+
+        // OverwriteIfSizeDiffers: サイズが異なるため上書き
+        std::fs::write(&original, b"stale file").unwrap();
+        let mut task = make_test_task(temp_dir.path(), server.uri(), Some(3));
+        let config = DownloadConfig {
+            conflict_policy: ConflictPolicy::OverwriteIfSizeDiffers,
+            ..DownloadConfig::default()
+        };
+        let status = DownloadComponent::download_file(
+            &client,
+            &mut task,
+            &EventBus::default(),
+            &config,
+            &control,
+            &ProgressTracker::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(status, TransferStatus::Completed);
+        assert_eq!(std::fs::read(&original).unwrap(), b"new");
+        assert!(!task.part_path().exists());
+    }
+
+    #[tokio::test]
+    async fn test_download_file_respects_bandwidth_limit() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let body = vec![7u8; 30_000];
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(body.clone()))
+            .mount(&server)
+            .await;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut task = make_test_task(temp_dir.path(), server.uri(), Some(30_000));
+        let client = DownloadClient::from(Client::new());
+        let config = DownloadConfig::default();
+        let control = DownloadControl::new();
+        control.set_bandwidth_limit(Some(20_000));
+        assert_eq!(control.bandwidth_limit(), Some(20_000));
+
+        // 20KB/s: 最初の1秒分はバーストで通過し、残り10KBで約0.5秒待機する
+        let start = Instant::now();
+        let status = DownloadComponent::download_file(
+            &client,
+            &mut task,
+            &EventBus::default(),
+            &config,
+            &control,
+            &ProgressTracker::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(status, TransferStatus::Completed);
+        assert!(start.elapsed() >= Duration::from_millis(400));
+        assert_eq!(std::fs::read(&task.output_path).unwrap(), body);
+    }
+
+    #[tokio::test]
+    async fn test_download_file_pauses_when_disk_space_is_low() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut task = make_test_task(temp_dir.path(), "http://127.0.0.1:9".to_string(), Some(5));
+        let client = DownloadClient::from(Client::new());
+        let config = DownloadConfig {
+            output_directory: temp_dir.path().to_path_buf(),
+            min_free_space_bytes: u64::MAX,
+            ..DownloadConfig::default()
+        };
+        let control = DownloadControl::new();
+        let events = EventBus::default();
+        let mut rx = events.subscribe();
+
+        let status = DownloadComponent::download_file(
+            &client,
+            &mut task,
+            &events,
+            &config,
+            &control,
+            &ProgressTracker::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(status, TransferStatus::Paused);
+        assert!(control.is_paused());
+        assert!(!task.part_path().exists());
+        assert!(matches!(
+            rx.try_recv(),
+            Some(BusMessage::Event(EventEnvelope {
+                event: DownloadEvent::LowDiskSpace {
+                    reserve_bytes: u64::MAX,
+                    ..
+                },
+                ..
+            }))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_download_file_pauses_while_waiting_for_response() {
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(wiremock::matchers::any())
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_bytes(b"hello".to_vec())
+                    .set_delay(Duration::from_secs(5)),
+            )
+            .mount(&server)
+            .await;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut task = make_test_task(
+            temp_dir.path(),
+            format!("{}/video.mp4", server.uri()),
+            Some(5),
+        );
+        let client = DownloadClient::from(Client::new());
+        let config = DownloadConfig {
+            output_directory: temp_dir.path().to_path_buf(),
+            ..DownloadConfig::default()
+        };
+        let control = DownloadControl::new();
+        let pauser = control.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            pauser.pause();
+        });
+
+        let start = Instant::now();
+        let status = DownloadComponent::download_file(
+            &client,
+            &mut task,
+            &EventBus::default(),
+            &config,
+            &control,
+            &ProgressTracker::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(status, TransferStatus::Paused);
+        assert!(start.elapsed() < Duration::from_secs(2));
+        assert!(!task.part_path().exists());
+    }
+
+    #[tokio::test]
+    async fn test_cancel_stops_workers_and_removes_partial_files() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let config = DownloadConfig {
+            concurrent_downloads: 2,
+            output_directory: temp_dir.path().to_path_buf(),
+            keep_partial_on_cancel: false,
+            ..DownloadConfig::default()
+        };
+        let component = DownloadComponent::new(config);
+        let mut rx = component.subscribe();
+        let control = component.control();
+        control.pause();
+
+        component
+            .add_download_task(
+                "task-1".to_string(),
+                "http://127.0.0.1:9/video.mp4".to_string(),
+                "video.mp4".to_string(),
+                Some(11),
+            )
+            .await
+            .unwrap();
+        let part_path = temp_dir.path().join("video.mp4.part");
+        std::fs::write(&part_path, b"hello").unwrap();
+        component.start_downloads().await.unwrap();
+
+        let event = next_event(&mut rx).await.unwrap();
+        assert!(matches!(event, DownloadEvent::Paused));
+
+        control.cancel();
+        let event = next_event(&mut rx).await.unwrap();
+        match event {
+            DownloadEvent::OverallProgressUpdate(overall) => {
+                assert_eq!(overall.total_tasks, 1);
+                assert_eq!(overall.active_tasks, 0);
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+
+        let event = next_event(&mut rx).await.unwrap();
+        match event {
+            DownloadEvent::Cancelled { pending_tasks } => {
+                assert_eq!(pending_tasks, vec!["task-1".to_string()]);
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+        assert!(!part_path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_cancel_while_paused_does_not_restart_transfer() {
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(wiremock::matchers::any())
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        // 空き容量不足で転送前に一時停止させる
+        let config = DownloadConfig {
+            concurrent_downloads: 1,
+            output_directory: temp_dir.path().to_path_buf(),
+            min_free_space_bytes: u64::MAX,
+            ..DownloadConfig::default()
+        };
+        let component = DownloadComponent::new(config);
+        let mut rx = component.subscribe();
+        let control = component.control();
+        component
+            .add_download_task(
+                "task-1".to_string(),
+                format!("{}/video.mp4", server.uri()),
+                "video.mp4".to_string(),
+                Some(11),
+            )
+            .await
+            .unwrap();
+        component.start_downloads().await.unwrap();
+
+        loop {
+            match next_event(&mut rx).await {
+                Some(DownloadEvent::Paused) => break,
+                Some(_) => continue,
+                None => panic!("worker did not pause"),
+            }
+        }
+
+        control.cancel();
+        loop {
+            match next_event(&mut rx).await {
+                Some(DownloadEvent::TaskCancelled { task_id }) => {
+                    assert_eq!(task_id, "task-1");
+                    break;
+                }
+                Some(DownloadEvent::LowDiskSpace { .. }) => {
+                    panic!("cancelled task was re-entered")
+                }
+                Some(_) => continue,
+                None => panic!("task was not cancelled"),
+            }
+        }
+    }
+
+    /// 回復可能なエラーのみ即座に再試行するテスト用ポリシー
+    #[derive(Debug)]
+    struct ImmediateRetry;
+
+    impl RetryPolicy for ImmediateRetry {
+        fn decide(&self, error: &AppError, attempt: u32, _elapsed: Duration) -> RetryDecision {
+            if error.is_recoverable() && attempt < 3 {
+                RetryDecision::Retry(Duration::from_millis(10))
+            } else {
+                RetryDecision::GiveUp
+            }
+        }
+    }
+
+    async fn run_until_finished(
+        server_uri: String,
+        output_dir: &std::path::Path,
+    ) -> Vec<DownloadEvent> {
+        let config = DownloadConfig {
+            concurrent_downloads: 1,
+            output_directory: output_dir.to_path_buf(),
+            ..DownloadConfig::default()
+        };
+        let mut component = DownloadComponent::new(config);
+        let mut rx = component.subscribe();
+        component.set_retry_policy(Arc::new(ImmediateRetry));
+        component
+            .add_download_task(
+                "task-1".to_string(),
+                format!("{}/video.mp4", server_uri),
+                "video.mp4".to_string(),
+                None,
+            )
+            .await
+            .unwrap();
+        component.start_downloads().await.unwrap();
+
+        let mut events = Vec::new();
+        while let Some(event) = next_event(&mut rx).await {
+            let finished = matches!(
+                event,
+                DownloadEvent::TaskCompleted { .. } | DownloadEvent::TaskFailed { .. }
+            );
+            events.push(event);
+            if finished {
+                break;
+            }
+        }
+        component.stop_downloads().await.unwrap();
+        events
+    }
+
+    #[tokio::test]
+    async fn test_worker_retries_server_errors_with_policy() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"hello".to_vec()))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let events = run_until_finished(server.uri(), temp_dir.path()).await;
+        assert!(matches!(
+            events.last(),
+            Some(DownloadEvent::TaskCompleted { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_worker_does_not_retry_not_found() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let events = run_until_finished(server.uri(), temp_dir.path()).await;
+        match events.last() {
+            Some(DownloadEvent::TaskFailed { error, .. }) => assert!(error.contains("404")),
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_failure_event_redacts_echoed_token() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(403)
+                    .set_body_string(r#"{"message":"denied","access_token":"secret"}"#),
+            )
+            .mount(&server)
+            .await;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let events = run_until_finished(server.uri(), temp_dir.path()).await;
+        match events.last() {
+            Some(DownloadEvent::TaskFailed { error, .. }) => {
+                assert!(error.contains("403"));
+                assert!(!error.contains("secret"));
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_adaptive_concurrency_reports_initial_limit() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"hello".to_vec()))
+            .mount(&server)
+            .await;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let config = DownloadConfig {
+            concurrent_downloads: 8,
+            output_directory: temp_dir.path().to_path_buf(),
+            adaptive_concurrency: Some(AdaptiveConcurrency {
+                min_workers: 1,
+                max_workers: 2,
+                ..AdaptiveConcurrency::default()
+            }),
+            ..DownloadConfig::default()
+        };
+        let component = DownloadComponent::new(config);
+        let mut rx = component.subscribe();
+        for index in 0..3 {
+            component
+                .add_download_task(
+                    format!("task-{}", index),
+                    format!("{}/video{}.mp4", server.uri(), index),
+                    format!("video{}.mp4", index),
+                    None,
+                )
+                .await
+                .unwrap();
+        }
+        component.start_downloads().await.unwrap();
+        // 初期値は上限に丸められる
+        assert_eq!(component.current_concurrency(), 2);
+
+        let mut initial = None;
+        let mut completed = 0;
+        while let Some(event) = next_event(&mut rx).await {
+            match event {
+                DownloadEvent::ConcurrencyChanged {
+                    concurrency,
+                    reason: ConcurrencyChangeReason::Initial,
+                } => initial = Some(concurrency),
+                DownloadEvent::TaskCompleted { .. } => completed += 1,
+                _ => {}
+            }
+            if completed == 3 {
+                break;
+            }
+        }
+        component.stop_downloads().await.unwrap();
+
+        assert_eq!(initial, Some(2));
+        assert_eq!(completed, 3);
+    }
+
+    #[test]
+    fn test_status_error_classification() {
+        use reqwest::StatusCode;
+
+        let unauthorized =
+            DownloadComponent::status_error(StatusCode::UNAUTHORIZED, None, "401".to_string());
+        assert!(matches!(unauthorized, AppError::Authentication { .. }));
+        assert!(!unauthorized.is_recoverable());
+
+        let rate_limited = DownloadComponent::status_error(
+            StatusCode::TOO_MANY_REQUESTS,
+            Some(30),
+            "429".to_string(),
+        );
+        assert_eq!(rate_limited.retry_after(), Some(30));
+
+        let server_error =
+            DownloadComponent::status_error(StatusCode::BAD_GATEWAY, None, "502".to_string());
+        assert!(server_error.is_recoverable());
+        assert!(
+            !DownloadComponent::status_error(StatusCode::FORBIDDEN, None, "403".to_string())
+                .is_recoverable()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_journal_records_completed_task() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"hello".to_vec()))
+            .mount(&server)
+            .await;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let journal_path = temp_dir.path().join("journal.json");
+        let config = DownloadConfig {
+            concurrent_downloads: 1,
+            output_directory: temp_dir.path().to_path_buf(),
+            ..DownloadConfig::default()
+        };
+        let mut component = DownloadComponent::new(config);
+        let mut rx = component.subscribe();
+        component.set_journal(Arc::new(std::sync::Mutex::new(DownloadJournal::new(
+            &journal_path,
+        ))));
+
+        component
+            .add_download_task(
+                "task-1".to_string(),
+                format!("{}/video.mp4?access_token=secret", server.uri()),
+                "video.mp4".to_string(),
+                Some(5),
+            )
+            .await
+            .unwrap();
+        let pending = DownloadJournal::open(&journal_path).unwrap();
+        assert_eq!(pending.entries()[0].state, TaskState::Pending);
+
+        component.start_downloads().await.unwrap();
+        while let Some(event) = next_event(&mut rx).await {
+            if matches!(event, DownloadEvent::TaskCompleted { .. }) {
+                break;
+            }
+        }
+        component.stop_downloads().await.unwrap();
+
+        let journal = DownloadJournal::open(&journal_path).unwrap();
+        assert_eq!(journal.entries()[0].state, TaskState::Completed);
+        assert_eq!(journal.entries()[0].bytes_written, 5);
+        assert!(!journal.entries()[0].download_url.contains("secret"));
+        assert!(journal.unfinished().is_empty());
+    }
 }
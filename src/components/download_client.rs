@@ -0,0 +1,286 @@
+//! ダウンロード用HTTPクライアント
+//!
+//! # 責任
+//! - ダウンロードリクエストへの認証情報の付与（Authorization: Bearer ヘッダー）
+//! - 401 で拒否されたリクエストを更新したトークンで再送する
+//! - 更新後のトークンでもヘッダー認証が拒否された場合のみ access_token クエリパラメータへ切り替える
+//! - 通信エラーに含まれるリクエストURLの認証情報を伏せる
+
+use crate::components::redaction::redact_url;
+use crate::components::token_provider::TokenProvider;
+use crate::errors::{AppError, AppResult};
+use reqwest::{Client, Method, RequestBuilder, Response, StatusCode};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// 認証情報付きのダウンロード用HTTPクライアント
+///
+/// クローンしたクライアントは認証方式の切り替え状態を共有する。
+#[derive(Clone)]
+pub(crate) struct DownloadClient {
+    http: Client,
+    tokens: Option<Arc<dyn TokenProvider>>,
+    /// ヘッダー認証が拒否され、クエリパラメータ認証に切り替えた
+    query_auth: Arc<AtomicBool>,
+}
+
+impl std::fmt::Debug for DownloadClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DownloadClient")
+            .field("has_token_provider", &self.tokens.is_some())
+            .field("query_auth", &self.query_auth.load(Ordering::SeqCst))
+            .finish()
+    }
+}
+
+impl From<Client> for DownloadClient {
+    fn from(http: Client) -> Self {
+        Self::new(http, None)
+    }
+}
+
+impl DownloadClient {
+    pub(crate) fn new(http: Client, tokens: Option<Arc<dyn TokenProvider>>) -> Self {
+        Self {
+            http,
+            tokens,
+            query_auth: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// GET リクエストを送信（range は "bytes=..." 形式の Range ヘッダー値）
+    pub(crate) async fn get(&self, url: &str, range: Option<String>) -> AppResult<Response> {
+        self.send(Method::GET, url, range).await
+    }
+
+    /// HEAD リクエストを送信
+    pub(crate) async fn head(&self, url: &str) -> AppResult<Response> {
+        self.send(Method::HEAD, url, None).await
+    }
+
+    /// 認証情報を付与してリクエストを送信する
+    ///
+    /// # 事後条件
+    /// - リクエストごとに TokenProvider から現在のトークンを取得し、Authorization: Bearer ヘッダーで認証する
+    /// - 401 が返された場合は更新したトークンで再送する
+    /// - 更新したトークンでもヘッダー認証が拒否された場合はクエリパラメータで再送し、成功すれば以降もクエリパラメータを使う
+    async fn send(&self, method: Method, url: &str, range: Option<String>) -> AppResult<Response> {
+        let Some(tokens) = &self.tokens else {
+            return Self::execute(self.http.request(method, url), range.as_deref()).await;
+        };
+
+        let token = tokens.access_token().await?;
+        let query_auth = self.query_auth.load(Ordering::SeqCst);
+        let response = self
+            .execute_with_token(method.clone(), url, range.as_deref(), &token, query_auth)
+            .await?;
+        if response.status() != StatusCode::UNAUTHORIZED {
+            return Ok(response);
+        }
+
+        // 期限切れなどで拒否されたトークンを更新して再送する
+        let (token, response) = match tokens.refresh_rejected(&token).await {
+            Ok(fresh) => {
+                log::info!("Download request was rejected (401), retrying with a refreshed token");
+                let response = self
+                    .execute_with_token(method.clone(), url, range.as_deref(), &fresh, query_auth)
+                    .await?;
+                if response.status() != StatusCode::UNAUTHORIZED {
+                    return Ok(response);
+                }
+                (fresh, response)
+            }
+            Err(e) => {
+                log::warn!("Could not refresh rejected access token: {}", e);
+                (token, response)
+            }
+        };
+        if query_auth {
+            return Ok(response);
+        }
+
+        let fallback = self
+            .execute_with_token(method, url, range.as_deref(), &token, true)
+            .await?;
+        if fallback.status() != StatusCode::UNAUTHORIZED {
+            log::warn!(
+                "Server rejected bearer authentication, using access_token query parameter for downloads"
+            );
+            self.query_auth.store(true, Ordering::SeqCst);
+        }
+        Ok(fallback)
+    }
+
+    async fn execute_with_token(
+        &self,
+        method: Method,
+        url: &str,
+        range: Option<&str>,
+        token: &str,
+        query_auth: bool,
+    ) -> AppResult<Response> {
+        let request = self.http.request(method, url);
+        let request = if query_auth {
+            request.query(&[("access_token", token)])
+        } else {
+            request.bearer_auth(token)
+        };
+        Self::execute(request, range).await
+    }
+
+    async fn execute(mut request: RequestBuilder, range: Option<&str>) -> AppResult<Response> {
+        if let Some(range) = range {
+            request = request.header(reqwest::header::RANGE, range);
+        }
+        request
+            .send()
+            .await
+            .map_err(|e| network_error("Failed to send download request", e))
+    }
+}
+
+/// reqwest のエラーをネットワークエラーに変換する
+///
+/// # 事後条件
+/// - エラーにリクエストURLが含まれる場合は redact_url で認証情報を伏せてメッセージに含める
+/// - 元のエラー（source）からはURLを取り除く
+pub(crate) fn network_error(message: &str, error: reqwest::Error) -> AppError {
+    let message = match error.url() {
+        Some(url) => format!("{} ({})", message, redact_url(url.as_str())),
+        None => message.to_string(),
+    };
+    AppError::network(message, Some(error.without_url()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::token_provider::StaticToken;
+    use wiremock::matchers::{header, method, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn static_client(access_token: &str) -> DownloadClient {
+        DownloadClient::new(
+            Client::new(),
+            Some(Arc::new(StaticToken::new(access_token))),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_sends_bearer_header_without_query_token() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(header("Authorization", "Bearer secret"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = static_client("secret");
+        let response = client
+            .get(&format!("{}/video.mp4", server.uri()), None)
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let requests = server.received_requests().await.unwrap();
+        assert_eq!(requests[0].url.query(), None);
+    }
+
+    #[tokio::test]
+    async fn test_falls_back_to_query_token_when_header_rejected() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(query_param("access_token", "secret"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(401))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = static_client("secret");
+        let url = format!("{}/video.mp4", server.uri());
+        assert_eq!(
+            client.get(&url, None).await.unwrap().status(),
+            StatusCode::OK
+        );
+        // 切り替え後はヘッダー認証を試さない
+        assert_eq!(
+            client.clone().get(&url, None).await.unwrap().status(),
+            StatusCode::OK
+        );
+    }
+
+    #[tokio::test]
+    async fn test_send_error_redacts_query_token_in_url() {
+        // 接続できないポートへの送信は URL を含む reqwest のエラーになる
+        let client = DownloadClient::from(Client::new());
+        let error = client
+            .get(
+                "http://127.0.0.1:1/video.mp4?access_token=secret&type=mp4",
+                None,
+            )
+            .await
+            .unwrap_err();
+
+        let message = error.to_string();
+        assert!(message.contains("access_token=REDACTED"), "{}", message);
+        assert!(message.contains("type=mp4"), "{}", message);
+        assert!(!format!("{:?}", error).contains("secret"));
+    }
+
+    /// 拒否されると新しいトークンに切り替わる供給元
+    struct RotatingToken(tokio::sync::Mutex<String>);
+
+    #[async_trait::async_trait]
+    impl TokenProvider for RotatingToken {
+        async fn access_token(&self) -> AppResult<String> {
+            Ok(self.0.lock().await.clone())
+        }
+
+        async fn refresh_rejected(&self, _rejected: &str) -> AppResult<String> {
+            let mut token = self.0.lock().await;
+            *token = "fresh".to_string();
+            Ok(token.clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reissues_rejected_request_with_refreshed_token() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(header("Authorization", "Bearer fresh"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(401))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let tokens = Arc::new(RotatingToken(tokio::sync::Mutex::new("expired".into())));
+        let client = DownloadClient::new(Client::new(), Some(tokens));
+        let url = format!("{}/video.mp4", server.uri());
+        assert_eq!(
+            client.get(&url, None).await.unwrap().status(),
+            StatusCode::OK
+        );
+        // 以降のリクエストは更新後のトークンを最初から使う
+        assert_eq!(
+            client
+                .get(&url, Some("bytes=10-".into()))
+                .await
+                .unwrap()
+                .status(),
+            StatusCode::OK
+        );
+        let requests = server.received_requests().await.unwrap();
+        assert!(requests.iter().all(|request| request.url.query().is_none()));
+    }
+}
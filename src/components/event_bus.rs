@@ -0,0 +1,234 @@
+//! イベントバスコンポーネント
+//!
+//! # 責任
+//! - 1つのイベント列を複数の購読者（GUI・ログ・ジャーナル・通知など）へ配信する
+//! - イベントへの通番と発行時刻の付与
+//! - 処理が追いつかない購読者の検出と、取りこぼした件数の報告
+
+use crate::components::EventEmitter;
+use crate::errors::AppResult;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::{Arc, Mutex};
+use tokio::sync::broadcast::{self, error::RecvError, error::TryRecvError};
+
+/// 購読者ごとに保持できる未受信イベント数の既定値
+pub const DEFAULT_EVENT_BUS_CAPACITY: usize = 1024;
+
+/// 通番と発行時刻を付与したイベント
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventEnvelope<T> {
+    /// バス内で一意な通番（1から始まり、発行順に増加する）
+    pub sequence: u64,
+    /// 発行時刻
+    pub timestamp: DateTime<Utc>,
+    /// イベント本体
+    pub event: T,
+}
+
+/// 購読者が受け取るメッセージ
+#[derive(Debug, Clone, PartialEq)]
+pub enum BusMessage<T> {
+    /// 発行されたイベント
+    Event(EventEnvelope<T>),
+    /// 受信が追いつかず、古いイベントを取りこぼした
+    Lagged { skipped: u64 },
+}
+
+/// 複数の購読者へイベントを配信するバス
+///
+/// 購読者ごとの未受信イベントは capacity 件までしか保持しない。
+/// 上限を超えると古いイベントから破棄され、該当の購読者には BusMessage::Lagged が届く。
+/// クローンはすべて同じバス（購読者・通番）を共有する。
+pub struct EventBus<T> {
+    sender: broadcast::Sender<EventEnvelope<T>>,
+    /// 最後に発行した通番（通番の採番と送信の順序を揃えるため送信中も保持する）
+    last_sequence: Arc<Mutex<u64>>,
+}
+
+impl<T> Clone for EventBus<T> {
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+            last_sequence: Arc::clone(&self.last_sequence),
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for EventBus<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventBus")
+            .field("subscribers", &self.sender.receiver_count())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: Clone + Send + 'static> Default for EventBus<T> {
+    fn default() -> Self {
+        Self::new(DEFAULT_EVENT_BUS_CAPACITY)
+    }
+}
+
+impl<T: Clone + Send + 'static> EventBus<T> {
+    /// 購読者ごとに capacity 件まで保持するバスを作成
+    ///
+    /// # 事前条件
+    /// - capacity は 1 以上（0 の場合は 1 として扱う）
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity.max(1));
+        Self {
+            sender,
+            last_sequence: Arc::new(Mutex::new(0)),
+        }
+    }
+
+    /// イベントの購読を開始する
+    ///
+    /// # 事後条件
+    /// - 呼び出し以降に発行されたイベントのみを受信する
+    pub fn subscribe(&self) -> EventSubscriber<T> {
+        EventSubscriber {
+            receiver: self.sender.subscribe(),
+            skipped: 0,
+        }
+    }
+
+    /// イベントを発行し、付与した通番を返す
+    ///
+    /// # 副作用
+    /// - 全購読者へイベントが配信される（購読者がいない場合は破棄される）
+    ///
+    /// # 事後条件
+    /// - 通番は発行順に 1 ずつ増加し、購読者が受け取る順序と一致する
+    pub fn publish(&self, event: T) -> u64 {
+        let mut last_sequence = self
+            .last_sequence
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        *last_sequence += 1;
+        let _ = self.sender.send(EventEnvelope {
+            sequence: *last_sequence,
+            timestamp: Utc::now(),
+            event,
+        });
+        *last_sequence
+    }
+
+    /// 現在の購読者数
+    pub fn subscriber_count(&self) -> usize {
+        self.sender.receiver_count()
+    }
+}
+
+#[async_trait]
+impl<T: Clone + Send + Sync + 'static> EventEmitter<T> for EventBus<T> {
+    async fn emit_event(&self, event: T) -> AppResult<()> {
+        self.publish(event);
+        Ok(())
+    }
+}
+
+/// イベントバスの購読者
+pub struct EventSubscriber<T> {
+    receiver: broadcast::Receiver<EventEnvelope<T>>,
+    /// 取りこぼしたイベントの累計
+    skipped: u64,
+}
+
+impl<T: Clone> EventSubscriber<T> {
+    /// 次のメッセージを待って受信する
+    ///
+    /// # 事後条件
+    /// - 取りこぼしがあった場合は、次のイベントより先に BusMessage::Lagged を返す
+    /// - バスがすべて破棄され、未受信イベントもない場合は None
+    pub async fn recv(&mut self) -> Option<BusMessage<T>> {
+        match self.receiver.recv().await {
+            Ok(envelope) => Some(BusMessage::Event(envelope)),
+            Err(RecvError::Lagged(skipped)) => Some(self.lagged(skipped)),
+            Err(RecvError::Closed) => None,
+        }
+    }
+
+    /// 受信済みのメッセージがあれば待たずに受信する
+    ///
+    /// # 事後条件
+    /// - 未受信のメッセージがない場合、またはバスが破棄された場合は None
+    pub fn try_recv(&mut self) -> Option<BusMessage<T>> {
+        match self.receiver.try_recv() {
+            Ok(envelope) => Some(BusMessage::Event(envelope)),
+            Err(TryRecvError::Lagged(skipped)) => Some(self.lagged(skipped)),
+            Err(TryRecvError::Empty) | Err(TryRecvError::Closed) => None,
+        }
+    }
+
+    /// これまでに取りこぼしたイベントの累計
+    pub fn skipped_total(&self) -> u64 {
+        self.skipped
+    }
+
+    fn lagged(&mut self, skipped: u64) -> BusMessage<T> {
+        self.skipped += skipped;
+        log::warn!(
+            "Event subscriber lagged behind, {} event(s) skipped ({} in total)",
+            skipped,
+            self.skipped
+        );
+        BusMessage::Lagged { skipped }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sequences(subscriber: &mut EventSubscriber<&'static str>) -> Vec<u64> {
+        std::iter::from_fn(|| match subscriber.try_recv()? {
+            BusMessage::Event(envelope) => Some(envelope.sequence),
+            BusMessage::Lagged { .. } => Some(0),
+        })
+        .collect()
+    }
+
+    #[tokio::test]
+    async fn test_every_subscriber_receives_numbered_events() {
+        let bus = EventBus::new(8);
+        let mut first = bus.subscribe();
+        let mut second = bus.subscribe();
+        assert_eq!(bus.subscriber_count(), 2);
+
+        assert_eq!(bus.publish("a"), 1);
+        bus.emit_event("b").await.unwrap();
+
+        for subscriber in [&mut first, &mut second] {
+            match subscriber.recv().await {
+                Some(BusMessage::Event(envelope)) => {
+                    assert_eq!((envelope.sequence, envelope.event), (1, "a"));
+                }
+                other => panic!("unexpected message: {:?}", other),
+            }
+            assert_eq!(sequences(subscriber), vec![2]);
+        }
+
+        drop(bus);
+        assert_eq!(first.recv().await, None);
+    }
+
+    #[test]
+    fn test_slow_subscriber_reports_lag_without_blocking_others() {
+        let bus = EventBus::new(2);
+        let mut slow = bus.subscribe();
+        let mut fast = bus.subscribe();
+
+        for event in ["a", "b", "c", "d", "e"] {
+            bus.publish(event);
+            // 受信が追いついている購読者は取りこぼさない
+            assert_eq!(sequences(&mut fast).len(), 1);
+        }
+
+        // 保持数を超えた分は取りこぼしとして報告され、最新の2件だけが残る
+        assert_eq!(slow.try_recv(), Some(BusMessage::Lagged { skipped: 3 }));
+        assert_eq!(sequences(&mut slow), vec![4, 5]);
+        assert_eq!(slow.skipped_total(), 3);
+        assert_eq!(fast.skipped_total(), 0);
+    }
+}
@@ -0,0 +1,271 @@
+//! 失敗ファイル記録コンポーネント
+//!
+//! # 責任
+//! - 直前のダウンロードバッチで失敗したファイル、および再試行で取得できる可能性がある理由で
+//!   スキップしたファイルの永続化
+//! - アプリ再起動後の「失敗したファイルを再試行」の対象一覧の提供
+//!
+//! # セキュリティ要件
+//! - download_url は access_token を含まない（Authorization ヘッダーで送る）形のまま保存する
+
+use crate::components::api::{MeetingRecording, RecordingFile};
+use crate::components::report::{FinalState, RunReport, SkipReason};
+use crate::errors::{AppError, AppResult};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// 失敗ファイル記録のファイル名
+const FAILED_BATCH_FILE_NAME: &str = "failed_downloads.json";
+
+/// 失敗ファイル記録の形式のバージョン
+const FAILED_BATCH_VERSION: u32 = 1;
+
+/// 再試行の対象となるファイル
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FailedItem {
+    pub meeting: MeetingRecording,
+    pub file: RecordingFile,
+    /// 前回の最終状態（Failed または Skipped）
+    pub state: FinalState,
+    pub skip_reason: Option<SkipReason>,
+    pub error: Option<String>,
+}
+
+/// 失敗ファイル記録の内容
+#[derive(Debug, Serialize, Deserialize)]
+struct FailedBatchFile {
+    version: u32,
+    /// 記録したバッチの終了日時
+    finished_at: DateTime<Utc>,
+    items: Vec<FailedItem>,
+}
+
+/// 直前のダウンロードバッチの失敗ファイル記録
+///
+/// バッチが終わるたびに内容を置き換えるため、常に最新のバッチの結果のみを保持する。
+#[derive(Debug)]
+pub struct FailedBatch {
+    /// 記録ファイルパス
+    path: PathBuf,
+    finished_at: Option<DateTime<Utc>>,
+    items: Vec<FailedItem>,
+}
+
+impl FailedBatch {
+    /// 既定の記録ファイルパスを取得
+    pub fn default_path() -> AppResult<PathBuf> {
+        let mut path = dirs::config_dir().ok_or_else(|| {
+            AppError::file_system(
+                "Could not determine config directory",
+                None::<std::io::Error>,
+            )
+        })?;
+
+        // Windows: %APPDATA%\ZoomVideoMover\failed_downloads.json
+        #[cfg(target_os = "windows")]
+        path.push("ZoomVideoMover");
+
+        // Unix-like: ~/.config/zoom-video-mover/failed_downloads.json
+        #[cfg(not(target_os = "windows"))]
+        path.push("zoom-video-mover");
+
+        path.push(FAILED_BATCH_FILE_NAME);
+        Ok(path)
+    }
+
+    /// 空の記録を作成（ファイルは replace まで書き込まれない）
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            finished_at: None,
+            items: Vec::new(),
+        }
+    }
+
+    /// 記録を開く
+    ///
+    /// # 副作用
+    /// - ファイルシステムからの読み込み
+    ///
+    /// # 事後条件
+    /// - ファイルが存在しない場合は空の記録が返される
+    /// - ファイルが破損している場合は data_integrity エラーが返される
+    pub fn open(path: impl AsRef<Path>) -> AppResult<Self> {
+        let path = path.as_ref().to_path_buf();
+        if !path.exists() {
+            return Ok(Self::new(path));
+        }
+
+        let content = std::fs::read_to_string(&path)
+            .map_err(|e| AppError::file_system("Failed to read failed download list", Some(e)))?;
+        let file: FailedBatchFile = serde_json::from_str(&content).map_err(|e| {
+            AppError::data_integrity(format!("Failed download list is corrupted: {}", e))
+        })?;
+
+        Ok(Self {
+            path,
+            finished_at: Some(file.finished_at),
+            items: file.items,
+        })
+    }
+
+    /// 既定パスの記録を開く
+    pub fn open_default() -> AppResult<Self> {
+        Self::open(Self::default_path()?)
+    }
+
+    /// 再試行の対象となるファイル
+    pub fn items(&self) -> &[FailedItem] {
+        &self.items
+    }
+
+    /// 記録したバッチの終了日時（記録がない場合は None）
+    pub fn finished_at(&self) -> Option<DateTime<Utc>> {
+        self.finished_at
+    }
+
+    /// 新しいバッチの結果で記録を置き換える
+    ///
+    /// # 副作用
+    /// - 記録ファイルの書き込み（items が空の場合は削除）
+    pub fn replace(&mut self, finished_at: DateTime<Utc>, items: Vec<FailedItem>) -> AppResult<()> {
+        if items.is_empty() {
+            self.finished_at = None;
+            self.items.clear();
+            if self.path.exists() {
+                std::fs::remove_file(&self.path).map_err(|e| {
+                    AppError::file_system("Failed to remove failed download list", Some(e))
+                })?;
+            }
+            return Ok(());
+        }
+
+        let file = FailedBatchFile {
+            version: FAILED_BATCH_VERSION,
+            finished_at,
+            items,
+        };
+        let content = serde_json::to_string_pretty(&file).map_err(|e| {
+            AppError::data_integrity(format!("Failed to serialize failed download list: {}", e))
+        })?;
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| {
+                AppError::file_system("Failed to create failed download list directory", Some(e))
+            })?;
+        }
+        std::fs::write(&self.path, content)
+            .map_err(|e| AppError::file_system("Failed to write failed download list", Some(e)))?;
+
+        self.finished_at = Some(finished_at);
+        self.items = file.items;
+        Ok(())
+    }
+}
+
+/// 実行レポートから再試行の対象となるファイルを抽出する
+///
+/// # 事前条件
+/// - sources は出力ファイル名（RunReportEntry の file_name）→ 会議・録画ファイルの対応である
+///
+/// # 事後条件
+/// - 失敗したファイルと、再試行可能な理由でスキップしたファイルのうち sources に含まれるものが返される
+pub fn retryable_items(
+    report: &RunReport,
+    sources: &HashMap<String, (MeetingRecording, RecordingFile)>,
+) -> Vec<FailedItem> {
+    report
+        .entries
+        .iter()
+        .filter(|entry| entry.is_retryable())
+        .filter_map(|entry| {
+            let (meeting, file) = sources.get(&entry.file.file_name)?;
+            Some(FailedItem {
+                meeting: meeting.clone(),
+                file: file.clone(),
+                state: entry.state,
+                skip_reason: entry.skip_reason,
+                error: entry.error.clone(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::report::{ReportFile, RunRecorder};
+
+    fn recording(topic: &str) -> (MeetingRecording, RecordingFile) {
+        let meeting: MeetingRecording = serde_json::from_value(serde_json::json!({
+            "uuid": format!("{}-uuid", topic),
+            "id": 1,
+            "host_id": "host",
+            "topic": topic,
+            "start_time": "2025-01-01T10:00:00Z",
+            "duration": 30,
+        }))
+        .unwrap();
+        let file: RecordingFile = serde_json::from_value(serde_json::json!({
+            "id": format!("{}-file", topic),
+            "file_type": "MP4",
+        }))
+        .unwrap();
+        (meeting, file)
+    }
+
+    #[test]
+    fn test_only_retryable_entries_are_remembered_across_reopen() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join(FAILED_BATCH_FILE_NAME);
+
+        let mut recorder = RunRecorder::new();
+        recorder.record(
+            ReportFile::unattributed("failed.mp4"),
+            FinalState::Failed,
+            None,
+            Some("HTTP 500".to_string()),
+        );
+        recorder.skip(
+            ReportFile::unattributed("processing.mp4"),
+            SkipReason::EmptyDownloadUrl,
+        );
+        recorder.skip(
+            ReportFile::unattributed("done.mp4"),
+            SkipReason::AlreadyDownloaded,
+        );
+        recorder.record(
+            ReportFile::unattributed("resumed.mp4"),
+            FinalState::Failed,
+            None,
+            None,
+        );
+        let report = recorder.finish(false);
+        let sources: HashMap<_, _> = ["failed.mp4", "processing.mp4", "done.mp4"]
+            .into_iter()
+            .map(|name| (name.to_string(), recording(name)))
+            .collect();
+
+        let items = retryable_items(&report, &sources);
+        let topics: Vec<_> = items
+            .iter()
+            .map(|item| item.meeting.topic.as_str())
+            .collect();
+        // 取得済みのファイルと、会議情報のないファイルは対象外
+        assert_eq!(topics, vec!["failed.mp4", "processing.mp4"]);
+
+        let mut batch = FailedBatch::open(&path).unwrap();
+        batch.replace(report.finished_at, items).unwrap();
+        let reopened = FailedBatch::open(&path).unwrap();
+        assert_eq!(reopened.items().len(), 2);
+        assert_eq!(reopened.items()[0].error.as_deref(), Some("HTTP 500"));
+        assert_eq!(reopened.finished_at(), Some(report.finished_at));
+
+        // 失敗がなくなったバッチで置き換えると記録は削除される
+        let mut batch = reopened;
+        batch.replace(Utc::now(), Vec::new()).unwrap();
+        assert!(!path.exists());
+        assert!(FailedBatch::open(&path).unwrap().items().is_empty());
+    }
+}
@@ -10,13 +10,16 @@ use crate::components::api::{ApiComponent, ApiConfig, RecordingFileType, Recordi
 use crate::components::auth::{AuthComponent, AuthToken};
 use crate::components::config::{AppConfig, OAuthConfig};
 use crate::components::download::{DownloadComponent, DownloadConfig, DownloadEvent};
+use crate::components::event_bus::{BusMessage, EventSubscriber};
+use crate::components::token_provider::{AuthTokenProvider, TokenProvider};
 use crate::components::ComponentLifecycle;
 use crate::errors::AppResult;
 use async_trait::async_trait;
 use chrono::NaiveDate;
 use log;
 use std::path::PathBuf;
-use tokio::sync::mpsc;
+use std::sync::Arc;
+use tokio::sync::Mutex;
 
 /// 統合コンポーネント設定
 #[derive(Debug, Clone)]
@@ -52,16 +55,16 @@ pub struct IntegrationComponent {
     /// ファイルパス
     #[allow(dead_code)]
     config_path: String,
-    /// 認証管理
-    auth_component: AuthComponent,
+    /// 認証管理（API・ダウンロードのトークン供給元と共有）
+    auth_component: Arc<Mutex<AuthComponent>>,
     /// API通信
     api_component: ApiComponent,
     /// ダウンロード実行
     download_component: DownloadComponent,
     /// 統合設定
     integration_config: IntegrationConfig,
-    /// イベントチャネル
-    event_receiver: Option<mpsc::UnboundedReceiver<DownloadEvent>>,
+    /// ダウンロードイベントの購読（ログ出力用）
+    event_receiver: Option<EventSubscriber<DownloadEvent>>,
 }
 
 impl IntegrationComponent {
@@ -95,11 +98,16 @@ impl IntegrationComponent {
         };
 
         // 認証コンポーネントの初期化
-        let auth_component = AuthComponent::new(oauth_config);
+        let mut auth_component = AuthComponent::new(oauth_config);
+        auth_component.set_endpoints(app_config.endpoints.clone())?;
+        let auth_component = Arc::new(Mutex::new(auth_component));
+        // API・ダウンロードはリクエストごとに認証コンポーネントから有効なトークンを取得する
+        let tokens: Arc<dyn TokenProvider> =
+            Arc::new(AuthTokenProvider::shared(Arc::clone(&auth_component)));
 
         // API設定の構築
         let api_config = ApiConfig {
-            base_url: app_config.api.base_url.clone(),
+            base_url: app_config.endpoints.api_base_url(),
             rate_limit: Default::default(),
             timeout: std::time::Duration::from_secs(app_config.api.timeout_seconds),
             max_retries: app_config.api.max_retries,
@@ -109,7 +117,8 @@ impl IntegrationComponent {
         };
 
         // APIコンポーネントの初期化
-        let api_component = ApiComponent::new(api_config);
+        let mut api_component = ApiComponent::new(api_config);
+        api_component.set_token_provider(Arc::clone(&tokens));
 
         // ダウンロード設定の構築
         let download_config = DownloadConfig {
@@ -118,14 +127,15 @@ impl IntegrationComponent {
             timeout: std::time::Duration::from_secs(300),
             max_retries: 3,
             output_directory: integration_config.output_directory.clone(),
+            ..DownloadConfig::default()
         };
 
         // ダウンロードコンポーネントの初期化
         let mut download_component = DownloadComponent::new(download_config);
+        download_component.set_token_provider(tokens);
 
-        // イベントチャネルの設定
-        let (event_sender, event_receiver) = mpsc::unbounded_channel();
-        download_component.set_event_listener(event_sender);
+        // イベントの購読
+        let event_receiver = download_component.subscribe();
 
         Ok(Self {
             config_path: config_path.to_string(),
@@ -152,8 +162,10 @@ impl IntegrationComponent {
     pub async fn authenticate(&mut self) -> AppResult<AuthToken> {
         log::info!("Starting authentication flow");
 
+        let mut auth_component = self.auth_component.lock().await;
+
         // 認証URL生成
-        let (auth_url, state_id) = self.auth_component.generate_auth_url()?;
+        let (auth_url, state_id) = auth_component.generate_auth_url()?;
 
         log::info!("Please visit the following URL to authenticate:");
         log::info!("{}", auth_url);
@@ -165,14 +177,11 @@ impl IntegrationComponent {
         let auth_code = "dummy_auth_code";
 
         // トークン交換
-        let token = self
-            .auth_component
+        // API・ダウンロードコンポーネントは共有した認証コンポーネントからこのトークンを使う
+        let token = auth_component
             .exchange_code_for_token(auth_code, &state_id)
             .await?;
 
-        // APIコンポーネントにトークンを設定
-        self.api_component.set_auth_token(token.clone()).await;
-
         // 設定ファイルに保存（オプション）
         // TODO: トークンの永続化
 
@@ -210,10 +219,12 @@ impl IntegrationComponent {
         // 録画データの検索
         let search_request = RecordingSearchRequest {
             user_id,
+            account_id: None,
             from: from_date,
             to: to_date,
             page_size: None,
             next_page_token: None,
+            trash: false,
         };
 
         let meetings = self
@@ -299,7 +310,14 @@ impl IntegrationComponent {
         // イベントの処理
         if let Some(mut receiver) = self.event_receiver.take() {
             tokio::spawn(async move {
-                while let Some(event) = receiver.recv().await {
+                while let Some(message) = receiver.recv().await {
+                    let event = match message {
+                        BusMessage::Event(envelope) => envelope.event,
+                        BusMessage::Lagged { skipped } => {
+                            log::warn!("Download log skipped {} event(s)", skipped);
+                            continue;
+                        }
+                    };
                     match event {
                         DownloadEvent::TaskStarted { task_id } => {
                             log::info!("Download started: {}", task_id);
@@ -314,11 +332,25 @@ impl IntegrationComponent {
                         DownloadEvent::TaskCompleted {
                             task_id,
                             output_path,
+                            ..
                         } => {
                             log::info!("Download completed: {} -> {:?}", task_id, output_path);
                         }
-                        DownloadEvent::TaskFailed { task_id, error } => {
-                            log::error!("Download failed: {} - {}", task_id, error);
+                        DownloadEvent::TaskFailed {
+                            task_id,
+                            error,
+                            reason,
+                        } => {
+                            log::error!("Download failed ({:?}): {} - {}", reason, task_id, error);
+                        }
+                        DownloadEvent::TaskCancelled { task_id } => {
+                            log::info!("Download cancelled: {}", task_id);
+                        }
+                        DownloadEvent::TaskSkipped {
+                            task_id,
+                            output_path,
+                        } => {
+                            log::info!("Download skipped: {} ({:?} exists)", task_id, output_path);
                         }
                         DownloadEvent::OverallProgressUpdate(overall) => {
                             log::info!(
@@ -327,6 +359,34 @@ impl IntegrationComponent {
                                 overall.total_tasks
                             );
                         }
+                        DownloadEvent::LowDiskSpace {
+                            available_bytes,
+                            reserve_bytes,
+                        } => {
+                            log::warn!(
+                                "Low disk space: {} bytes free (reserve {})",
+                                available_bytes,
+                                reserve_bytes
+                            );
+                        }
+                        DownloadEvent::ConcurrencyChanged {
+                            concurrency,
+                            reason,
+                        } => {
+                            log::info!("Download concurrency: {} ({})", concurrency, reason);
+                        }
+                        DownloadEvent::Paused => {
+                            log::info!("Downloads paused");
+                        }
+                        DownloadEvent::Resumed => {
+                            log::info!("Downloads resumed");
+                        }
+                        DownloadEvent::Cancelled { pending_tasks } => {
+                            log::info!(
+                                "Downloads cancelled ({} pending tasks discarded)",
+                                pending_tasks.len()
+                            );
+                        }
                     }
                 }
             });
@@ -342,7 +402,7 @@ impl ComponentLifecycle for IntegrationComponent {
         log::info!("Initializing IntegrationComponent");
 
         // 各コンポーネントの初期化
-        self.auth_component.initialize().await?;
+        self.auth_component.lock().await.initialize().await?;
         self.api_component.initialize().await?;
         self.download_component.initialize().await?;
 
@@ -356,14 +416,14 @@ impl ComponentLifecycle for IntegrationComponent {
         // 各コンポーネントのシャットダウン
         self.download_component.shutdown().await?;
         self.api_component.shutdown().await?;
-        self.auth_component.shutdown().await?;
+        self.auth_component.lock().await.shutdown().await?;
 
         log::info!("IntegrationComponent shut down successfully");
         Ok(())
     }
 
     async fn health_check(&self) -> bool {
-        self.auth_component.health_check().await
+        self.auth_component.lock().await.health_check().await
             && self.api_component.health_check().await
             && self.download_component.health_check().await
     }
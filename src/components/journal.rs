@@ -0,0 +1,486 @@
+//! ダウンロードジャーナルコンポーネント
+//!
+//! # 責任
+//! - ダウンロードタスク状態の永続化
+//! - アプリ再起動・クラッシュ後の未完了ジョブの復元
+//!
+//! # セキュリティ要件
+//! - ダウンロードURLに含まれる access_token はジャーナルに保存しない
+
+use crate::components::api::{MeetingRecording, RecordingFile};
+use crate::components::download::{DownloadProgress, DownloadTask, TaskPriority, TaskState};
+use crate::errors::{AppError, AppResult};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// ジャーナルファイル名
+const JOURNAL_FILE_NAME: &str = "download_journal.json";
+
+/// ジャーナルファイル形式のバージョン
+const JOURNAL_VERSION: u32 = 1;
+
+/// ジャーナルに記録されるタスク情報
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JournalEntry {
+    /// タスクID
+    pub task_id: String,
+    /// ダウンロードURL（access_token除去済み）
+    pub download_url: String,
+    /// 出力ファイルパス
+    pub output_path: PathBuf,
+    /// ファイル名
+    pub file_name: String,
+    /// 期待ファイルサイズ
+    pub expected_size: Option<u64>,
+    /// タスク状態
+    pub state: TaskState,
+    /// リトライ数
+    pub retry_count: u32,
+    /// 優先度
+    #[serde(default)]
+    pub priority: TaskPriority,
+    /// 書き込み済みバイト数
+    pub bytes_written: u64,
+    /// エラー情報
+    pub error: Option<String>,
+    /// 最終更新日時
+    pub updated_at: DateTime<Utc>,
+    /// 取得元の会議・録画ファイル（DownloadComponent に直接追加したタスクでは None）
+    pub source: Option<JournalSource>,
+}
+
+/// ジャーナルエントリの取得元（再開時にマニフェスト・実行レポートへ記録するために使う）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JournalSource {
+    /// 会議情報（recording_files は保存しない）
+    pub meeting: MeetingRecording,
+    /// 録画ファイル情報（download_url は access_token 除去済み）
+    pub file: RecordingFile,
+}
+
+impl JournalSource {
+    /// 会議・録画ファイルから取得元を作成する
+    ///
+    /// # 事後条件
+    /// - 会議の recording_files は空になる
+    /// - download_url から access_token クエリパラメータが除去される
+    pub fn new(meeting: &MeetingRecording, file: &RecordingFile) -> Self {
+        let meeting = MeetingRecording {
+            recording_files: Vec::new(),
+            ..meeting.clone()
+        };
+        let file = RecordingFile {
+            download_url: strip_access_token(&file.download_url),
+            ..file.clone()
+        };
+        Self { meeting, file }
+    }
+}
+
+impl JournalEntry {
+    /// タスクからジャーナルエントリを作成する
+    ///
+    /// # 事後条件
+    /// - download_url から access_token クエリパラメータが除去される
+    pub fn from_task(task: &DownloadTask) -> Self {
+        Self {
+            task_id: task.task_id.clone(),
+            download_url: strip_access_token(&task.download_url),
+            output_path: task.output_path.clone(),
+            file_name: task.file_name.clone(),
+            expected_size: task.expected_size,
+            state: task.state.clone(),
+            retry_count: task.retry_count,
+            priority: task.priority,
+            bytes_written: task.progress.downloaded_bytes,
+            error: task.error.clone(),
+            updated_at: Utc::now(),
+            source: None,
+        }
+    }
+
+    /// 未完了（再開対象）のエントリか
+    pub fn is_unfinished(&self) -> bool {
+        matches!(
+            self.state,
+            TaskState::Pending | TaskState::InProgress | TaskState::Failed
+        )
+    }
+
+    /// 再開用のダウンロードタスクに変換する
+    ///
+    /// # 事前条件
+    /// - download_url は認証情報を付与済みのURLである
+    ///
+    /// # 事後条件
+    /// - 状態は Pending に戻される
+    /// - 最終的に失敗したタスクはリトライ数がリセットされる
+    pub fn to_task(&self, download_url: String) -> DownloadTask {
+        let retry_count = if self.state == TaskState::Failed {
+            0
+        } else {
+            self.retry_count
+        };
+
+        DownloadTask {
+            task_id: self.task_id.clone(),
+            download_url,
+            output_path: self.output_path.clone(),
+            expected_size: self.expected_size,
+            file_name: self.file_name.clone(),
+            state: TaskState::Pending,
+            progress: DownloadProgress::default(),
+            error: self.error.clone(),
+            retry_count,
+            priority: self.priority,
+            attempts: Vec::new(),
+            retry_at: None,
+            sha256: None,
+        }
+    }
+}
+
+/// ジャーナルファイルの内容
+#[derive(Debug, Serialize, Deserialize)]
+struct JournalFile {
+    version: u32,
+    entries: Vec<JournalEntry>,
+}
+
+/// ダウンロードジャーナル
+///
+/// タスクの状態が変わるたびにファイルへ書き出し、次回起動時に未完了ジョブを復元する。
+#[derive(Debug)]
+pub struct DownloadJournal {
+    /// ジャーナルファイルパス
+    path: PathBuf,
+    /// 記録済みエントリ（追加順）
+    entries: Vec<JournalEntry>,
+}
+
+impl DownloadJournal {
+    /// 既定のジャーナルファイルパスを取得
+    pub fn default_path() -> AppResult<PathBuf> {
+        let mut path = dirs::config_dir().ok_or_else(|| {
+            AppError::file_system(
+                "Could not determine config directory",
+                None::<std::io::Error>,
+            )
+        })?;
+
+        // Windows: %APPDATA%\ZoomVideoMover\download_journal.json
+        #[cfg(target_os = "windows")]
+        path.push("ZoomVideoMover");
+
+        // Unix-like: ~/.config/zoom-video-mover/download_journal.json
+        #[cfg(not(target_os = "windows"))]
+        path.push("zoom-video-mover");
+
+        path.push(JOURNAL_FILE_NAME);
+        Ok(path)
+    }
+
+    /// 空のジャーナルを作成（ファイルは最初の記録時に作成される）
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            entries: Vec::new(),
+        }
+    }
+
+    /// ジャーナルを開く
+    ///
+    /// # 副作用
+    /// - ファイルシステムからの読み込み
+    ///
+    /// # 事後条件
+    /// - ファイルが存在しない場合は空のジャーナルが返される
+    /// - ファイルが破損している場合は data_integrity エラーが返される
+    pub fn open(path: impl AsRef<Path>) -> AppResult<Self> {
+        let path = path.as_ref().to_path_buf();
+
+        if !path.exists() {
+            return Ok(Self::new(path));
+        }
+
+        let content = std::fs::read_to_string(&path)
+            .map_err(|e| AppError::file_system("Failed to read download journal", Some(e)))?;
+        let file: JournalFile = serde_json::from_str(&content).map_err(|e| {
+            AppError::data_integrity(format!("Download journal is corrupted: {}", e))
+        })?;
+
+        Ok(Self {
+            path,
+            entries: file.entries,
+        })
+    }
+
+    /// 既定パスのジャーナルを開く
+    pub fn open_default() -> AppResult<Self> {
+        Self::open(Self::default_path()?)
+    }
+
+    /// タスクの現在状態を記録する
+    ///
+    /// # 副作用
+    /// - ジャーナルファイルへの書き込み
+    ///
+    /// # 事後条件
+    /// - 同じ task_id のエントリは上書きされる（記録済みの取得元は引き継ぐ）
+    pub fn record(&mut self, task: &DownloadTask) -> AppResult<()> {
+        let entry = JournalEntry::from_task(task);
+        match self
+            .entries
+            .iter_mut()
+            .find(|existing| existing.task_id == entry.task_id)
+        {
+            Some(existing) => {
+                let source = existing.source.take();
+                *existing = JournalEntry { source, ..entry };
+            }
+            None => self.entries.push(entry),
+        }
+        self.save()
+    }
+
+    /// 記録済みのタスクに取得元を記録する
+    ///
+    /// # 副作用
+    /// - ジャーナルファイルへの書き込み
+    ///
+    /// # 事後条件
+    /// - task_id のエントリがない場合は何もしない
+    pub fn attach_source(&mut self, task_id: &str, source: JournalSource) -> AppResult<()> {
+        let Some(entry) = self
+            .entries
+            .iter_mut()
+            .find(|entry| entry.task_id == task_id)
+        else {
+            return Ok(());
+        };
+        entry.source = Some(source);
+        self.save()
+    }
+
+    /// 全エントリを取得
+    pub fn entries(&self) -> &[JournalEntry] {
+        &self.entries
+    }
+
+    /// 未完了のエントリを取得
+    pub fn unfinished(&self) -> Vec<JournalEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.is_unfinished())
+            .cloned()
+            .collect()
+    }
+
+    /// 完了・キャンセル済みのエントリを取り除く
+    ///
+    /// # 副作用
+    /// - ジャーナルファイルへの書き込み
+    pub fn prune_finished(&mut self) -> AppResult<()> {
+        self.entries.retain(|entry| entry.is_unfinished());
+        self.save()
+    }
+
+    /// 全エントリを破棄する
+    ///
+    /// # 副作用
+    /// - ジャーナルファイルへの書き込み
+    pub fn clear(&mut self) -> AppResult<()> {
+        self.entries.clear();
+        self.save()
+    }
+
+    /// ジャーナルをファイルへ保存する（一時ファイル経由で置き換え）
+    fn save(&self) -> AppResult<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| {
+                AppError::file_system("Failed to create journal directory", Some(e))
+            })?;
+        }
+
+        let file = JournalFile {
+            version: JOURNAL_VERSION,
+            entries: self.entries.clone(),
+        };
+        let content = serde_json::to_string_pretty(&file).map_err(|e| {
+            AppError::data_integrity(format!("Failed to serialize download journal: {}", e))
+        })?;
+
+        let mut tmp_path = self.path.clone().into_os_string();
+        tmp_path.push(".tmp");
+        let tmp_path = PathBuf::from(tmp_path);
+
+        std::fs::write(&tmp_path, content)
+            .map_err(|e| AppError::file_system("Failed to write download journal", Some(e)))?;
+        std::fs::rename(&tmp_path, &self.path)
+            .map_err(|e| AppError::file_system("Failed to replace download journal", Some(e)))?;
+        Ok(())
+    }
+}
+
+/// URLから access_token クエリパラメータを除去する
+///
+/// # 事後条件
+/// - access_token 以外のクエリパラメータは保持される
+/// - URLとして解析できない場合は入力をそのまま返す
+pub fn strip_access_token(url: &str) -> String {
+    let Ok(mut parsed) = url::Url::parse(url) else {
+        return url.to_string();
+    };
+
+    let remaining: Vec<(String, String)> = parsed
+        .query_pairs()
+        .filter(|(key, _)| key != "access_token")
+        .map(|(key, value)| (key.into_owned(), value.into_owned()))
+        .collect();
+
+    if remaining.is_empty() {
+        parsed.set_query(None);
+    } else {
+        parsed.query_pairs_mut().clear().extend_pairs(remaining);
+    }
+    parsed.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn make_task(task_id: &str, state: TaskState) -> DownloadTask {
+        DownloadTask {
+            task_id: task_id.to_string(),
+            download_url: "https://zoom.us/rec/download/abc?access_token=secret".to_string(),
+            output_path: PathBuf::from("downloads/video.mp4"),
+            expected_size: Some(100),
+            file_name: "video.mp4".to_string(),
+            state,
+            progress: DownloadProgress::default(),
+            error: None,
+            retry_count: 0,
+            priority: TaskPriority::default(),
+            attempts: Vec::new(),
+            retry_at: None,
+            sha256: None,
+        }
+    }
+
+    #[test]
+    fn test_strip_access_token() {
+        assert_eq!(
+            strip_access_token("https://zoom.us/rec/download/abc?access_token=secret"),
+            "https://zoom.us/rec/download/abc"
+        );
+        assert_eq!(
+            strip_access_token("https://zoom.us/rec/download/abc?type=mp4&access_token=secret"),
+            "https://zoom.us/rec/download/abc?type=mp4"
+        );
+        assert_eq!(strip_access_token("not a url"), "not a url");
+    }
+
+    #[test]
+    fn test_journal_roundtrip_keeps_unfinished_tasks() {
+        let temp_dir = tempdir().unwrap();
+        let path = temp_dir.path().join("journal.json");
+
+        let mut journal = DownloadJournal::open(&path).unwrap();
+        let mut task = make_task("task-1", TaskState::InProgress);
+        task.progress.downloaded_bytes = 42;
+        task.retry_count = 1;
+        journal.record(&task).unwrap();
+        journal
+            .record(&make_task("task-2", TaskState::Completed))
+            .unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(!content.contains("secret"));
+
+        let reopened = DownloadJournal::open(&path).unwrap();
+        assert_eq!(reopened.entries().len(), 2);
+        let unfinished = reopened.unfinished();
+        assert_eq!(unfinished.len(), 1);
+        assert_eq!(unfinished[0].task_id, "task-1");
+        assert_eq!(unfinished[0].bytes_written, 42);
+        assert_eq!(unfinished[0].retry_count, 1);
+        assert_eq!(
+            unfinished[0].download_url,
+            "https://zoom.us/rec/download/abc"
+        );
+    }
+
+    #[test]
+    fn test_journal_prune_and_failed_task_restore() {
+        let temp_dir = tempdir().unwrap();
+        let path = temp_dir.path().join("journal.json");
+
+        let mut journal = DownloadJournal::open(&path).unwrap();
+        let mut failed = make_task("task-1", TaskState::Failed);
+        failed.retry_count = 3;
+        journal.record(&failed).unwrap();
+        journal
+            .record(&make_task("task-2", TaskState::Cancelled))
+            .unwrap();
+        journal.prune_finished().unwrap();
+
+        let reopened = DownloadJournal::open(&path).unwrap();
+        assert_eq!(reopened.entries().len(), 1);
+
+        let restored = reopened.entries()[0].to_task("https://example.com/dl".to_string());
+        assert_eq!(restored.state, TaskState::Pending);
+        assert_eq!(restored.retry_count, 0);
+    }
+
+    #[test]
+    fn test_attached_source_survives_state_updates() {
+        let temp_dir = tempdir().unwrap();
+        let path = temp_dir.path().join("journal.json");
+        let meeting: MeetingRecording = serde_json::from_value(serde_json::json!({
+            "uuid": "m1",
+            "id": 1,
+            "host_id": "me",
+            "topic": "Weekly",
+            "start_time": "2025-01-01T10:00:00Z",
+            "duration": 30,
+            "recording_files": [{
+                "id": "f1",
+                "file_type": "MP4",
+                "download_url": "https://zoom.us/rec/download/abc?access_token=secret"
+            }]
+        }))
+        .unwrap();
+
+        let mut journal = DownloadJournal::open(&path).unwrap();
+        let mut task = make_task("m1-f1", TaskState::Pending);
+        journal.record(&task).unwrap();
+        journal
+            .attach_source(
+                "m1-f1",
+                JournalSource::new(&meeting, &meeting.recording_files[0]),
+            )
+            .unwrap();
+        task.state = TaskState::InProgress;
+        journal.record(&task).unwrap();
+
+        assert!(!std::fs::read_to_string(&path).unwrap().contains("secret"));
+        let reopened = DownloadJournal::open(&path).unwrap();
+        let source = reopened.entries()[0].source.as_ref().unwrap();
+        assert_eq!(source.meeting.uuid, "m1");
+        assert!(source.meeting.recording_files.is_empty());
+        assert_eq!(source.file.id, "f1");
+        assert_eq!(source.file.download_url, "https://zoom.us/rec/download/abc");
+    }
+
+    #[test]
+    fn test_open_corrupted_journal_returns_error() {
+        let temp_dir = tempdir().unwrap();
+        let path = temp_dir.path().join("journal.json");
+        std::fs::write(&path, "{ not json").unwrap();
+
+        assert!(DownloadJournal::open(&path).is_err());
+    }
+}
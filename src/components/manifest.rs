@@ -0,0 +1,384 @@
+//! ダウンロードマニフェストコンポーネント
+//!
+//! # 責任
+//! - 出力ディレクトリごとのダウンロード済みファイルの記録
+//! - 既にダウンロード済みのファイルの判定（差分同期）
+//! - 削除・サイズ変更された記録の検出
+
+use crate::errors::{AppError, AppResult};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// マニフェストファイル名（出力ディレクトリ直下に作成）
+pub const MANIFEST_FILE_NAME: &str = ".zoom_video_mover_manifest.json";
+
+/// マニフェストファイル形式のバージョン
+const MANIFEST_VERSION: u32 = 1;
+
+/// ダウンロード済みファイルの記録
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    /// 録画ファイルの安定ID（RecordingFile::stable_id）
+    pub stable_id: String,
+    /// 出力ディレクトリからの相対パス
+    pub path: PathBuf,
+    /// ファイルサイズ（バイト）
+    pub size: u64,
+    /// ダウンロード完了日時
+    pub completed_at: DateTime<Utc>,
+    /// 内容の SHA-256（16進小文字）
+    pub sha256: String,
+}
+
+/// マニフェストとの照合結果
+#[derive(Debug, Clone, PartialEq)]
+pub enum ManifestStatus {
+    /// 記録済みで、ファイルも記録どおり存在する
+    Intact,
+    /// 記録がない
+    NotRecorded,
+    /// 記録はあるが、ファイルの削除・変更などにより無効
+    Stale(String),
+}
+
+/// マニフェストファイルの内容
+#[derive(Debug, Serialize, Deserialize)]
+struct ManifestFile {
+    version: u32,
+    entries: BTreeMap<String, ManifestEntry>,
+}
+
+/// 出力ディレクトリ単位のダウンロードマニフェスト
+#[derive(Debug)]
+pub struct DownloadManifest {
+    /// 出力ディレクトリ
+    output_dir: PathBuf,
+    /// stable_id → 記録
+    entries: BTreeMap<String, ManifestEntry>,
+}
+
+impl DownloadManifest {
+    /// 空のマニフェストを作成（ファイルは最初の記録時に作成される）
+    pub fn new(output_dir: impl AsRef<Path>) -> Self {
+        Self {
+            output_dir: output_dir.as_ref().to_path_buf(),
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// 出力ディレクトリのマニフェストファイルパスを取得
+    pub fn manifest_path(output_dir: impl AsRef<Path>) -> PathBuf {
+        output_dir.as_ref().join(MANIFEST_FILE_NAME)
+    }
+
+    /// 出力ディレクトリのマニフェストを開く
+    ///
+    /// # 副作用
+    /// - ファイルシステムからの読み込み
+    ///
+    /// # 事後条件
+    /// - ファイルが存在しない場合は空のマニフェストが返される
+    /// - ファイルが破損している場合は data_integrity エラーが返される
+    pub fn open(output_dir: impl AsRef<Path>) -> AppResult<Self> {
+        let output_dir = output_dir.as_ref().to_path_buf();
+        let path = Self::manifest_path(&output_dir);
+
+        if !path.exists() {
+            return Ok(Self::new(output_dir));
+        }
+
+        let content = std::fs::read_to_string(&path)
+            .map_err(|e| AppError::file_system("Failed to read download manifest", Some(e)))?;
+        let file: ManifestFile = serde_json::from_str(&content).map_err(|e| {
+            AppError::data_integrity(format!("Download manifest is corrupted: {}", e))
+        })?;
+
+        Ok(Self {
+            output_dir,
+            entries: file.entries,
+        })
+    }
+
+    /// 記録を取得
+    pub fn get(&self, stable_id: &str) -> Option<&ManifestEntry> {
+        self.entries.get(stable_id)
+    }
+
+    /// 全記録を取得（stable_id順）
+    pub fn entries(&self) -> impl Iterator<Item = &ManifestEntry> {
+        self.entries.values()
+    }
+
+    /// ファイルが記録どおり存在するか照合する
+    ///
+    /// # 事後条件
+    /// - 記録のパス・サイズと実ファイルが一致し、期待サイズ（判明時）とも一致する場合のみ Intact
+    /// - 内容のハッシュは照合しない（大容量ファイルの再読込を避けるため）
+    pub fn check(
+        &self,
+        stable_id: &str,
+        relative_path: &Path,
+        expected_size: Option<u64>,
+    ) -> ManifestStatus {
+        let Some(entry) = self.entries.get(stable_id) else {
+            return ManifestStatus::NotRecorded;
+        };
+
+        if entry.path != relative_path {
+            return ManifestStatus::Stale(format!(
+                "recorded path differs: {}",
+                entry.path.display()
+            ));
+        }
+        if let Some(expected_size) = expected_size {
+            if expected_size != entry.size {
+                return ManifestStatus::Stale(format!(
+                    "source size changed: recorded {}, expected {}",
+                    entry.size, expected_size
+                ));
+            }
+        }
+
+        match std::fs::metadata(self.output_dir.join(relative_path)) {
+            Ok(metadata) if metadata.len() == entry.size => ManifestStatus::Intact,
+            Ok(metadata) => ManifestStatus::Stale(format!(
+                "file size changed: recorded {}, found {}",
+                entry.size,
+                metadata.len()
+            )),
+            Err(_) => ManifestStatus::Stale("file was deleted".to_string()),
+        }
+    }
+
+    /// ダウンロード済みファイルを記録する
+    ///
+    /// # 副作用
+    /// - 対象ファイルの読み込み（SHA-256 計算）
+    /// - マニフェストファイルへの書き込み
+    ///
+    /// # 事前条件
+    /// - relative_path のファイルが出力ディレクトリ内に存在する
+    ///
+    /// # 事後条件
+    /// - 同じ stable_id の記録は上書きされる
+    pub fn record(&mut self, stable_id: &str, relative_path: &Path) -> AppResult<ManifestEntry> {
+        let sha256 = sha256_file(&self.output_dir.join(relative_path))?;
+        self.record_hashed(stable_id, relative_path, sha256)
+    }
+
+    /// ハッシュ計算済みのダウンロード済みファイルを記録する
+    ///
+    /// # 副作用
+    /// - マニフェストファイルへの書き込み
+    ///
+    /// # 事前条件
+    /// - relative_path のファイルが出力ディレクトリ内に存在する
+    /// - sha256 はダウンロード時に計算したファイル内容のハッシュである
+    ///
+    /// # 事後条件
+    /// - 同じ stable_id の記録は上書きされる
+    pub fn record_hashed(
+        &mut self,
+        stable_id: &str,
+        relative_path: &Path,
+        sha256: String,
+    ) -> AppResult<ManifestEntry> {
+        let size = std::fs::metadata(self.output_dir.join(relative_path))
+            .map_err(|e| AppError::file_system("Failed to read downloaded file", Some(e)))?
+            .len();
+        let entry = ManifestEntry {
+            stable_id: stable_id.to_string(),
+            path: relative_path.to_path_buf(),
+            size,
+            completed_at: Utc::now(),
+            sha256,
+        };
+
+        self.entries.insert(stable_id.to_string(), entry.clone());
+        self.save()?;
+        Ok(entry)
+    }
+
+    /// 未記録の既存ファイルが期待サイズと一致する場合に記録へ取り込む
+    ///
+    /// マニフェスト導入前に保存されたファイルを再ダウンロードしないために使う。
+    ///
+    /// # 事後条件
+    /// - 取り込んだ場合は true、期待サイズ不明・不一致・ファイルなしの場合は false
+    pub fn adopt_existing(
+        &mut self,
+        stable_id: &str,
+        relative_path: &Path,
+        expected_size: Option<u64>,
+    ) -> AppResult<bool> {
+        let Some(expected_size) = expected_size else {
+            return Ok(false);
+        };
+        let matches = std::fs::metadata(self.output_dir.join(relative_path))
+            .map(|metadata| metadata.is_file() && metadata.len() == expected_size)
+            .unwrap_or(false);
+        if !matches {
+            return Ok(false);
+        }
+
+        self.record(stable_id, relative_path)?;
+        Ok(true)
+    }
+
+    /// 記録を削除する
+    ///
+    /// # 副作用
+    /// - マニフェストファイルへの書き込み
+    pub fn remove(&mut self, stable_id: &str) -> AppResult<()> {
+        if self.entries.remove(stable_id).is_some() {
+            self.save()?;
+        }
+        Ok(())
+    }
+
+    /// マニフェストをファイルへ保存する（一時ファイル経由で置き換え）
+    fn save(&self) -> AppResult<()> {
+        std::fs::create_dir_all(&self.output_dir)
+            .map_err(|e| AppError::file_system("Failed to create output directory", Some(e)))?;
+
+        let file = ManifestFile {
+            version: MANIFEST_VERSION,
+            entries: self.entries.clone(),
+        };
+        let content = serde_json::to_string_pretty(&file).map_err(|e| {
+            AppError::data_integrity(format!("Failed to serialize download manifest: {}", e))
+        })?;
+
+        let path = Self::manifest_path(&self.output_dir);
+        let mut tmp_path = path.clone().into_os_string();
+        tmp_path.push(".tmp");
+        let tmp_path = PathBuf::from(tmp_path);
+
+        std::fs::write(&tmp_path, content)
+            .map_err(|e| AppError::file_system("Failed to write download manifest", Some(e)))?;
+        std::fs::rename(&tmp_path, &path)
+            .map_err(|e| AppError::file_system("Failed to replace download manifest", Some(e)))?;
+        Ok(())
+    }
+}
+
+/// ファイル内容の SHA-256 を16進小文字で返す
+///
+/// # 副作用
+/// - ファイル全体の読み込み
+pub fn sha256_file(path: &Path) -> AppResult<String> {
+    Ok(format!("{:x}", sha256_hasher(path)?.finalize()))
+}
+
+/// ファイル内容を読み込んだ SHA-256 の計算状態を返す
+///
+/// 途中まで取得したファイルに続きを追記しながらハッシュを計算する場合に使う。
+///
+/// # 副作用
+/// - ファイル全体の読み込み
+pub fn sha256_hasher(path: &Path) -> AppResult<Sha256> {
+    let mut file = std::fs::File::open(path)
+        .map_err(|e| AppError::file_system("Failed to open file for hashing", Some(e)))?;
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut file, &mut hasher)
+        .map_err(|e| AppError::file_system("Failed to read file for hashing", Some(e)))?;
+    Ok(hasher)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_sha256_file() {
+        let temp_dir = tempdir().unwrap();
+        let path = temp_dir.path().join("hello.txt");
+        std::fs::write(&path, b"hello").unwrap();
+
+        assert_eq!(
+            sha256_file(&path).unwrap(),
+            "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+        );
+    }
+
+    #[test]
+    fn test_record_and_check_intact_file() {
+        let temp_dir = tempdir().unwrap();
+        let relative = Path::new("2025-01-01/meeting.mp4");
+        std::fs::create_dir_all(temp_dir.path().join("2025-01-01")).unwrap();
+        std::fs::write(temp_dir.path().join(relative), b"video").unwrap();
+
+        let mut manifest = DownloadManifest::open(temp_dir.path()).unwrap();
+        assert_eq!(
+            manifest.check("file-1", relative, Some(5)),
+            ManifestStatus::NotRecorded
+        );
+        let entry = manifest.record("file-1", relative).unwrap();
+        assert_eq!(entry.size, 5);
+
+        let reopened = DownloadManifest::open(temp_dir.path()).unwrap();
+        assert_eq!(reopened.get("file-1"), Some(&entry));
+        assert_eq!(
+            reopened.check("file-1", relative, Some(5)),
+            ManifestStatus::Intact
+        );
+        assert_eq!(
+            reopened.check("file-1", relative, None),
+            ManifestStatus::Intact
+        );
+    }
+
+    #[test]
+    fn test_check_detects_stale_entries() {
+        let temp_dir = tempdir().unwrap();
+        let relative = Path::new("meeting.mp4");
+        let full_path = temp_dir.path().join(relative);
+        std::fs::write(&full_path, b"video").unwrap();
+
+        let mut manifest = DownloadManifest::open(temp_dir.path()).unwrap();
+        manifest.record("file-1", relative).unwrap();
+
+        // 元ファイルのサイズが変わった
+        assert!(matches!(
+            manifest.check("file-1", relative, Some(10)),
+            ManifestStatus::Stale(_)
+        ));
+
+        // ローカルのファイルが変更された
+        std::fs::write(&full_path, b"truncated video").unwrap();
+        assert!(matches!(
+            manifest.check("file-1", relative, None),
+            ManifestStatus::Stale(_)
+        ));
+
+        // ローカルのファイルが削除された
+        std::fs::remove_file(&full_path).unwrap();
+        assert_eq!(
+            manifest.check("file-1", relative, None),
+            ManifestStatus::Stale("file was deleted".to_string())
+        );
+
+        manifest.remove("file-1").unwrap();
+
+        // 期待サイズと一致する既存ファイルのみ取り込む
+        std::fs::write(&full_path, b"video").unwrap();
+        assert!(!manifest.adopt_existing("file-1", relative, None).unwrap());
+        assert!(!manifest
+            .adopt_existing("file-1", relative, Some(6))
+            .unwrap());
+        assert!(manifest
+            .adopt_existing("file-1", relative, Some(5))
+            .unwrap());
+        manifest.remove("file-1").unwrap();
+        assert_eq!(
+            DownloadManifest::open(temp_dir.path())
+                .unwrap()
+                .check("file-1", relative, None),
+            ManifestStatus::NotRecorded
+        );
+    }
+}
@@ -6,13 +6,29 @@
 //! - 依存関係の明確化
 
 pub mod api;
+pub mod archive;
 pub mod auth;
+pub mod concurrency;
 pub mod config;
 pub mod crypto;
+pub mod disk_space;
 pub mod download;
+pub mod download_client;
+pub mod event_bus;
+pub mod failed_batch;
 pub mod integration;
+pub mod journal;
+pub mod manifest;
+pub mod rate_limit;
 pub mod recording;
+pub mod redaction;
+pub mod report;
+pub mod retry;
+pub mod segmented;
+pub mod token_provider;
+pub mod trash;
 pub mod ui;
+pub mod watchdog;
 
 // 共通トレイトとタイプ定義
 use crate::errors::AppResult;
@@ -0,0 +1,878 @@
+//! レート制限コンポーネント
+//!
+//! # 責任
+//! - Token Bucket によるレート制限（APIリクエスト数・転送バイト数）
+//! - Zoom API のカテゴリ別レート制限と1日あたりの上限の管理
+//! - 時間帯別の帯域スケジュール管理
+//! - 全ダウンロードワーカーで共有する帯域制限
+
+use crate::errors::{AppError, AppResult};
+use chrono::{DateTime, Local, NaiveDate, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// 帯域制限待機の最大単位（制限値の変更を速やかに反映するため）
+const MAX_THROTTLE_WAIT: Duration = Duration::from_millis(250);
+
+/// Token Bucket レート制限実装
+#[derive(Debug)]
+pub(crate) struct TokenBucket {
+    capacity: f64,
+    tokens: f64,
+    refill_rate: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    /// 任意の単位（バイト数など）で補充速度と容量を指定して作成する
+    ///
+    /// # 事後条件
+    /// - 作成直後は容量いっぱいのトークンを保持する
+    pub(crate) fn with_rate(refill_rate: f64, capacity: f64) -> Self {
+        Self {
+            capacity,
+            tokens: capacity,
+            refill_rate,
+            last_refill: Instant::now(),
+        }
+    }
+
+    fn refill_tokens(&mut self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+
+        let tokens_to_add = elapsed * self.refill_rate;
+        self.tokens = (self.tokens + tokens_to_add).min(self.capacity);
+        self.last_refill = now;
+    }
+
+    pub(crate) fn try_consume_token(&mut self) -> bool {
+        self.try_consume(1.0)
+    }
+
+    /// 指定量のトークンを消費する（不足時は消費せず false）
+    pub(crate) fn try_consume(&mut self, amount: f64) -> bool {
+        self.refill_tokens();
+
+        if self.tokens >= amount {
+            self.tokens -= amount;
+            true
+        } else {
+            false
+        }
+    }
+
+    pub(crate) fn time_until_next_token(&self) -> Duration {
+        self.time_until_available(1.0)
+    }
+
+    /// 指定量のトークンが貯まるまでの時間
+    pub(crate) fn time_until_available(&self, amount: f64) -> Duration {
+        if self.tokens >= amount {
+            Duration::ZERO
+        } else {
+            let tokens_needed = amount - self.tokens;
+            let seconds_to_wait = tokens_needed / self.refill_rate;
+            Duration::from_secs_f64(seconds_to_wait)
+        }
+    }
+
+    /// 補充速度と容量を変更する（保持トークンは新しい容量に切り詰める）
+    pub(crate) fn set_rate(&mut self, refill_rate: f64, capacity: f64) {
+        self.refill_tokens();
+        self.refill_rate = refill_rate;
+        self.capacity = capacity;
+        self.tokens = self.tokens.min(capacity);
+    }
+
+    /// 保持トークンを使い切った状態にする（サーバー側の残数が尽きた場合）
+    pub(crate) fn drain(&mut self) {
+        self.refill_tokens();
+        self.tokens = 0.0;
+    }
+}
+
+/// Zoom API のレート制限カテゴリ
+///
+/// Zoom はエンドポイントごとにカテゴリを割り当て、カテゴリ単位で秒間上限を適用する。
+/// Heavy と Resource-intensive には1日あたりの上限もある（両カテゴリで共有）。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RateLimitCategory {
+    Light,
+    Medium,
+    Heavy,
+    ResourceIntensive,
+}
+
+impl RateLimitCategory {
+    /// 1日あたりの上限が適用されるか
+    pub fn has_daily_quota(self) -> bool {
+        matches!(self, Self::Heavy | Self::ResourceIntensive)
+    }
+
+    /// X-RateLimit-Category ヘッダーの値から変換する（大文字小文字・区切り文字は区別しない）
+    pub fn from_header(value: &str) -> Option<Self> {
+        let normalized: String = value
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .collect::<String>()
+            .to_ascii_lowercase();
+        match normalized.as_str() {
+            "light" => Some(Self::Light),
+            "medium" => Some(Self::Medium),
+            "heavy" => Some(Self::Heavy),
+            "resourceintensive" => Some(Self::ResourceIntensive),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for RateLimitCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Light => "Light",
+            Self::Medium => "Medium",
+            Self::Heavy => "Heavy",
+            Self::ResourceIntensive => "Resource-intensive",
+        };
+        f.write_str(name)
+    }
+}
+
+/// カテゴリ1つ分の秒間上限
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CategoryLimit {
+    /// 秒あたりのリクエスト数（Resource-intensive のように1未満も指定できる）
+    pub requests_per_second: f64,
+    /// バースト容量
+    pub burst_size: u32,
+}
+
+/// カテゴリ別の上限
+///
+/// 既定値は Zoom Pro プランの上限（Light=30, Medium=20, Heavy=10 req/sec,
+/// Resource-intensive=10 req/min, 1日30,000件）に余裕を持たせた値。
+#[derive(Debug, Clone, PartialEq)]
+pub struct CategoryLimits {
+    pub light: CategoryLimit,
+    pub medium: CategoryLimit,
+    pub heavy: CategoryLimit,
+    pub resource_intensive: CategoryLimit,
+    /// Heavy / Resource-intensive で共有する1日あたりのリクエスト数
+    pub daily_quota: u32,
+}
+
+impl Default for CategoryLimits {
+    fn default() -> Self {
+        Self {
+            light: CategoryLimit {
+                requests_per_second: 20.0,
+                burst_size: 30,
+            },
+            medium: CategoryLimit {
+                requests_per_second: 10.0,
+                burst_size: 20,
+            },
+            heavy: CategoryLimit {
+                requests_per_second: 5.0,
+                burst_size: 10,
+            },
+            resource_intensive: CategoryLimit {
+                requests_per_second: 0.15,
+                burst_size: 1,
+            },
+            daily_quota: 30_000,
+        }
+    }
+}
+
+impl CategoryLimits {
+    /// カテゴリの秒間上限を取得
+    pub fn get(&self, category: RateLimitCategory) -> CategoryLimit {
+        match category {
+            RateLimitCategory::Light => self.light,
+            RateLimitCategory::Medium => self.medium,
+            RateLimitCategory::Heavy => self.heavy,
+            RateLimitCategory::ResourceIntensive => self.resource_intensive,
+        }
+    }
+}
+
+/// レート制限ヘッダーが示す上限の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitType {
+    /// 秒間上限（X-RateLimit-Type: QPS）
+    PerSecond,
+    /// 1日あたりの上限（X-RateLimit-Type: Daily-limit）
+    Daily,
+}
+
+/// レスポンスのレート制限ヘッダー
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RateLimitHeaders {
+    /// X-RateLimit-Category
+    pub category: Option<RateLimitCategory>,
+    /// X-RateLimit-Type
+    pub limit_type: Option<RateLimitType>,
+    /// X-RateLimit-Limit
+    pub limit: Option<u32>,
+    /// X-RateLimit-Remaining
+    pub remaining: Option<u32>,
+}
+
+impl RateLimitHeaders {
+    /// レスポンスヘッダーから読み取る（存在しない・解釈できない値は None）
+    pub fn parse(headers: &reqwest::header::HeaderMap) -> Self {
+        let text = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+        let number = |name: &str| text(name).and_then(|value| value.trim().parse::<u32>().ok());
+        Self {
+            category: text("x-ratelimit-category").and_then(RateLimitCategory::from_header),
+            limit_type: text("x-ratelimit-type").map(|value| {
+                if value.to_ascii_lowercase().contains("daily") {
+                    RateLimitType::Daily
+                } else {
+                    RateLimitType::PerSecond
+                }
+            }),
+            limit: number("x-ratelimit-limit"),
+            remaining: number("x-ratelimit-remaining"),
+        }
+    }
+}
+
+/// 1日あたりの上限の消費状況（Zoom は UTC 0時にリセットする）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DailyQuotaUsage {
+    /// 集計日（UTC）
+    pub date: NaiveDate,
+    /// 消費したリクエスト数
+    pub used: u32,
+    /// 1日あたりの上限（レスポンスヘッダーで判明した場合はその値）
+    pub limit: u32,
+}
+
+impl DailyQuotaUsage {
+    fn new(date: NaiveDate, limit: u32) -> Self {
+        Self {
+            date,
+            used: 0,
+            limit,
+        }
+    }
+
+    /// 残りのリクエスト数
+    pub fn remaining(&self) -> u32 {
+        self.limit.saturating_sub(self.used)
+    }
+}
+
+/// ApiRateLimiter::try_acquire の結果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitDecision {
+    /// リクエストを送信してよい
+    Granted,
+    /// 秒間上限のため、指定時間後に再度取得する
+    Wait(Duration),
+    /// 1日あたりの上限に達した（指定時間後の UTC 0時にリセットされる）
+    QuotaExhausted { resets_in: Duration },
+}
+
+/// 1日あたりの上限の消費状況ファイル名
+const QUOTA_FILE_NAME: &str = "api_quota.json";
+
+/// 消費状況を保存する最短間隔（間隔内の消費はまとめて次の保存か drop 時に書き込む）
+const QUOTA_SAVE_INTERVAL: Duration = Duration::from_secs(5);
+
+/// カテゴリ別の API レート制限
+///
+/// カテゴリごとの Token Bucket で秒間上限を守り、Heavy / Resource-intensive は
+/// 1日あたりの上限も数える。レスポンスのレート制限ヘッダーから残数を学習し、
+/// 保存先を設定した場合は消費状況を保存して同じ日の次回起動時に引き継ぐ。
+/// 保存は QUOTA_SAVE_INTERVAL ごとにまとめ、未保存の消費は flush または drop 時に書き込む。
+#[derive(Debug)]
+pub struct ApiRateLimiter {
+    buckets: HashMap<RateLimitCategory, TokenBucket>,
+    daily: DailyQuotaUsage,
+    /// 消費状況の保存先（None の場合は保存しない）
+    store: Option<PathBuf>,
+    /// 最後に保存した時刻
+    last_saved: Option<Instant>,
+    /// 保存していない消費があるか
+    unsaved: bool,
+}
+
+impl ApiRateLimiter {
+    /// カテゴリ別の上限を指定して作成
+    pub fn new(limits: &CategoryLimits) -> Self {
+        let buckets = [
+            RateLimitCategory::Light,
+            RateLimitCategory::Medium,
+            RateLimitCategory::Heavy,
+            RateLimitCategory::ResourceIntensive,
+        ]
+        .into_iter()
+        .map(|category| {
+            let limit = limits.get(category);
+            let bucket = TokenBucket::with_rate(
+                limit.requests_per_second.max(f64::MIN_POSITIVE),
+                limit.burst_size.max(1) as f64,
+            );
+            (category, bucket)
+        })
+        .collect();
+
+        Self {
+            buckets,
+            daily: DailyQuotaUsage::new(Utc::now().date_naive(), limits.daily_quota),
+            store: None,
+            last_saved: None,
+            unsaved: false,
+        }
+    }
+
+    /// 既定の消費状況ファイルパスを取得
+    pub fn default_store_path() -> AppResult<PathBuf> {
+        let mut path = dirs::config_dir().ok_or_else(|| {
+            AppError::file_system(
+                "Could not determine config directory",
+                None::<std::io::Error>,
+            )
+        })?;
+
+        // Windows: %APPDATA%\ZoomVideoMover\api_quota.json
+        #[cfg(target_os = "windows")]
+        path.push("ZoomVideoMover");
+
+        // Unix-like: ~/.config/zoom-video-mover/api_quota.json
+        #[cfg(not(target_os = "windows"))]
+        path.push("zoom-video-mover");
+
+        path.push(QUOTA_FILE_NAME);
+        Ok(path)
+    }
+
+    /// 消費状況の保存先を設定する
+    ///
+    /// # 副作用
+    /// - 保存済みの消費状況の読み込み
+    ///
+    /// # 事後条件
+    /// - 保存済みの消費状況が引き継がれる（日付が変わっていれば次の取得時にリセットされる）
+    /// - 読めない保存ファイルは無視される（次回の保存で置き換えられる）
+    pub fn with_store(mut self, path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        if let Some(saved) = Self::load(&path) {
+            self.daily = saved;
+        }
+        self.store = Some(path);
+        self
+    }
+
+    /// 消費状況の保存先
+    pub fn store_path(&self) -> Option<&Path> {
+        self.store.as_deref()
+    }
+
+    /// 1日あたりの上限の消費状況
+    pub fn daily_usage(&self) -> &DailyQuotaUsage {
+        &self.daily
+    }
+
+    /// リクエスト1件分の枠を取得する
+    ///
+    /// # 副作用
+    /// - 1日あたりの上限があるカテゴリは消費数を加算し、保存間隔を過ぎていれば保存先に書き込む
+    ///
+    /// # 事後条件
+    /// - Granted の場合のみ枠が消費される
+    pub fn try_acquire(
+        &mut self,
+        category: RateLimitCategory,
+        now: DateTime<Utc>,
+    ) -> RateLimitDecision {
+        self.roll_over(now);
+        if category.has_daily_quota() && self.daily.remaining() == 0 {
+            return RateLimitDecision::QuotaExhausted {
+                resets_in: Self::until_reset(now),
+            };
+        }
+
+        let Some(bucket) = self.buckets.get_mut(&category) else {
+            return RateLimitDecision::Granted;
+        };
+        if !bucket.try_consume_token() {
+            return RateLimitDecision::Wait(bucket.time_until_next_token());
+        }
+
+        if category.has_daily_quota() {
+            self.daily.used += 1;
+            self.save_debounced();
+        }
+        RateLimitDecision::Granted
+    }
+
+    /// レスポンスのレート制限ヘッダーから残数を学習する
+    ///
+    /// # 副作用
+    /// - 1日あたりの残数が示された場合は消費状況を更新し、保存間隔を過ぎているか上限に達した場合は保存する
+    ///
+    /// # 事後条件
+    /// - ヘッダーのカテゴリが判明した場合は requested より優先する
+    /// - 秒間上限の残数が 0 の場合、そのカテゴリのトークンを使い切った状態にする
+    pub fn observe(
+        &mut self,
+        requested: RateLimitCategory,
+        headers: &RateLimitHeaders,
+        now: DateTime<Utc>,
+    ) {
+        let category = headers.category.unwrap_or(requested);
+        match headers.limit_type {
+            Some(RateLimitType::Daily) => {
+                self.roll_over(now);
+                if let Some(limit) = headers.limit {
+                    self.daily.limit = limit;
+                }
+                if let Some(remaining) = headers.remaining {
+                    self.daily.used = self.daily.limit.saturating_sub(remaining);
+                    if remaining == 0 {
+                        log::warn!(
+                            "Daily API quota for {} requests is exhausted until 00:00 UTC",
+                            category
+                        );
+                    }
+                }
+                self.save_debounced();
+            }
+            Some(RateLimitType::PerSecond) if headers.remaining == Some(0) => {
+                if let Some(bucket) = self.buckets.get_mut(&category) {
+                    bucket.drain();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// 日付が変わっていれば消費状況をリセットする
+    fn roll_over(&mut self, now: DateTime<Utc>) {
+        let today = now.date_naive();
+        if self.daily.date != today {
+            self.daily = DailyQuotaUsage::new(today, self.daily.limit);
+        }
+    }
+
+    /// 次の UTC 0時までの時間
+    fn until_reset(now: DateTime<Utc>) -> Duration {
+        let next_midnight = now
+            .date_naive()
+            .succ_opt()
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+            .map(|midnight| midnight.and_utc());
+        next_midnight
+            .and_then(|midnight| (midnight - now).to_std().ok())
+            .unwrap_or(Duration::ZERO)
+    }
+
+    fn load(path: &Path) -> Option<DailyQuotaUsage> {
+        let content = std::fs::read_to_string(path).ok()?;
+        serde_json::from_str(&content)
+            .map_err(|e| log::warn!("Ignoring unreadable API quota file: {}", e))
+            .ok()
+    }
+
+    /// 未保存の消費状況を保存先に書き込む
+    ///
+    /// # 副作用
+    /// - 未保存の消費がある場合のみファイルへの書き込み
+    pub fn flush(&mut self) {
+        if self.unsaved {
+            self.save();
+        }
+    }
+
+    /// 前回の保存から QUOTA_SAVE_INTERVAL 以上経過していれば保存し、それ以外は未保存として残す
+    ///
+    /// 上限に達したことは他の実行がすぐ参照できるよう、間隔を待たずに保存する。
+    fn save_debounced(&mut self) {
+        let due = self.daily.remaining() == 0
+            || self
+                .last_saved
+                .map_or(true, |saved| saved.elapsed() >= QUOTA_SAVE_INTERVAL);
+        if due {
+            self.save();
+        } else {
+            self.unsaved = true;
+        }
+    }
+
+    /// 消費状況を保存する（同じ日の保存済みの値が大きい場合はそちらを残す）
+    fn save(&mut self) {
+        self.unsaved = false;
+        let Some(path) = &self.store else {
+            return;
+        };
+        self.last_saved = Some(Instant::now());
+        if let Some(saved) = Self::load(path) {
+            if saved.date == self.daily.date && saved.used > self.daily.used {
+                self.daily.used = saved.used;
+            }
+        }
+
+        let result = serde_json::to_string_pretty(&self.daily)
+            .map_err(|e| e.to_string())
+            .and_then(|content| {
+                if let Some(parent) = path.parent() {
+                    std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+                }
+                std::fs::write(path, content).map_err(|e| e.to_string())
+            });
+        if let Err(e) = result {
+            log::warn!("Failed to save API quota usage: {}", e);
+        }
+    }
+}
+
+impl Drop for ApiRateLimiter {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
+/// 時間帯別の帯域制限
+///
+/// start > end の場合は日付をまたぐ時間帯（例: 22:00〜06:00）として扱う。
+/// start == end の時間帯はどの時刻にも一致しない。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BandwidthWindow {
+    /// 開始時刻（この時刻を含む）
+    pub start: NaiveTime,
+    /// 終了時刻（この時刻を含まない）
+    pub end: NaiveTime,
+    /// 時間帯内の上限（bytes/sec、None は無制限）
+    pub max_bytes_per_sec: Option<u64>,
+}
+
+impl BandwidthWindow {
+    /// 指定時刻が時間帯に含まれるか
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        if self.start <= self.end {
+            self.start <= time && time < self.end
+        } else {
+            time >= self.start || time < self.end
+        }
+    }
+}
+
+/// 帯域スケジュール
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BandwidthSchedule {
+    /// 時間帯外の上限（bytes/sec、None は無制限）
+    pub base_limit: Option<u64>,
+    /// 時間帯別の上限（先に一致したものを優先）
+    pub windows: Vec<BandwidthWindow>,
+}
+
+impl BandwidthSchedule {
+    /// 指定時刻に適用される上限を取得
+    pub fn limit_at(&self, time: NaiveTime) -> Option<u64> {
+        self.windows
+            .iter()
+            .find(|window| window.contains(time))
+            .map_or(self.base_limit, |window| window.max_bytes_per_sec)
+    }
+}
+
+#[derive(Debug, Default)]
+struct BandwidthLimiterState {
+    /// 設定由来のスケジュール
+    schedule: BandwidthSchedule,
+    /// 実行中に変更された上限（Some の間はスケジュールより優先）
+    override_limit: Option<Option<u64>>,
+    /// 現在の上限に対応するバケット
+    bucket: Option<(u64, TokenBucket)>,
+}
+
+impl BandwidthLimiterState {
+    fn current_limit(&self) -> Option<u64> {
+        self.override_limit
+            .unwrap_or_else(|| self.schedule.limit_at(Local::now().time()))
+    }
+}
+
+/// 全ワーカーで共有する帯域制限
+///
+/// クローンしたハンドルは同じバケットを共有する。
+#[derive(Debug, Clone, Default)]
+pub struct BandwidthLimiter {
+    inner: Arc<Mutex<BandwidthLimiterState>>,
+}
+
+impl BandwidthLimiter {
+    /// スケジュールを指定して作成
+    pub fn new(schedule: BandwidthSchedule) -> Self {
+        let limiter = Self::default();
+        limiter.set_schedule(schedule);
+        limiter
+    }
+
+    /// スケジュールを置き換える（実行中の上限変更は維持される）
+    pub fn set_schedule(&self, schedule: BandwidthSchedule) {
+        if let Ok(mut state) = self.inner.lock() {
+            state.schedule = schedule;
+        }
+    }
+
+    /// 実行中に上限を変更する
+    ///
+    /// # 事後条件
+    /// - 以降はスケジュールに関わらず指定した上限が適用される（None は無制限）
+    pub fn set_limit(&self, max_bytes_per_sec: Option<u64>) {
+        if let Ok(mut state) = self.inner.lock() {
+            state.override_limit = Some(max_bytes_per_sec);
+        }
+    }
+
+    /// 実行中の上限変更を解除し、スケジュールに戻す
+    pub fn clear_limit_override(&self) {
+        if let Ok(mut state) = self.inner.lock() {
+            state.override_limit = None;
+        }
+    }
+
+    /// 現在適用されている上限（bytes/sec、None は無制限）
+    pub fn current_limit(&self) -> Option<u64> {
+        self.inner
+            .lock()
+            .map(|state| state.current_limit())
+            .unwrap_or(None)
+    }
+
+    /// 指定バイト数の転送枠を取得するまで待機する
+    ///
+    /// # 事後条件
+    /// - 上限が無制限の場合は直ちに戻る
+    /// - 待機中に上限が変更された場合は新しい上限で待機を続ける
+    pub async fn acquire(&self, bytes: u64) {
+        let mut remaining = bytes;
+
+        while remaining > 0 {
+            let wait = {
+                let Ok(mut state) = self.inner.lock() else {
+                    return;
+                };
+                let Some(limit) = state.current_limit() else {
+                    state.bucket = None;
+                    return;
+                };
+                let limit = limit.max(1);
+
+                // 容量は1秒分（上限が変わった場合は補充速度を更新する）
+                match &mut state.bucket {
+                    Some((rate, bucket)) if *rate != limit => {
+                        bucket.set_rate(limit as f64, limit as f64);
+                        *rate = limit;
+                    }
+                    Some(_) => {}
+                    None => {
+                        state.bucket =
+                            Some((limit, TokenBucket::with_rate(limit as f64, limit as f64)));
+                    }
+                }
+                let Some((_, bucket)) = state.bucket.as_mut() else {
+                    return;
+                };
+
+                let amount = remaining.min(limit);
+                if bucket.try_consume(amount as f64) {
+                    remaining -= amount;
+                    continue;
+                }
+                bucket.time_until_available(amount as f64)
+            };
+
+            tokio::time::sleep(wait.min(MAX_THROTTLE_WAIT)).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn test_token_bucket_consumes_arbitrary_amounts() {
+        let mut bucket = TokenBucket::with_rate(1000.0, 1000.0);
+
+        assert!(bucket.try_consume(600.0));
+        assert!(!bucket.try_consume(600.0));
+        assert!(bucket.time_until_available(600.0) > Duration::ZERO);
+
+        bucket.set_rate(100.0, 100.0);
+        assert!(bucket.tokens <= 100.0);
+    }
+
+    fn utc(day: u32, hour: u32) -> DateTime<Utc> {
+        NaiveDate::from_ymd_opt(2025, 1, day)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap()
+            .and_utc()
+    }
+
+    #[test]
+    fn test_categories_are_limited_independently() {
+        let limits = CategoryLimits {
+            medium: CategoryLimit {
+                requests_per_second: 1.0,
+                burst_size: 1,
+            },
+            ..CategoryLimits::default()
+        };
+        let mut limiter = ApiRateLimiter::new(&limits);
+        let now = Utc::now();
+
+        assert_eq!(
+            limiter.try_acquire(RateLimitCategory::Medium, now),
+            RateLimitDecision::Granted
+        );
+        assert!(matches!(
+            limiter.try_acquire(RateLimitCategory::Medium, now),
+            RateLimitDecision::Wait(_)
+        ));
+        // Medium が尽きても Light は影響を受けない
+        assert_eq!(
+            limiter.try_acquire(RateLimitCategory::Light, now),
+            RateLimitDecision::Granted
+        );
+
+        // サーバーが秒間残数 0 を返したカテゴリは待機させる
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert("X-RateLimit-Category", "Light".parse().unwrap());
+        headers.insert("X-RateLimit-Type", "QPS".parse().unwrap());
+        headers.insert("X-RateLimit-Remaining", "0".parse().unwrap());
+        let headers = RateLimitHeaders::parse(&headers);
+        assert_eq!(headers.category, Some(RateLimitCategory::Light));
+        assert_eq!(headers.limit_type, Some(RateLimitType::PerSecond));
+        limiter.observe(RateLimitCategory::Medium, &headers, now);
+        assert!(matches!(
+            limiter.try_acquire(RateLimitCategory::Light, now),
+            RateLimitDecision::Wait(_)
+        ));
+    }
+
+    #[test]
+    fn test_daily_quota_is_learned_from_headers_and_persisted_for_the_day() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let store = temp_dir.path().join(QUOTA_FILE_NAME);
+        let limits = CategoryLimits::default();
+
+        let mut limiter = ApiRateLimiter::new(&limits).with_store(&store);
+        let morning = utc(10, 9);
+
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert("X-RateLimit-Category", "Heavy".parse().unwrap());
+        headers.insert("X-RateLimit-Type", "Daily-limit".parse().unwrap());
+        headers.insert("X-RateLimit-Limit", "100".parse().unwrap());
+        headers.insert("X-RateLimit-Remaining", "2".parse().unwrap());
+        limiter.observe(
+            RateLimitCategory::Heavy,
+            &RateLimitHeaders::parse(&headers),
+            morning,
+        );
+        assert_eq!(limiter.daily_usage().remaining(), 2);
+        assert_eq!(
+            limiter.try_acquire(RateLimitCategory::Heavy, morning),
+            RateLimitDecision::Granted
+        );
+        // 保存間隔内の消費はまとめて書き込む
+        assert_eq!(ApiRateLimiter::load(&store).unwrap().used, 98);
+        drop(limiter);
+        assert_eq!(ApiRateLimiter::load(&store).unwrap().used, 99);
+
+        // 同じ日の次の実行は保存された消費状況から始まる
+        let mut next_run = ApiRateLimiter::new(&limits).with_store(&store);
+        assert_eq!(next_run.daily_usage().used, 99);
+        assert_eq!(
+            next_run.try_acquire(RateLimitCategory::Heavy, morning),
+            RateLimitDecision::Granted
+        );
+        // 上限に達した消費は間隔を待たずに保存される
+        assert_eq!(ApiRateLimiter::load(&store).unwrap().used, 100);
+        assert_eq!(
+            next_run.try_acquire(RateLimitCategory::Heavy, morning),
+            RateLimitDecision::QuotaExhausted {
+                resets_in: Duration::from_secs(15 * 3600)
+            }
+        );
+        // 1日あたりの上限は Light には適用されない
+        assert_eq!(
+            next_run.try_acquire(RateLimitCategory::Light, morning),
+            RateLimitDecision::Granted
+        );
+
+        // UTC 0時を過ぎるとリセットされる
+        let next_day = morning + chrono::Duration::days(1);
+        assert_eq!(
+            next_run.try_acquire(RateLimitCategory::Heavy, next_day),
+            RateLimitDecision::Granted
+        );
+        assert_eq!(next_run.daily_usage().used, 1);
+    }
+
+    #[test]
+    fn test_schedule_limit_at_uses_windows_and_base_limit() {
+        let schedule = BandwidthSchedule {
+            base_limit: None,
+            windows: vec![
+                BandwidthWindow {
+                    start: time(9, 0),
+                    end: time(18, 0),
+                    max_bytes_per_sec: Some(2_000_000),
+                },
+                BandwidthWindow {
+                    start: time(22, 0),
+                    end: time(6, 0),
+                    max_bytes_per_sec: Some(10_000_000),
+                },
+            ],
+        };
+
+        assert_eq!(schedule.limit_at(time(9, 0)), Some(2_000_000));
+        assert_eq!(schedule.limit_at(time(17, 59)), Some(2_000_000));
+        assert_eq!(schedule.limit_at(time(18, 0)), None);
+        assert_eq!(schedule.limit_at(time(23, 30)), Some(10_000_000));
+        assert_eq!(schedule.limit_at(time(5, 0)), Some(10_000_000));
+        assert_eq!(schedule.limit_at(time(7, 0)), None);
+    }
+
+    #[tokio::test]
+    async fn test_limiter_throttles_and_accepts_live_changes() {
+        let limiter = BandwidthLimiter::new(BandwidthSchedule {
+            base_limit: Some(10_000),
+            windows: Vec::new(),
+        });
+        assert_eq!(limiter.current_limit(), Some(10_000));
+
+        // 初回は1秒分のバーストを即座に消費し、次の5000バイトで約0.5秒待機する
+        let start = Instant::now();
+        limiter.acquire(10_000).await;
+        limiter.acquire(5_000).await;
+        assert!(start.elapsed() >= Duration::from_millis(400));
+
+        // 実行中に無制限へ変更すると待機しない
+        limiter.set_limit(None);
+        assert_eq!(limiter.current_limit(), None);
+        let start = Instant::now();
+        limiter.acquire(1_000_000).await;
+        assert!(start.elapsed() < Duration::from_millis(100));
+
+        limiter.clear_limit_override();
+        assert_eq!(limiter.current_limit(), Some(10_000));
+    }
+}
@@ -0,0 +1,599 @@
+//! 実行レポートコンポーネント
+//!
+//! # 責任
+//! - ダウンロードバッチごとに、ファイル単位の結果（状態・サイズ・所要時間・リトライ数・エラー・スキップ理由）を集計する
+//! - 集計結果の JSON / CSV 形式での書き出し
+
+use crate::components::api::{MeetingRecording, RecordingFile};
+use crate::components::download::DownloadEvent;
+use crate::components::event_bus::EventEnvelope;
+use crate::errors::{AppError, AppResult};
+use chrono::{DateTime, Local, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// 実行レポートの保存先ディレクトリ名（出力ディレクトリ直下）
+pub const REPORT_DIR_NAME: &str = "reports";
+
+/// ファイルの最終状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FinalState {
+    Completed,
+    Skipped,
+    Failed,
+    Cancelled,
+}
+
+impl std::fmt::Display for FinalState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Completed => write!(f, "completed"),
+            Self::Skipped => write!(f, "skipped"),
+            Self::Failed => write!(f, "failed"),
+            Self::Cancelled => write!(f, "cancelled"),
+        }
+    }
+}
+
+/// ダウンロードしなかった理由
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SkipReason {
+    /// マニフェストで取得済みと確認できた
+    AlreadyDownloaded,
+    /// 出力先に同名ファイルが存在した
+    FileExists,
+    /// API が download_url を返さなかった
+    EmptyDownloadUrl,
+    /// Meeting Summary API に AI 要約がなかった
+    NoAiSummary,
+}
+
+impl std::fmt::Display for SkipReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AlreadyDownloaded => write!(f, "already_downloaded"),
+            Self::FileExists => write!(f, "file_exists"),
+            Self::EmptyDownloadUrl => write!(f, "empty_download_url"),
+            Self::NoAiSummary => write!(f, "no_ai_summary"),
+        }
+    }
+}
+
+/// レポート対象のファイル
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReportFile {
+    pub meeting_uuid: String,
+    pub meeting_topic: String,
+    pub meeting_start_time: String,
+    /// ファイル種別（MP4, TRANSCRIPT など。取得元の記録がない再開タスクは空）
+    pub file_type: String,
+    /// 出力ディレクトリからの相対パス
+    pub file_name: String,
+}
+
+impl ReportFile {
+    pub fn new(meeting: &MeetingRecording, file: &RecordingFile, file_name: &str) -> Self {
+        Self {
+            meeting_uuid: meeting.uuid.clone(),
+            meeting_topic: meeting.topic.clone(),
+            meeting_start_time: meeting.start_time.clone(),
+            file_type: file.file_type.to_string(),
+            file_name: file_name.to_string(),
+        }
+    }
+
+    /// 会議情報が残っていないファイル（取得元の記録がない再開タスクなど）
+    pub fn unattributed(file_name: &str) -> Self {
+        Self {
+            file_name: file_name.to_string(),
+            ..Self::default()
+        }
+    }
+}
+
+/// ファイル単位の結果
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunReportEntry {
+    #[serde(flatten)]
+    pub file: ReportFile,
+    /// 保存したバイト数（失敗・キャンセル時は取得済みのバイト数）
+    pub bytes: Option<u64>,
+    /// 最初の試行開始から最終状態までの秒数
+    pub duration_secs: Option<f64>,
+    /// リトライ回数
+    pub retries: u32,
+    pub state: FinalState,
+    pub error: Option<String>,
+    pub skip_reason: Option<SkipReason>,
+}
+
+impl RunReportEntry {
+    /// 再試行で取得できる可能性があるか
+    ///
+    /// 失敗したファイルと、URL 未発行（録画処理中など）でスキップしたファイルが該当する。
+    /// AI要約の取得不可（403/404）はアカウント側の状態によるため対象外とする。
+    pub fn is_retryable(&self) -> bool {
+        match self.state {
+            FinalState::Failed => true,
+            FinalState::Skipped => self.skip_reason == Some(SkipReason::EmptyDownloadUrl),
+            FinalState::Completed | FinalState::Cancelled => false,
+        }
+    }
+}
+
+/// 書き出し形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Json,
+    Csv,
+}
+
+impl ReportFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Csv => "csv",
+        }
+    }
+}
+
+/// CSV の列（RunReportEntry のフィールド順）
+const CSV_HEADER: [&str; 11] = [
+    "meeting_uuid",
+    "meeting_topic",
+    "meeting_start_time",
+    "file_type",
+    "file_name",
+    "bytes",
+    "duration_secs",
+    "retries",
+    "state",
+    "error",
+    "skip_reason",
+];
+
+/// ダウンロードバッチ1回分の実行レポート
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunReport {
+    pub started_at: DateTime<Utc>,
+    pub finished_at: DateTime<Utc>,
+    /// ユーザーによりキャンセルされた
+    pub cancelled: bool,
+    pub entries: Vec<RunReportEntry>,
+}
+
+impl RunReport {
+    /// 指定した最終状態のファイル数
+    pub fn count(&self, state: FinalState) -> usize {
+        self.entries
+            .iter()
+            .filter(|entry| entry.state == state)
+            .count()
+    }
+
+    /// 件数の要約（例: "3 completed, 1 skipped, 0 failed, 0 cancelled"）
+    pub fn summary(&self) -> String {
+        format!(
+            "{} completed, {} skipped, {} failed, {} cancelled",
+            self.count(FinalState::Completed),
+            self.count(FinalState::Skipped),
+            self.count(FinalState::Failed),
+            self.count(FinalState::Cancelled)
+        )
+    }
+
+    pub fn to_json(&self) -> AppResult<String> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| AppError::data_integrity(format!("Failed to serialize run report: {}", e)))
+    }
+
+    /// CSV に変換する（1行目はヘッダー、1ファイル1行）
+    pub fn to_csv(&self) -> String {
+        let mut csv = CSV_HEADER.join(",");
+        csv.push_str("\r\n");
+        for entry in &self.entries {
+            let fields = [
+                entry.file.meeting_uuid.clone(),
+                entry.file.meeting_topic.clone(),
+                entry.file.meeting_start_time.clone(),
+                entry.file.file_type.clone(),
+                entry.file.file_name.clone(),
+                entry.bytes.map(|b| b.to_string()).unwrap_or_default(),
+                entry
+                    .duration_secs
+                    .map(|secs| format!("{:.3}", secs))
+                    .unwrap_or_default(),
+                entry.retries.to_string(),
+                entry.state.to_string(),
+                entry.error.clone().unwrap_or_default(),
+                entry
+                    .skip_reason
+                    .map(|reason| reason.to_string())
+                    .unwrap_or_default(),
+            ];
+            let row: Vec<String> = fields.iter().map(|field| csv_field(field)).collect();
+            csv.push_str(&row.join(","));
+            csv.push_str("\r\n");
+        }
+        csv
+    }
+
+    /// 出力ディレクトリの reports フォルダへ書き出す
+    ///
+    /// # 副作用
+    /// - `<output_dir>/reports/run_report_<開始日時>.<拡張子>` の作成（同名ファイルは上書き）
+    ///
+    /// # 事後条件
+    /// - 成功時は書き出したファイルパスが返される
+    pub fn export(&self, output_dir: impl AsRef<Path>, format: ReportFormat) -> AppResult<PathBuf> {
+        let directory = output_dir.as_ref().join(REPORT_DIR_NAME);
+        std::fs::create_dir_all(&directory)
+            .map_err(|e| AppError::file_system("Failed to create run report directory", Some(e)))?;
+
+        let content = match format {
+            ReportFormat::Json => self.to_json()?,
+            ReportFormat::Csv => self.to_csv(),
+        };
+        let path = directory.join(format!(
+            "run_report_{}.{}",
+            self.started_at
+                .with_timezone(&Local)
+                .format("%Y%m%d_%H%M%S"),
+            format.extension()
+        ));
+        std::fs::write(&path, content)
+            .map_err(|e| AppError::file_system("Failed to write run report", Some(e)))?;
+        Ok(path)
+    }
+}
+
+/// RFC 4180 に従って CSV のフィールドを引用する
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// 実行中タスクの観測状態
+#[derive(Debug)]
+struct TaskTimeline {
+    file: ReportFile,
+    first_started_at: Option<DateTime<Utc>>,
+    /// 試行回数（TaskStarted の受信数）
+    attempts: u32,
+    /// 最後に報告された取得済みバイト数
+    bytes: Option<u64>,
+}
+
+impl TaskTimeline {
+    /// 最終状態を受けてレポートの行に変換する
+    fn into_entry(
+        self,
+        finished_at: DateTime<Utc>,
+        state: FinalState,
+        bytes: Option<u64>,
+        error: Option<String>,
+        skip_reason: Option<SkipReason>,
+    ) -> RunReportEntry {
+        RunReportEntry {
+            file: self.file,
+            bytes: bytes.or(self.bytes),
+            duration_secs: self
+                .first_started_at
+                .map(|started| (finished_at - started).num_milliseconds().max(0) as f64 / 1000.0),
+            retries: self.attempts.saturating_sub(1),
+            state,
+            error,
+            skip_reason,
+        }
+    }
+}
+
+/// ダウンロードイベントから実行レポートを組み立てる
+///
+/// 所要時間・リトライ数は、イベントに付与された発行時刻と TaskStarted の回数から求める。
+#[derive(Debug)]
+pub struct RunRecorder {
+    started_at: DateTime<Utc>,
+    tasks: HashMap<String, TaskTimeline>,
+    entries: Vec<RunReportEntry>,
+}
+
+impl Default for RunRecorder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RunRecorder {
+    pub fn new() -> Self {
+        Self {
+            started_at: Utc::now(),
+            tasks: HashMap::new(),
+            entries: Vec::new(),
+        }
+    }
+
+    /// ダウンロードタスクの結果をイベントから集計する対象に加える
+    pub fn track(&mut self, task_id: impl Into<String>, file: ReportFile) {
+        self.tasks.insert(
+            task_id.into(),
+            TaskTimeline {
+                file,
+                first_started_at: None,
+                attempts: 0,
+                bytes: None,
+            },
+        );
+    }
+
+    /// ダウンロードしなかったファイルを記録する
+    pub fn skip(&mut self, file: ReportFile, reason: SkipReason) {
+        self.entries.push(RunReportEntry {
+            file,
+            bytes: None,
+            duration_secs: None,
+            retries: 0,
+            state: FinalState::Skipped,
+            error: None,
+            skip_reason: Some(reason),
+        });
+    }
+
+    /// ダウンロードタスク以外で取得したファイル（AI 要約など）の結果を記録する
+    pub fn record(
+        &mut self,
+        file: ReportFile,
+        state: FinalState,
+        bytes: Option<u64>,
+        error: Option<String>,
+    ) {
+        self.entries.push(RunReportEntry {
+            file,
+            bytes,
+            duration_secs: None,
+            retries: 0,
+            state,
+            error,
+            skip_reason: None,
+        });
+    }
+
+    /// ダウンロードイベントを反映する
+    ///
+    /// # 事後条件
+    /// - track していないタスク・確定済みのタスクのイベントは無視される
+    /// - 最終状態のイベントを受けたタスクはレポートの行として確定する
+    pub fn observe(&mut self, envelope: &EventEnvelope<DownloadEvent>) {
+        let at = envelope.timestamp;
+        match &envelope.event {
+            DownloadEvent::TaskStarted { task_id } => {
+                if let Some(timeline) = self.tasks.get_mut(task_id) {
+                    timeline.attempts += 1;
+                    timeline.first_started_at.get_or_insert(at);
+                }
+            }
+            DownloadEvent::ProgressUpdate { task_id, progress } => {
+                if let Some(timeline) = self.tasks.get_mut(task_id) {
+                    timeline.bytes = Some(progress.downloaded_bytes);
+                }
+            }
+            DownloadEvent::TaskCompleted {
+                task_id,
+                output_path,
+                ..
+            } => {
+                let bytes = std::fs::metadata(output_path).map(|m| m.len()).ok();
+                self.finish_task(task_id, at, FinalState::Completed, bytes, None, None);
+            }
+            DownloadEvent::TaskSkipped {
+                task_id,
+                output_path,
+            } => {
+                let bytes = std::fs::metadata(output_path).map(|m| m.len()).ok();
+                let reason = Some(SkipReason::FileExists);
+                self.finish_task(task_id, at, FinalState::Skipped, bytes, None, reason);
+            }
+            DownloadEvent::TaskFailed { task_id, error, .. } => {
+                let error = Some(error.clone());
+                self.finish_task(task_id, at, FinalState::Failed, None, error, None);
+            }
+            DownloadEvent::TaskCancelled { task_id } => {
+                self.finish_task(task_id, at, FinalState::Cancelled, None, None, None);
+            }
+            DownloadEvent::Cancelled { pending_tasks } => {
+                for task_id in pending_tasks {
+                    self.finish_task(task_id, at, FinalState::Cancelled, None, None, None);
+                }
+            }
+            DownloadEvent::OverallProgressUpdate(_)
+            | DownloadEvent::LowDiskSpace { .. }
+            | DownloadEvent::ConcurrencyChanged { .. }
+            | DownloadEvent::Paused
+            | DownloadEvent::Resumed => {}
+        }
+    }
+
+    fn finish_task(
+        &mut self,
+        task_id: &str,
+        at: DateTime<Utc>,
+        state: FinalState,
+        bytes: Option<u64>,
+        error: Option<String>,
+        skip_reason: Option<SkipReason>,
+    ) {
+        if let Some(timeline) = self.tasks.remove(task_id) {
+            self.entries
+                .push(timeline.into_entry(at, state, bytes, error, skip_reason));
+        }
+    }
+
+    /// 実行レポートを確定する
+    ///
+    /// # 事後条件
+    /// - 最終状態を受信できなかったタスクは、キャンセル時は Cancelled、それ以外は Failed として記録される
+    pub fn finish(mut self, cancelled: bool) -> RunReport {
+        let finished_at = Utc::now();
+        let (state, error) = if cancelled {
+            (FinalState::Cancelled, None)
+        } else {
+            (
+                FinalState::Failed,
+                Some("Final state was not reported".to_string()),
+            )
+        };
+        let mut unfinished: Vec<TaskTimeline> = self.tasks.into_values().collect();
+        unfinished.sort_by(|a, b| a.file.file_name.cmp(&b.file.file_name));
+        self.entries.extend(
+            unfinished
+                .into_iter()
+                .map(|timeline| timeline.into_entry(finished_at, state, None, error.clone(), None)),
+        );
+
+        RunReport {
+            started_at: self.started_at,
+            finished_at,
+            cancelled,
+            entries: self.entries,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::download::{DownloadProgress, FailureReason};
+    use chrono::Duration;
+
+    fn envelope(sequence: u64, seconds: i64, event: DownloadEvent) -> EventEnvelope<DownloadEvent> {
+        EventEnvelope {
+            sequence,
+            timestamp: DateTime::<Utc>::UNIX_EPOCH + Duration::seconds(seconds),
+            event,
+        }
+    }
+
+    fn started(task_id: &str) -> DownloadEvent {
+        DownloadEvent::TaskStarted {
+            task_id: task_id.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_recorder_derives_duration_retries_and_state_from_events() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let output_path = temp_dir.path().join("video.mp4");
+        std::fs::write(&output_path, b"hello").unwrap();
+
+        let mut recorder = RunRecorder::new();
+        recorder.track("video", ReportFile::unattributed("video.mp4"));
+        recorder.track("chat", ReportFile::unattributed("chat.txt"));
+        recorder.track("audio", ReportFile::unattributed("audio.m4a"));
+        recorder.skip(
+            ReportFile::unattributed("summary.json"),
+            SkipReason::NoAiSummary,
+        );
+
+        recorder.observe(&envelope(1, 10, started("video")));
+        recorder.observe(&envelope(2, 11, started("chat")));
+        recorder.observe(&envelope(
+            3,
+            12,
+            DownloadEvent::ProgressUpdate {
+                task_id: "chat".to_string(),
+                progress: DownloadProgress {
+                    downloaded_bytes: 3,
+                    ..DownloadProgress::default()
+                },
+            },
+        ));
+        // 1回目の失敗後に再試行
+        recorder.observe(&envelope(4, 13, started("video")));
+        recorder.observe(&envelope(
+            5,
+            14,
+            DownloadEvent::TaskFailed {
+                task_id: "chat".to_string(),
+                error: "HTTP 403".to_string(),
+                reason: FailureReason::Error,
+            },
+        ));
+        recorder.observe(&envelope(
+            6,
+            15,
+            DownloadEvent::TaskCompleted {
+                task_id: "video".to_string(),
+                output_path,
+                sha256: None,
+            },
+        ));
+        let report = recorder.finish(true);
+
+        let states: Vec<_> = report
+            .entries
+            .iter()
+            .map(|entry| (entry.file.file_name.as_str(), entry.state))
+            .collect();
+        assert_eq!(
+            states,
+            vec![
+                ("summary.json", FinalState::Skipped),
+                ("chat.txt", FinalState::Failed),
+                ("video.mp4", FinalState::Completed),
+                ("audio.m4a", FinalState::Cancelled),
+            ]
+        );
+        let chat = &report.entries[1];
+        assert_eq!(
+            (chat.bytes, chat.error.as_deref()),
+            (Some(3), Some("HTTP 403"))
+        );
+        let video = &report.entries[2];
+        assert_eq!(
+            (video.bytes, video.duration_secs, video.retries),
+            (Some(5), Some(5.0), 1)
+        );
+        assert_eq!(
+            report.summary(),
+            "1 completed, 1 skipped, 1 failed, 1 cancelled"
+        );
+    }
+
+    #[test]
+    fn test_export_writes_json_and_quoted_csv() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut recorder = RunRecorder::new();
+        recorder.record(
+            ReportFile {
+                meeting_topic: "Weekly \"sync\", all hands".to_string(),
+                file_type: "SUMMARY".to_string(),
+                ..ReportFile::unattributed("summary.json")
+            },
+            FinalState::Failed,
+            None,
+            Some("line 1\nline 2".to_string()),
+        );
+        let report = recorder.finish(false);
+
+        let csv_path = report.export(temp_dir.path(), ReportFormat::Csv).unwrap();
+        assert_eq!(csv_path.parent().unwrap(), temp_dir.path().join("reports"));
+        let csv = std::fs::read_to_string(&csv_path).unwrap();
+        let mut lines = csv.split("\r\n");
+        assert_eq!(lines.next().unwrap(), CSV_HEADER.join(","));
+        assert_eq!(
+            lines.next().unwrap(),
+            ",\"Weekly \"\"sync\"\", all hands\",,SUMMARY,summary.json,,,0,failed,\"line 1\nline 2\","
+        );
+
+        let json_path = report.export(temp_dir.path(), ReportFormat::Json).unwrap();
+        let restored: RunReport =
+            serde_json::from_str(&std::fs::read_to_string(json_path).unwrap()).unwrap();
+        assert_eq!(restored, report);
+    }
+}
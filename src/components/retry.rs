@@ -0,0 +1,223 @@
+//! リトライポリシーコンポーネント
+//!
+//! # 責任
+//! - エラー種別に応じたリトライ可否の判定
+//! - 指数バックオフ（ジッター付き）による待機時間の算出
+//! - API呼び出しとダウンロードで共通のリトライ判定
+
+use crate::errors::AppError;
+use chrono::{DateTime, Utc};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+/// リトライ判定結果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetryDecision {
+    /// 指定時間待機してから再試行する
+    Retry(Duration),
+    /// 再試行せずに失敗とする
+    GiveUp,
+}
+
+/// リトライポリシー
+///
+/// 実装は API 呼び出し・ダウンロードワーカーの双方から共有される。
+pub trait RetryPolicy: std::fmt::Debug + Send + Sync {
+    /// 失敗後に再試行するかを判定する
+    ///
+    /// # 事前条件
+    /// - attempt は失敗した試行の通し番号（1 始まり）
+    /// - elapsed は最初の試行開始からの経過時間
+    fn decide(&self, error: &AppError, attempt: u32, elapsed: Duration) -> RetryDecision;
+}
+
+/// 試行履歴（失敗した試行ごとの記録）
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryAttempt {
+    /// 試行の通し番号（1 始まり）
+    pub attempt: u32,
+    /// 開始日時
+    pub started_at: DateTime<Utc>,
+    /// 失敗日時
+    pub failed_at: DateTime<Utc>,
+    /// エラー内容
+    pub error: String,
+    /// 判定結果
+    pub decision: RetryDecision,
+}
+
+/// ジッター付き指数バックオフ
+///
+/// # 判定規則
+/// - `AppError::is_recoverable()` が false のエラーは再試行しない（401/404・ディスク容量不足など）
+/// - 試行回数が max_attempts に達した場合は再試行しない
+/// - 待機時間は initial_delay × multiplier^(attempt-1) を max_delay で打ち切り、±jitter の割合で揺らす
+/// - `AppError::retry_after()` がある場合はその秒数を待機時間の下限とする
+/// - 待機後に max_elapsed を超える場合は再試行しない
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExponentialBackoff {
+    /// 最大試行回数（初回を含む）
+    pub max_attempts: u32,
+    /// 初回リトライまでの待機時間
+    pub initial_delay: Duration,
+    /// 待機時間の上限
+    pub max_delay: Duration,
+    /// 待機時間の増加率
+    pub multiplier: f64,
+    /// 待機時間を揺らす割合（0.0〜1.0）
+    pub jitter: f64,
+    /// 最初の試行からの経過時間の上限（None は無制限）
+    pub max_elapsed: Option<Duration>,
+}
+
+impl Default for ExponentialBackoff {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(60),
+            multiplier: 2.0,
+            jitter: 0.2,
+            max_elapsed: Some(Duration::from_secs(15 * 60)),
+        }
+    }
+}
+
+impl ExponentialBackoff {
+    /// 最大試行回数を指定して作成（その他は既定値）
+    pub fn with_max_attempts(max_attempts: u32) -> Self {
+        Self {
+            max_attempts,
+            ..Self::default()
+        }
+    }
+
+    /// ジッターを含まない待機時間
+    fn base_delay(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(32) as i32;
+        let delay = self.initial_delay.as_secs_f64() * self.multiplier.max(1.0).powi(exponent);
+        Duration::from_secs_f64(delay.min(self.max_delay.as_secs_f64()))
+    }
+}
+
+impl RetryPolicy for ExponentialBackoff {
+    fn decide(&self, error: &AppError, attempt: u32, elapsed: Duration) -> RetryDecision {
+        if !error.is_recoverable() || attempt >= self.max_attempts {
+            return RetryDecision::GiveUp;
+        }
+
+        let jitter = self.jitter.clamp(0.0, 1.0);
+        let factor = 1.0 + jitter * (2.0 * random_unit() - 1.0);
+        let mut delay = self.base_delay(attempt).mul_f64(factor);
+        if let Some(retry_after) = error.retry_after() {
+            delay = delay.max(Duration::from_secs(retry_after));
+        }
+
+        match self.max_elapsed {
+            Some(max_elapsed) if elapsed + delay > max_elapsed => RetryDecision::GiveUp,
+            _ => RetryDecision::Retry(delay),
+        }
+    }
+}
+
+/// 0.0〜1.0 の疑似乱数（ジッター用、暗号用途には使わない）
+fn random_unit() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or_default(),
+    );
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn no_jitter() -> ExponentialBackoff {
+        ExponentialBackoff {
+            max_attempts: 5,
+            initial_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(500),
+            multiplier: 2.0,
+            jitter: 0.0,
+            max_elapsed: None,
+        }
+    }
+
+    #[test]
+    fn test_non_recoverable_errors_are_not_retried() {
+        let policy = no_jitter();
+        let errors = [
+            AppError::authentication("401", None::<std::io::Error>),
+            AppError::not_found("recording"),
+            AppError::file_system("No space left on device", None::<std::io::Error>),
+            AppError::api(403, "Forbidden", None::<std::io::Error>),
+        ];
+        for error in &errors {
+            assert_eq!(
+                policy.decide(error, 1, Duration::ZERO),
+                RetryDecision::GiveUp
+            );
+        }
+    }
+
+    #[test]
+    fn test_backoff_grows_and_respects_limits() {
+        let policy = ExponentialBackoff {
+            max_attempts: 10,
+            ..no_jitter()
+        };
+        let error = AppError::api(500, "Internal Server Error", None::<std::io::Error>);
+        let rate_limited = AppError::rate_limit("Too many requests");
+
+        // 500 は retry_after() の 10 秒が下限になる
+        assert_eq!(
+            policy.decide(&error, 1, Duration::ZERO),
+            RetryDecision::Retry(Duration::from_secs(10))
+        );
+        assert_eq!(
+            policy.decide(&rate_limited, 1, Duration::ZERO),
+            RetryDecision::Retry(Duration::from_millis(100))
+        );
+        assert_eq!(
+            policy.decide(&rate_limited, 3, Duration::ZERO),
+            RetryDecision::Retry(Duration::from_millis(400))
+        );
+        assert_eq!(
+            policy.decide(&rate_limited, 6, Duration::ZERO),
+            RetryDecision::Retry(Duration::from_millis(500))
+        );
+        assert_eq!(
+            policy.decide(&rate_limited, 10, Duration::ZERO),
+            RetryDecision::GiveUp
+        );
+
+        let limited = ExponentialBackoff {
+            max_elapsed: Some(Duration::from_secs(1)),
+            ..policy
+        };
+        assert_eq!(
+            limited.decide(&rate_limited, 1, Duration::from_millis(950)),
+            RetryDecision::GiveUp
+        );
+    }
+
+    #[test]
+    fn test_jitter_stays_within_bounds() {
+        let policy = ExponentialBackoff {
+            jitter: 0.5,
+            ..no_jitter()
+        };
+        let error = AppError::rate_limit("Too many requests");
+        for _ in 0..50 {
+            let RetryDecision::Retry(delay) = policy.decide(&error, 1, Duration::ZERO) else {
+                panic!("expected retry");
+            };
+            assert!(delay >= Duration::from_millis(50) && delay <= Duration::from_millis(150));
+        }
+    }
+}
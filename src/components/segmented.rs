@@ -0,0 +1,232 @@
+//! 分割ダウンロードコンポーネント
+//!
+//! # 責任
+//! - 大きなファイルを複数のバイト範囲（セグメント）に分割する計画
+//! - セグメントごとの取得済みバイト数の永続化（.part と並べて保存）
+//! - 全セグメント取得後の整合性検証
+
+use crate::errors::{AppError, AppResult};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// 分割ダウンロードの設定
+#[derive(Debug, Clone, PartialEq)]
+pub struct SegmentedDownload {
+    /// 分割数
+    pub segments: usize,
+    /// 分割を行う最小ファイルサイズ（バイト）
+    pub min_size_bytes: u64,
+}
+
+impl Default for SegmentedDownload {
+    fn default() -> Self {
+        Self {
+            segments: 4,
+            min_size_bytes: 256 * 1024 * 1024,
+        }
+    }
+}
+
+impl SegmentedDownload {
+    /// 指定サイズのファイルを分割するか
+    pub fn applies_to(&self, size: u64) -> bool {
+        self.segments > 1 && size >= self.min_size_bytes && size >= self.segments as u64
+    }
+}
+
+/// 1つのセグメント（バイト範囲 start..end）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct Segment {
+    /// 開始位置（含む）
+    pub start: u64,
+    /// 終了位置（含まない）
+    pub end: u64,
+    /// 取得済みバイト数
+    pub downloaded: u64,
+}
+
+impl Segment {
+    pub(crate) fn len(&self) -> u64 {
+        self.end - self.start
+    }
+
+    /// 次に取得する位置
+    pub(crate) fn offset(&self) -> u64 {
+        self.start + self.downloaded
+    }
+
+    pub(crate) fn is_complete(&self) -> bool {
+        self.downloaded >= self.len()
+    }
+}
+
+/// 分割ダウンロードの進行状態（.part.segments ファイルとして保存）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct SegmentState {
+    /// ファイル全体のサイズ
+    pub total_size: u64,
+    /// セグメント一覧（start 昇順）
+    pub segments: Vec<Segment>,
+}
+
+impl SegmentState {
+    /// total_size を count 個のほぼ等しい範囲に分割する
+    ///
+    /// # 事前条件
+    /// - count >= 1
+    ///
+    /// # 事後条件
+    /// - セグメントは隙間なく 0..total_size を覆う
+    pub(crate) fn plan(total_size: u64, count: usize) -> Self {
+        let count = (count.max(1) as u64).min(total_size.max(1));
+        let base = total_size / count;
+        let remainder = total_size % count;
+        let mut start = 0;
+        let segments = (0..count)
+            .map(|index| {
+                let len = base + u64::from(index < remainder);
+                let segment = Segment {
+                    start,
+                    end: start + len,
+                    downloaded: 0,
+                };
+                start += len;
+                segment
+            })
+            .collect();
+        Self {
+            total_size,
+            segments,
+        }
+    }
+
+    /// 状態ファイルのパス（例: "video.mp4.part.segments"）
+    pub(crate) fn state_path(part_path: &Path) -> PathBuf {
+        let mut path = part_path.as_os_str().to_owned();
+        path.push(".segments");
+        PathBuf::from(path)
+    }
+
+    /// 状態ファイルを読み込む（存在しない・壊れている場合は None）
+    pub(crate) fn load(part_path: &Path) -> Option<Self> {
+        let content = std::fs::read_to_string(Self::state_path(part_path)).ok()?;
+        match serde_json::from_str::<Self>(&content) {
+            Ok(state) if state.is_consistent() => Some(state),
+            _ => {
+                log::warn!(
+                    "Ignoring invalid segment state: {}",
+                    Self::state_path(part_path).display()
+                );
+                None
+            }
+        }
+    }
+
+    /// 状態ファイルを保存する（一時ファイル経由で置き換え）
+    pub(crate) fn save(&self, part_path: &Path) -> AppResult<()> {
+        let content = serde_json::to_string(self).map_err(|e| {
+            AppError::data_integrity(format!("Failed to serialize segment state: {}", e))
+        })?;
+        let path = Self::state_path(part_path);
+        let mut tmp_path = path.clone().into_os_string();
+        tmp_path.push(".tmp");
+        let tmp_path = PathBuf::from(tmp_path);
+
+        std::fs::write(&tmp_path, content)
+            .map_err(|e| AppError::file_system("Failed to write segment state", Some(e)))?;
+        std::fs::rename(&tmp_path, &path)
+            .map_err(|e| AppError::file_system("Failed to replace segment state", Some(e)))
+    }
+
+    /// 状態ファイルを削除する（存在しない場合は何もしない）
+    pub(crate) fn remove(part_path: &Path) {
+        let _ = std::fs::remove_file(Self::state_path(part_path));
+    }
+
+    /// 取得済みバイト数の合計
+    pub(crate) fn downloaded(&self) -> u64 {
+        self.segments.iter().map(|segment| segment.downloaded).sum()
+    }
+
+    /// 全セグメントが取得済みであることを検証する
+    ///
+    /// # 事後条件
+    /// - 取得済みバイト数・ファイルサイズが計画と一致しない場合は data_integrity エラー
+    pub(crate) fn verify(&self, file_size: u64) -> AppResult<()> {
+        if let Some(segment) = self
+            .segments
+            .iter()
+            .find(|segment| segment.downloaded != segment.len())
+        {
+            return Err(AppError::data_integrity(format!(
+                "Segment {}-{} incomplete: got {} of {} bytes",
+                segment.start,
+                segment.end,
+                segment.downloaded,
+                segment.len()
+            )));
+        }
+        if file_size != self.total_size {
+            return Err(AppError::data_integrity(format!(
+                "File size mismatch: expected {}, got {}",
+                self.total_size, file_size
+            )));
+        }
+        Ok(())
+    }
+
+    /// セグメントが 0..total_size を隙間なく覆っているか
+    fn is_consistent(&self) -> bool {
+        let mut expected_start = 0;
+        for segment in &self.segments {
+            if segment.start != expected_start
+                || segment.end < segment.start
+                || segment.downloaded > segment.len()
+            {
+                return false;
+            }
+            expected_start = segment.end;
+        }
+        !self.segments.is_empty() && expected_start == self.total_size
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plan_covers_whole_file() {
+        let state = SegmentState::plan(10, 3);
+        let ranges: Vec<(u64, u64)> = state.segments.iter().map(|s| (s.start, s.end)).collect();
+        assert_eq!(ranges, vec![(0, 4), (4, 7), (7, 10)]);
+        assert!(state.is_consistent());
+
+        // ファイルサイズより多くは分割しない
+        assert_eq!(SegmentState::plan(2, 8).segments.len(), 2);
+        assert!(!SegmentedDownload::default().applies_to(1024));
+    }
+
+    #[test]
+    fn test_state_roundtrip_and_verify() {
+        let dir = tempfile::tempdir().unwrap();
+        let part_path = dir.path().join("video.mp4.part");
+        let mut state = SegmentState::plan(100, 2);
+        state.segments[0].downloaded = 50;
+        state.segments[1].downloaded = 20;
+        state.save(&part_path).unwrap();
+
+        let loaded = SegmentState::load(&part_path).unwrap();
+        assert_eq!(loaded, state);
+        assert_eq!(loaded.downloaded(), 70);
+        assert_eq!(loaded.segments[1].offset(), 70);
+        assert!(loaded.verify(100).is_err());
+
+        state.segments[1].downloaded = 50;
+        assert!(state.verify(100).is_ok());
+        assert!(state.verify(99).is_err());
+
+        SegmentState::remove(&part_path);
+        assert!(SegmentState::load(&part_path).is_none());
+    }
+}
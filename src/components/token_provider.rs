@@ -0,0 +1,242 @@
+//! アクセストークン供給コンポーネント
+//!
+//! # 責任
+//! - API・ダウンロードリクエストへ現在有効なアクセストークンを供給する
+//! - 期限切れ前のトークン更新と、サーバーに拒否（401）されたトークンの更新
+
+use crate::components::auth::{AuthComponent, AuthToken};
+use crate::components::config::{EndpointProfile, OAuthConfig};
+use crate::errors::{AppError, AppResult};
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// アクセストークンの供給元
+///
+/// 長時間のバッチでもリクエストごとに取得することで、期限切れ前に更新されたトークンを使える。
+#[async_trait]
+pub trait TokenProvider: Send + Sync {
+    /// 現在有効なアクセストークンを取得する
+    ///
+    /// # 事後条件
+    /// - 期限が近いトークンは可能であれば更新してから返す
+    /// - トークンがない場合は AppError::Authentication
+    async fn access_token(&self) -> AppResult<String>;
+
+    /// サーバーに拒否されたトークンを更新し、新しいトークンを返す
+    ///
+    /// # 事後条件
+    /// - 他のリクエストが既に更新済みの場合は、更新せずに現在のトークンを返す
+    /// - 更新できない場合は AppError::Authentication
+    async fn refresh_rejected(&self, rejected: &str) -> AppResult<String>;
+}
+
+/// 更新できない固定のアクセストークン
+#[derive(Clone)]
+pub struct StaticToken(String);
+
+impl StaticToken {
+    pub fn new(access_token: impl Into<String>) -> Self {
+        Self(access_token.into())
+    }
+}
+
+impl std::fmt::Debug for StaticToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("StaticToken(..)")
+    }
+}
+
+#[async_trait]
+impl TokenProvider for StaticToken {
+    async fn access_token(&self) -> AppResult<String> {
+        Ok(self.0.clone())
+    }
+
+    async fn refresh_rejected(&self, _rejected: &str) -> AppResult<String> {
+        Err(AppError::authentication(
+            "Access token was rejected and cannot be refreshed",
+            None::<std::io::Error>,
+        ))
+    }
+}
+
+/// AuthComponent のトークンを共有する供給元
+///
+/// クローンは同じ AuthComponent を共有するため、1回の更新が全リクエストに反映される。
+/// 更新中は排他ロックを保持するので、同時に 401 を受けたリクエストがあっても更新は1回で済む。
+#[derive(Clone)]
+pub struct AuthTokenProvider {
+    auth: Arc<Mutex<AuthComponent>>,
+    /// 未認証の場合に使う固定のアクセストークン
+    fallback: Option<String>,
+}
+
+impl std::fmt::Debug for AuthTokenProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuthTokenProvider").finish_non_exhaustive()
+    }
+}
+
+impl Default for AuthTokenProvider {
+    fn default() -> Self {
+        Self::new(AuthComponent::new(OAuthConfig::default()))
+    }
+}
+
+impl AuthTokenProvider {
+    pub fn new(auth: AuthComponent) -> Self {
+        Self {
+            auth: Arc::new(Mutex::new(auth)),
+            fallback: None,
+        }
+    }
+
+    /// 呼び出し元と共有している AuthComponent のトークンを供給する
+    pub fn shared(auth: Arc<Mutex<AuthComponent>>) -> Self {
+        Self {
+            auth,
+            fallback: None,
+        }
+    }
+
+    /// 未認証の場合は access_token を固定で使う供給元を返す
+    ///
+    /// # 事後条件
+    /// - 返された供給元は self と同じ AuthComponent を共有する
+    pub fn or_static(&self, access_token: impl Into<String>) -> Self {
+        Self {
+            auth: Arc::clone(&self.auth),
+            fallback: Some(access_token.into()),
+        }
+    }
+
+    /// 認証フローで取得したトークンに切り替える
+    ///
+    /// # 副作用
+    /// - 共有している AuthComponent を config・endpoints で作り直す
+    ///
+    /// # 事後条件
+    /// - 以降のリクエストは token を使い、リフレッシュトークンがあれば endpoints のトークンURLで
+    ///   自動更新される
+    pub async fn sign_in(
+        &self,
+        config: OAuthConfig,
+        endpoints: EndpointProfile,
+        token: AuthToken,
+    ) -> AppResult<()> {
+        let mut auth = AuthComponent::new(config);
+        auth.set_endpoints(endpoints)?;
+        auth.set_token(token)?;
+        *self.auth.lock().await = auth;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TokenProvider for AuthTokenProvider {
+    async fn access_token(&self) -> AppResult<String> {
+        let mut auth = self.auth.lock().await;
+        auth.get_valid_token()
+            .await?
+            .map(|token| token.access_token.clone())
+            .or_else(|| self.fallback.clone())
+            .ok_or_else(|| {
+                AppError::authentication("No access token available", None::<std::io::Error>)
+            })
+    }
+
+    async fn refresh_rejected(&self, rejected: &str) -> AppResult<String> {
+        let mut auth = self.auth.lock().await;
+        match auth.get_current_token() {
+            Some(token) if token.access_token != rejected => {
+                return Ok(token.access_token.clone());
+            }
+            Some(_) if auth.can_auto_refresh() => {}
+            _ => {
+                return Err(AppError::authentication(
+                    "Access token was rejected and cannot be refreshed",
+                    None::<std::io::Error>,
+                ))
+            }
+        }
+
+        log::info!("Access token was rejected, refreshing");
+        let token = auth.refresh_token().await?;
+        if let Err(e) = auth.save_token_securely().await {
+            log::warn!("Failed to save refreshed token: {:?}", e);
+        }
+        Ok(token.access_token)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Utc};
+
+    fn token(access_token: &str, refresh_token: Option<&str>) -> AuthToken {
+        AuthToken {
+            access_token: access_token.to_string(),
+            token_type: "Bearer".to_string(),
+            expires_at: Utc::now() + Duration::hours(1),
+            refresh_token: refresh_token.map(str::to_string),
+            scopes: vec!["recording:read".to_string()],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_auth_provider_serves_signed_in_token() {
+        let provider = AuthTokenProvider::default();
+        let with_fallback = provider.or_static("fallback");
+        assert!(provider.access_token().await.is_err());
+        assert_eq!(with_fallback.access_token().await.unwrap(), "fallback");
+
+        provider
+            .sign_in(
+                OAuthConfig::default(),
+                EndpointProfile::default(),
+                token("current", None),
+            )
+            .await
+            .unwrap();
+        assert_eq!(provider.access_token().await.unwrap(), "current");
+        // 認証済みのトークンは固定のトークンより優先される
+        assert_eq!(with_fallback.access_token().await.unwrap(), "current");
+    }
+
+    #[tokio::test]
+    async fn test_shared_provider_serves_token_set_on_shared_component() {
+        let auth = Arc::new(Mutex::new(AuthComponent::new(OAuthConfig::default())));
+        let provider = AuthTokenProvider::shared(Arc::clone(&auth));
+        assert!(provider.access_token().await.is_err());
+
+        auth.lock().await.set_token(token("current", None)).unwrap();
+        assert_eq!(provider.access_token().await.unwrap(), "current");
+    }
+
+    #[tokio::test]
+    async fn test_refresh_rejected_reuses_already_refreshed_token() {
+        let provider = AuthTokenProvider::default();
+        provider
+            .sign_in(
+                OAuthConfig::default(),
+                EndpointProfile::default(),
+                token("fresh", None),
+            )
+            .await
+            .unwrap();
+
+        // 別のリクエストが更新済み: 更新せずに現在のトークンを返す
+        assert_eq!(provider.refresh_rejected("old").await.unwrap(), "fresh");
+        // 現在のトークン自体が拒否され、リフレッシュトークンもない
+        assert!(matches!(
+            provider.refresh_rejected("fresh").await,
+            Err(AppError::Authentication { .. })
+        ));
+        assert!(StaticToken::new("fixed")
+            .refresh_rejected("fixed")
+            .await
+            .is_err());
+    }
+}
@@ -0,0 +1,621 @@
+//! クラウド録画のゴミ箱移動コンポーネント
+//!
+//! # 責任
+//! - ダウンロード済みファイルのローカル検証（マニフェストとのサイズ・SHA-256 照合）
+//! - ゴミ箱へ移動する対象の事前確認（ドライラン）結果の表現
+//! - ゴミ箱にある録画の復元・完全削除の対象の表現
+//! - ゴミ箱への移動・復元・完全削除の監査記録（JSON Lines）の永続化
+//!
+//! # 安全要件
+//! - ローカルに存在しない、または検証に失敗したファイルを含む対象は移動しない
+//! - 移動は Zoom の `action=trash` のみ（完全削除はゴミ箱画面で確認した対象に限る）
+
+use crate::components::api::RecordingSearchResponse;
+use crate::components::manifest::{sha256_file, DownloadManifest, ManifestStatus};
+use crate::errors::{AppError, AppResult};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// 監査記録のファイル名
+const TRASH_AUDIT_FILE_NAME: &str = "trash_audit.jsonl";
+
+/// ゴミ箱へ移動する単位
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrashGranularity {
+    /// 録画ファイルごと（DELETE /meetings/{meetingId}/recordings/{recordingId}）
+    #[default]
+    File,
+    /// ミーティングの全録画（DELETE /meetings/{meetingId}/recordings）
+    Meeting,
+}
+
+/// クラウド録画に対する操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrashAction {
+    /// ゴミ箱へ移動
+    #[default]
+    Trash,
+    /// ゴミ箱から復元
+    Recover,
+    /// ゴミ箱から完全に削除
+    Delete,
+}
+
+impl std::fmt::Display for TrashAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Trash => write!(f, "trash"),
+            Self::Recover => write!(f, "recover"),
+            Self::Delete => write!(f, "delete"),
+        }
+    }
+}
+
+/// ゴミ箱にある録画に対する操作（ゴミ箱への移動は含まない）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrashedAction {
+    /// ゴミ箱から復元
+    Recover,
+    /// ゴミ箱から完全に削除
+    Delete,
+}
+
+impl From<TrashedAction> for TrashAction {
+    fn from(action: TrashedAction) -> Self {
+        match action {
+            TrashedAction::Recover => Self::Recover,
+            TrashedAction::Delete => Self::Delete,
+        }
+    }
+}
+
+impl std::fmt::Display for TrashedAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        TrashAction::from(*self).fmt(f)
+    }
+}
+
+/// ダウンロード済みファイルのローカル検証結果
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum LocalVerification {
+    /// マニフェストの記録とサイズ・SHA-256 が一致した
+    Verified { size: u64, sha256: String },
+    /// ローカルにファイルがない
+    Missing,
+    /// ダウンロードの記録がない（検証できない）
+    NotRecorded,
+    /// 記録と実ファイル・Zoom 上のサイズが一致しない
+    Mismatch { reason: String },
+    /// 内容が記録と一致しない
+    HashMismatch,
+    /// 録画ファイルIDがなく、個別に移動できない（AI要約など）
+    NoRecordingId,
+}
+
+impl LocalVerification {
+    /// 検証に成功したか
+    pub fn is_verified(&self) -> bool {
+        matches!(self, Self::Verified { .. })
+    }
+
+    /// 移動しない理由（検証済みの場合は None）
+    pub fn block_reason(&self) -> Option<String> {
+        match self {
+            Self::Verified { .. } => None,
+            Self::Missing => Some("ローカルにファイルがありません".to_string()),
+            Self::NotRecorded => Some("ダウンロードの記録がありません".to_string()),
+            Self::Mismatch { reason } => Some(format!("サイズが一致しません（{}）", reason)),
+            Self::HashMismatch => Some("内容が記録と一致しません".to_string()),
+            Self::NoRecordingId => Some("録画ファイルIDがありません".to_string()),
+        }
+    }
+}
+
+/// ダウンロード済みファイルを検証する
+///
+/// # 副作用
+/// - 対象ファイルの読み込み（SHA-256 計算）
+///
+/// # 事前条件
+/// - relative_path は manifest の出力ディレクトリからの相対パスである
+///
+/// # 事後条件
+/// - ファイルが存在し、マニフェストの記録とサイズ・内容が一致し、
+///   Zoom 上のサイズ（判明時）とも一致する場合のみ Verified が返される
+pub fn verify_local_file(
+    manifest: &DownloadManifest,
+    output_dir: &Path,
+    key: &str,
+    relative_path: &Path,
+    expected_size: Option<u64>,
+) -> LocalVerification {
+    if !output_dir.join(relative_path).is_file() {
+        return LocalVerification::Missing;
+    }
+    match manifest.check(key, relative_path, expected_size) {
+        ManifestStatus::Intact => {}
+        ManifestStatus::NotRecorded => return LocalVerification::NotRecorded,
+        ManifestStatus::Stale(reason) => return LocalVerification::Mismatch { reason },
+    }
+    let Some(entry) = manifest.get(key) else {
+        return LocalVerification::NotRecorded;
+    };
+
+    match sha256_file(&output_dir.join(relative_path)) {
+        Ok(sha256) if sha256 == entry.sha256 => LocalVerification::Verified {
+            size: entry.size,
+            sha256,
+        },
+        Ok(_) => LocalVerification::HashMismatch,
+        Err(e) => {
+            log::warn!(
+                "Could not hash {} for trash verification: {}",
+                relative_path.display(),
+                e
+            );
+            LocalVerification::Missing
+        }
+    }
+}
+
+/// ゴミ箱へ移動する録画ファイル
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrashFile {
+    /// 録画ファイルID（Zoom API の recording id）
+    pub recording_id: String,
+    /// ファイル種別（MP4, CHAT など）
+    pub file_type: String,
+    /// 出力ディレクトリからの相対パス
+    pub local_path: String,
+    pub verification: LocalVerification,
+}
+
+/// ゴミ箱へ移動する対象（ファイル単位では1ファイル、ミーティング単位では全ファイル）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrashItem {
+    pub meeting_uuid: String,
+    pub topic: String,
+    pub start_time: String,
+    pub granularity: TrashGranularity,
+    pub files: Vec<TrashFile>,
+}
+
+impl TrashItem {
+    /// 移動できるか（全ファイルの検証に成功している）
+    pub fn is_eligible(&self) -> bool {
+        !self.files.is_empty() && self.files.iter().all(|f| f.verification.is_verified())
+    }
+
+    /// 移動しない理由（「パス: 理由」の一覧）
+    pub fn block_reasons(&self) -> Vec<String> {
+        if self.files.is_empty() {
+            return vec!["録画ファイルがありません".to_string()];
+        }
+        self.files
+            .iter()
+            .filter_map(|file| {
+                file.verification
+                    .block_reason()
+                    .map(|reason| format!("{}: {}", file.local_path, reason))
+            })
+            .collect()
+    }
+
+    /// 一覧表示用のラベル
+    pub fn label(&self) -> String {
+        match (self.granularity, self.files.as_slice()) {
+            (TrashGranularity::File, [file]) => file.local_path.clone(),
+            _ => format!(
+                "{} {}（{} ファイル）",
+                self.start_time,
+                self.topic,
+                self.files.len()
+            ),
+        }
+    }
+}
+
+/// ゴミ箱への移動の事前確認（ドライラン）結果
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrashPlan {
+    pub granularity: TrashGranularity,
+    pub items: Vec<TrashItem>,
+}
+
+impl TrashPlan {
+    /// 移動する対象
+    pub fn eligible(&self) -> impl Iterator<Item = &TrashItem> {
+        self.items.iter().filter(|item| item.is_eligible())
+    }
+
+    /// 検証に失敗したため移動しない対象
+    pub fn blocked(&self) -> impl Iterator<Item = &TrashItem> {
+        self.items.iter().filter(|item| !item.is_eligible())
+    }
+}
+
+/// ゴミ箱にある録画の復元・完全削除の対象（ミーティング単位またはファイル単位）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrashedTarget {
+    pub meeting_uuid: String,
+    pub topic: String,
+    pub start_time: String,
+    /// 録画ファイルID（ミーティング単位の場合は None）
+    pub recording_id: Option<String>,
+    /// ファイル種別（ミーティング単位の場合は None）
+    pub file_type: Option<String>,
+}
+
+impl TrashedTarget {
+    pub fn granularity(&self) -> TrashGranularity {
+        match self.recording_id {
+            Some(_) => TrashGranularity::File,
+            None => TrashGranularity::Meeting,
+        }
+    }
+
+    /// 一覧表示用のラベル
+    pub fn label(&self) -> String {
+        match &self.file_type {
+            Some(file_type) => format!("{} {}（{}）", self.start_time, self.topic, file_type),
+            None => format!("{} {}（全録画）", self.start_time, self.topic),
+        }
+    }
+}
+
+/// ゴミ箱の一覧での選択から復元・完全削除の対象を求める
+///
+/// # 事前条件
+/// - selected は録画リストと同じ形式（ミーティングは uuid、ファイルは "{uuid}-{stable_id}"）である
+///
+/// # 事後条件
+/// - 選択したミーティングはミーティング単位の対象となり、そのファイルの選択は含まれない
+/// - 録画ファイルIDのないファイルは対象にならない
+pub fn trashed_targets(
+    recordings: &RecordingSearchResponse,
+    selected: &[String],
+) -> Vec<TrashedTarget> {
+    let mut targets = Vec::new();
+    for meeting in &recordings.meetings {
+        let target = |recording_id: Option<String>, file_type: Option<String>| TrashedTarget {
+            meeting_uuid: meeting.uuid.clone(),
+            topic: meeting.topic.clone(),
+            start_time: meeting.start_time.clone(),
+            recording_id,
+            file_type,
+        };
+        if selected.contains(&meeting.uuid) {
+            targets.push(target(None, None));
+            continue;
+        }
+        for file in &meeting.recording_files {
+            let file_id = format!("{}-{}", meeting.uuid, file.stable_id());
+            if !file.id.is_empty() && selected.contains(&file_id) {
+                targets.push(target(
+                    Some(file.id.clone()),
+                    Some(file.file_type.to_string()),
+                ));
+            }
+        }
+    }
+    targets
+}
+
+/// クラウド録画に対する操作の結果
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "result", rename_all = "snake_case")]
+pub enum TrashResult {
+    Succeeded,
+    Failed {
+        error: String,
+    },
+    /// 実行直前の再検証に失敗したため移動しなかった
+    Skipped {
+        reason: String,
+    },
+}
+
+/// クラウド録画に対する操作の監査記録（1回の API 呼び出しにつき1件）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrashAuditRecord {
+    pub at: DateTime<Utc>,
+    #[serde(default)]
+    pub action: TrashAction,
+    pub meeting_uuid: String,
+    pub topic: String,
+    pub granularity: TrashGranularity,
+    /// 操作した録画ファイルID（ミーティング単位の場合は None）
+    #[serde(default)]
+    pub recording_id: Option<String>,
+    /// ゴミ箱への移動時のローカル検証結果（復元・完全削除では空）
+    #[serde(default)]
+    pub files: Vec<TrashFile>,
+    pub result: TrashResult,
+}
+
+impl TrashAuditRecord {
+    /// 復元・完全削除の監査記録を作成する
+    pub fn for_target(action: TrashAction, target: &TrashedTarget, result: TrashResult) -> Self {
+        Self {
+            at: Utc::now(),
+            action,
+            meeting_uuid: target.meeting_uuid.clone(),
+            topic: target.topic.clone(),
+            granularity: target.granularity(),
+            recording_id: target.recording_id.clone(),
+            files: Vec::new(),
+            result,
+        }
+    }
+}
+
+/// クラウド録画に対する操作の実行結果
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TrashReport {
+    pub records: Vec<TrashAuditRecord>,
+}
+
+impl TrashReport {
+    /// 操作に成功した対象の数
+    pub fn succeeded(&self) -> usize {
+        self.count(|result| matches!(result, TrashResult::Succeeded))
+    }
+
+    /// API 呼び出しに失敗した対象の数
+    pub fn failed(&self) -> usize {
+        self.count(|result| matches!(result, TrashResult::Failed { .. }))
+    }
+
+    /// 再検証に失敗して移動しなかった対象の数
+    pub fn skipped(&self) -> usize {
+        self.count(|result| matches!(result, TrashResult::Skipped { .. }))
+    }
+
+    fn count(&self, predicate: impl Fn(&TrashResult) -> bool) -> usize {
+        self.records
+            .iter()
+            .filter(|record| predicate(&record.result))
+            .count()
+    }
+}
+
+/// クラウド録画に対する操作の監査ログ（追記のみ）
+#[derive(Debug, Clone)]
+pub struct TrashAuditLog {
+    path: PathBuf,
+}
+
+impl TrashAuditLog {
+    /// 既定の監査ログのパスを取得
+    pub fn default_path() -> AppResult<PathBuf> {
+        let mut path = dirs::config_dir().ok_or_else(|| {
+            AppError::file_system(
+                "Could not determine config directory",
+                None::<std::io::Error>,
+            )
+        })?;
+
+        // Windows: %APPDATA%\ZoomVideoMover\trash_audit.jsonl
+        #[cfg(target_os = "windows")]
+        path.push("ZoomVideoMover");
+
+        // Unix-like: ~/.config/zoom-video-mover/trash_audit.jsonl
+        #[cfg(not(target_os = "windows"))]
+        path.push("zoom-video-mover");
+
+        path.push(TRASH_AUDIT_FILE_NAME);
+        Ok(path)
+    }
+
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+        }
+    }
+
+    /// 既定パスの監査ログを開く
+    pub fn open_default() -> AppResult<Self> {
+        Ok(Self::new(Self::default_path()?))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// 監査記録を追記する
+    ///
+    /// # 副作用
+    /// - 監査ログファイルへの追記（親ディレクトリがなければ作成）
+    pub fn append(&self, record: &TrashAuditRecord) -> AppResult<()> {
+        let line = serde_json::to_string(record).map_err(|e| {
+            AppError::data_integrity(format!("Failed to serialize trash audit record: {}", e))
+        })?;
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| {
+                AppError::file_system("Failed to create trash audit directory", Some(e))
+            })?;
+        }
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(|e| AppError::file_system("Failed to open trash audit log", Some(e)))?;
+        writeln!(file, "{}", line)
+            .map_err(|e| AppError::file_system("Failed to write trash audit log", Some(e)))
+    }
+
+    /// 全監査記録を読み込む（古い順）
+    ///
+    /// # 事後条件
+    /// - ファイルが存在しない場合は空の一覧が返される
+    /// - 解釈できない行は読み飛ばされる
+    pub fn records(&self) -> AppResult<Vec<TrashAuditRecord>> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+        let content = std::fs::read_to_string(&self.path)
+            .map_err(|e| AppError::file_system("Failed to read trash audit log", Some(e)))?;
+        Ok(content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .filter_map(|line| match serde_json::from_str(line) {
+                Ok(record) => Some(record),
+                Err(e) => {
+                    log::warn!("Skipping unreadable trash audit record: {}", e);
+                    None
+                }
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_files_matching_the_manifest_are_verified() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let output_dir = temp_dir.path();
+        std::fs::create_dir_all(output_dir.join("2025-01-01")).unwrap();
+        let relative = Path::new("2025-01-01/meeting.mp4");
+        std::fs::write(output_dir.join(relative), b"recording").unwrap();
+
+        let mut manifest = DownloadManifest::new(output_dir);
+        manifest.record("file-1", relative).unwrap();
+
+        let verification = verify_local_file(&manifest, output_dir, "file-1", relative, Some(9));
+        assert!(verification.is_verified());
+        // 記録のないファイル・Zoom 上のサイズと異なるファイルは検証できない
+        assert_eq!(
+            verify_local_file(&manifest, output_dir, "file-2", relative, None),
+            LocalVerification::NotRecorded
+        );
+        assert!(matches!(
+            verify_local_file(&manifest, output_dir, "file-1", relative, Some(10)),
+            LocalVerification::Mismatch { .. }
+        ));
+
+        // 同じサイズで内容が変わったファイル
+        std::fs::write(output_dir.join(relative), b"RECORDING").unwrap();
+        assert_eq!(
+            verify_local_file(&manifest, output_dir, "file-1", relative, Some(9)),
+            LocalVerification::HashMismatch
+        );
+
+        std::fs::remove_file(output_dir.join(relative)).unwrap();
+        assert_eq!(
+            verify_local_file(&manifest, output_dir, "file-1", relative, Some(9)),
+            LocalVerification::Missing
+        );
+    }
+
+    #[test]
+    fn test_meeting_with_any_unverified_file_is_blocked_and_audit_is_appended() {
+        let verified = TrashFile {
+            recording_id: "file-1".to_string(),
+            file_type: "MP4".to_string(),
+            local_path: "2025-01-01/meeting.mp4".to_string(),
+            verification: LocalVerification::Verified {
+                size: 9,
+                sha256: "abc".to_string(),
+            },
+        };
+        let missing = TrashFile {
+            recording_id: "file-2".to_string(),
+            file_type: "CHAT".to_string(),
+            local_path: "2025-01-01/meeting_chat.txt".to_string(),
+            verification: LocalVerification::Missing,
+        };
+        let item = |files: Vec<TrashFile>| TrashItem {
+            meeting_uuid: "meeting-1".to_string(),
+            topic: "Weekly".to_string(),
+            start_time: "2025-01-01T10:00:00Z".to_string(),
+            granularity: TrashGranularity::Meeting,
+            files,
+        };
+        let plan = TrashPlan {
+            granularity: TrashGranularity::Meeting,
+            items: vec![
+                item(vec![verified.clone()]),
+                item(vec![verified.clone(), missing]),
+            ],
+        };
+        assert_eq!(plan.eligible().count(), 1);
+        let blocked: Vec<_> = plan.blocked().collect();
+        assert_eq!(
+            blocked[0].block_reasons(),
+            vec!["2025-01-01/meeting_chat.txt: ローカルにファイルがありません"]
+        );
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let log = TrashAuditLog::new(temp_dir.path().join("audit").join(TRASH_AUDIT_FILE_NAME));
+        assert!(log.records().unwrap().is_empty());
+        let record = TrashAuditRecord {
+            at: Utc::now(),
+            action: TrashAction::Trash,
+            meeting_uuid: "meeting-1".to_string(),
+            topic: "Weekly".to_string(),
+            granularity: TrashGranularity::Meeting,
+            recording_id: None,
+            files: vec![verified],
+            result: TrashResult::Succeeded,
+        };
+        log.append(&record).unwrap();
+        log.append(&TrashAuditRecord {
+            result: TrashResult::Failed {
+                error: "HTTP 500".to_string(),
+            },
+            ..record.clone()
+        })
+        .unwrap();
+        let records = log.records().unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0], record);
+    }
+
+    #[test]
+    fn test_trashed_targets_prefer_meeting_selection() {
+        let recordings: RecordingSearchResponse = serde_json::from_value(serde_json::json!({
+            "from": "2025-01-01",
+            "to": "2025-01-31",
+            "page_size": 2,
+            "total_records": 2,
+            "meetings": [
+                {
+                    "uuid": "m1",
+                    "id": 1,
+                    "host_id": "me",
+                    "topic": "Weekly",
+                    "start_time": "2025-01-01T10:00:00Z",
+                    "duration": 30,
+                    "recording_files": [{ "id": "f1", "file_type": "MP4" }]
+                },
+                {
+                    "uuid": "m2",
+                    "id": 2,
+                    "host_id": "me",
+                    "topic": "Review",
+                    "start_time": "2025-01-02T10:00:00Z",
+                    "duration": 30,
+                    "recording_files": [
+                        { "id": "f2", "file_type": "MP4" },
+                        { "id": "f3", "file_type": "CHAT" }
+                    ]
+                }
+            ]
+        }))
+        .unwrap();
+        let selected = ["m1", "m1-f1", "m2-f3"].map(String::from);
+        let targets = trashed_targets(&recordings, &selected);
+        assert_eq!(targets.len(), 2);
+        assert_eq!(targets[0].granularity(), TrashGranularity::Meeting);
+        assert_eq!(targets[1].recording_id.as_deref(), Some("f3"));
+        assert_eq!(targets[1].label(), "2025-01-02T10:00:00Z Review（CHAT）");
+    }
+}
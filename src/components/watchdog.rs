@@ -0,0 +1,142 @@
+//! 転送停滞監視コンポーネント
+//!
+//! # 責任
+//! - タスクごとの転送速度の監視（スライディングウィンドウ）
+//! - 下限速度を下回り続けた転送の停滞判定
+
+use crate::errors::{AppError, AppResult};
+use std::collections::VecDeque;
+use std::time::Duration;
+
+/// 停滞検出の設定
+#[derive(Debug, Clone, PartialEq)]
+pub struct StallDetection {
+    /// 下限とする転送速度（bytes/sec）
+    pub min_bytes_per_sec: u64,
+    /// 転送速度を評価する期間（データ受信を待っていた時間で数える）
+    pub window: Duration,
+}
+
+impl Default for StallDetection {
+    fn default() -> Self {
+        Self {
+            min_bytes_per_sec: 16 * 1024,
+            window: Duration::from_secs(60),
+        }
+    }
+}
+
+impl StallDetection {
+    /// 1つの転送を streams 本の接続で分担する場合の接続ごとの設定
+    pub fn per_stream(&self, streams: usize) -> Self {
+        Self {
+            min_bytes_per_sec: self.min_bytes_per_sec / streams.max(1) as u64,
+            window: self.window,
+        }
+    }
+}
+
+/// 転送停滞の監視
+///
+/// # 判定規則
+/// - データ受信を待っていた時間と受信バイト数を記録し、直近 window 分で転送速度を求める
+/// - 帯域制限による待機や一時停止中の時間は記録されないため、停滞とは判定されない
+/// - 記録が window に満たない間は判定しない
+#[derive(Debug)]
+pub(crate) struct StallWatchdog {
+    config: StallDetection,
+    /// (待機時間, 受信バイト数) の記録
+    samples: VecDeque<(Duration, u64)>,
+    waited: Duration,
+    bytes: u64,
+}
+
+impl StallWatchdog {
+    pub(crate) fn new(config: StallDetection) -> Self {
+        Self {
+            config,
+            samples: VecDeque::new(),
+            waited: Duration::ZERO,
+            bytes: 0,
+        }
+    }
+
+    /// 1回のチャンク待機を記録する（タイムアウトした場合は bytes = 0）
+    pub(crate) fn record(&mut self, waited: Duration, bytes: u64) {
+        self.samples.push_back((waited, bytes));
+        self.waited += waited;
+        self.bytes += bytes;
+
+        // 最も古い記録を除いても評価期間を満たす間は捨てる
+        while let Some(&(oldest_waited, oldest_bytes)) = self.samples.front() {
+            if self.samples.len() == 1 || self.waited - oldest_waited < self.config.window {
+                break;
+            }
+            self.samples.pop_front();
+            self.waited -= oldest_waited;
+            self.bytes -= oldest_bytes;
+        }
+    }
+
+    /// 評価期間の転送速度（bytes/sec、記録が評価期間に満たない場合は None）
+    pub(crate) fn throughput(&self) -> Option<f64> {
+        (!self.waited.is_zero() && self.waited >= self.config.window)
+            .then(|| self.bytes as f64 / self.waited.as_secs_f64())
+    }
+
+    /// 停滞していないかを確認する
+    ///
+    /// # 事後条件
+    /// - 評価期間の転送速度が下限を下回った場合は AppError::Stalled
+    pub(crate) fn check(&self) -> AppResult<()> {
+        match self.throughput() {
+            Some(throughput) if throughput < self.config.min_bytes_per_sec as f64 => {
+                Err(AppError::stalled(format!(
+                    "{:.0} B/s over the last {}s (minimum {} B/s)",
+                    throughput,
+                    self.waited.as_secs(),
+                    self.config.min_bytes_per_sec
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn watchdog() -> StallWatchdog {
+        StallWatchdog::new(StallDetection {
+            min_bytes_per_sec: 1000,
+            window: Duration::from_secs(10),
+        })
+    }
+
+    #[test]
+    fn test_slow_transfer_is_stalled_after_window() {
+        let mut watchdog = watchdog();
+        for _ in 0..9 {
+            watchdog.record(Duration::from_secs(1), 100);
+            assert!(watchdog.check().is_ok());
+        }
+        watchdog.record(Duration::from_secs(1), 100);
+        assert_eq!(watchdog.throughput(), Some(100.0));
+        assert!(matches!(watchdog.check(), Err(AppError::Stalled { .. })));
+    }
+
+    #[test]
+    fn test_window_slides_past_old_samples() {
+        let mut watchdog = watchdog();
+        watchdog.record(Duration::from_secs(10), 0);
+        assert!(watchdog.check().is_err());
+
+        // 速い受信が続けば古い停滞の記録は評価期間から外れる
+        for _ in 0..10 {
+            watchdog.record(Duration::from_secs(1), 5000);
+        }
+        assert_eq!(watchdog.throughput(), Some(5000.0));
+        assert!(watchdog.check().is_ok());
+    }
+}
@@ -53,6 +53,10 @@ pub enum AppError {
         retry_after: Option<u64>,
     },
 
+    /// 転送の停滞（転送速度が下限を下回り続けた）
+    #[error("Transfer stalled: {message}")]
+    Stalled { message: String },
+
     /// 無効なトークンエラー
     #[error("Invalid token: {message}")]
     InvalidToken { message: String },
@@ -188,6 +192,13 @@ impl AppError {
         }
     }
 
+    /// 転送停滞エラーを作成
+    pub fn stalled(message: impl Into<String>) -> Self {
+        Self::Stalled {
+            message: message.into(),
+        }
+    }
+
     /// リソースが見つからないエラーを作成
     pub fn not_found(message: impl Into<String>) -> Self {
         Self::Validation {
@@ -238,6 +249,7 @@ impl AppError {
         match self {
             Self::Network { .. } => true,
             Self::RateLimit { .. } => true,
+            Self::Stalled { .. } => true,
             Self::Api { code, .. } => *code >= 500, // サーバーエラーは回復可能
             _ => false,
         }
@@ -322,6 +334,14 @@ mod tests {
         assert_eq!(err.retry_after(), Some(60));
     }
 
+    #[test]
+    fn test_stalled_error_is_recoverable() {
+        let err = AppError::stalled("1024 B/s");
+        assert!(err.is_recoverable());
+        assert_eq!(err.retry_after(), None);
+        assert_eq!(err.to_string(), "Transfer stalled: 1024 B/s");
+    }
+
     #[test]
     fn test_validation_error() {
         let err = AppError::validation("Invalid field", Some("client_id".to_string()));
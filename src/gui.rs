@@ -1,7 +1,22 @@
-use crate::components::api::RecordingSearchResponse;
+use crate::components::api::{RecordingSearchResponse, ZoomUser};
+use crate::components::archive::VerifyReport;
+use crate::components::auth::{build_oauth_client, AuthToken};
+use crate::components::config::{DownloadSettings, EndpointPreset, EndpointProfile, OAuthConfig};
+use crate::components::disk_space::SpaceCheck;
+use crate::components::download::{
+    ConflictPolicy, DownloadControl, DownloadProgress, OverallProgress, TaskPriority, TaskState,
+};
+use crate::components::failed_batch::FailedItem;
+use crate::components::journal::JournalEntry;
+use crate::components::rate_limit::BandwidthWindow;
+use crate::components::report::{FinalState, ReportFormat, RunReport};
+use crate::components::trash::{
+    self, TrashGranularity, TrashPlan, TrashReport, TrashResult, TrashedAction, TrashedTarget,
+};
+use crate::services::{DownloadOptions, DownloadOutcome, RecordingScope};
 use crate::services_impl::AppServices;
 use crate::Config;
-use chrono::{Datelike, Local};
+use chrono::{Datelike, Local, NaiveTime};
 use eframe::egui;
 use egui::Color32;
 use std::sync::{mpsc, Arc};
@@ -142,6 +157,113 @@ fn card_frame() -> egui::Frame {
         .outer_margin(egui::Margin::symmetric(0.0, 6.0))
 }
 
+/// 録画の一覧（ミーティング・ファイル単位の選択、全選択・全解除付き）を描画する
+///
+/// 選択はミーティングが uuid、ファイルが "{uuid}-{stable_id}" で selected に保持される。
+fn render_recording_list(
+    ui: &mut egui::Ui,
+    title: &str,
+    recordings: &RecordingSearchResponse,
+    selected: &mut std::collections::HashSet<String>,
+    id_source: &str,
+) {
+    let meeting_uuids: Vec<String> = recordings.meetings.iter().map(|m| m.uuid.clone()).collect();
+
+    card_frame().show(ui, |ui| {
+        // ヘッダー行: 選択数バッジ + 全選択/全解除
+        ui.horizontal(|ui| {
+            ui.add(egui::Label::new(
+                egui::RichText::new(title).strong().color(TEXT_PRIMARY),
+            ));
+
+            // 選択数バッジ
+            egui::Frame::none()
+                .fill(PRIMARY)
+                .rounding(egui::Rounding::same(12.0))
+                .inner_margin(egui::Margin::symmetric(10.0, 3.0))
+                .show(ui, |ui| {
+                    ui.add(egui::Label::new(
+                        egui::RichText::new(format!("{}", selected.len()))
+                            .color(TEXT_ON_PRIMARY)
+                            .size(13.0),
+                    ));
+                });
+
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                let deselect_btn = egui::Button::new("全解除").rounding(egui::Rounding::same(8.0));
+                if ui.add(deselect_btn).clicked() {
+                    selected.clear();
+                }
+                let select_btn = egui::Button::new("全選択").rounding(egui::Rounding::same(8.0));
+                if ui.add(select_btn).clicked() {
+                    for uuid in &meeting_uuids {
+                        selected.insert(uuid.clone());
+                    }
+                }
+            });
+        });
+
+        ui.add_space(6.0);
+
+        // 録画リスト
+        egui::ScrollArea::vertical()
+            .id_source(id_source)
+            .max_height(300.0)
+            .show(ui, |ui| {
+                for meeting in &recordings.meetings {
+                    ui.horizontal(|ui| {
+                        let mut meeting_selected = selected.contains(&meeting.uuid);
+                        if ui
+                            .checkbox(
+                                &mut meeting_selected,
+                                format!("Meeting - {}", meeting.topic),
+                            )
+                            .changed()
+                        {
+                            if meeting_selected {
+                                selected.insert(meeting.uuid.clone());
+                            } else {
+                                selected.remove(&meeting.uuid);
+                            }
+                        }
+                    });
+
+                    for file in &meeting.recording_files {
+                        ui.horizontal(|ui| {
+                            ui.add_space(20.0);
+                            let file_id = format!("{}-{}", meeting.uuid, file.stable_id());
+                            let mut file_selected = selected.contains(&file_id);
+                            let ext_display = if file.file_extension.is_empty() {
+                                file.file_type.to_string()
+                            } else {
+                                file.file_extension.clone()
+                            };
+                            if ui
+                                .checkbox(
+                                    &mut file_selected,
+                                    format!(
+                                        "{} ({}) - {}MB",
+                                        file.file_type,
+                                        ext_display,
+                                        file.file_size / 1024 / 1024
+                                    ),
+                                )
+                                .changed()
+                            {
+                                if file_selected {
+                                    selected.insert(file_id);
+                                } else {
+                                    selected.remove(&file_id);
+                                }
+                            }
+                        });
+                    }
+                    ui.add_space(5.0);
+                }
+            });
+    });
+}
+
 /// エラー表示用カードフレーム
 fn error_card_frame() -> egui::Frame {
     egui::Frame::none()
@@ -152,21 +274,204 @@ fn error_card_frame() -> egui::Frame {
         .outer_margin(egui::Margin::symmetric(0.0, 6.0))
 }
 
+/// バイト数を表示用に整形する（例: "12.3 MB"）
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[unit])
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+/// 残り時間を表示用に整形する（不明な場合は "--"）
+fn format_eta(eta: Option<std::time::Duration>) -> String {
+    match eta {
+        Some(eta) => {
+            let secs = eta.as_secs();
+            if secs >= 3600 {
+                format!("{}h {:02}m", secs / 3600, (secs % 3600) / 60)
+            } else {
+                format!("{}m {:02}s", secs / 60, secs % 60)
+            }
+        }
+        None => "--".to_string(),
+    }
+}
+
+/// ファイル別進捗行の状態表示
+fn file_row_status(row: &FileProgressRow) -> String {
+    match row.state {
+        TaskState::Pending => format!("Waiting · {}", priority_label(row.priority)),
+        TaskState::InProgress => format!(
+            "{:.0}% · {}/s",
+            row.progress.percentage * 100.0,
+            format_bytes(row.progress.current_speed as u64)
+        ),
+        TaskState::Completed => "Completed".to_string(),
+        TaskState::Failed => "Failed".to_string(),
+        TaskState::Cancelled => "Cancelled".to_string(),
+    }
+}
+
+/// ゴミ箱へ移動する単位の表示名
+fn trash_granularity_label(granularity: TrashGranularity) -> &'static str {
+    match granularity {
+        TrashGranularity::File => "選択したファイルごと",
+        TrashGranularity::Meeting => "ミーティングの全録画",
+    }
+}
+
+/// ゴミ箱の録画に対する操作の表示名
+fn trash_action_label(action: TrashedAction) -> &'static str {
+    match action {
+        TrashedAction::Recover => "復元",
+        TrashedAction::Delete => "完全に削除",
+    }
+}
+
+/// 優先度の表示名
+fn priority_label(priority: TaskPriority) -> &'static str {
+    match priority {
+        TaskPriority::High => "優先",
+        TaskPriority::Normal => "通常",
+        TaskPriority::Low => "低",
+        TaskPriority::Deferred => "後回し",
+    }
+}
+
 #[derive(Debug)]
 pub enum AppMessage {
     AuthUrlGenerated(String),
     AuthComplete(String),
     RecordingsLoaded(RecordingSearchResponse),
+    /// アカウントのユーザー一覧（録画の取得対象の選択用）
+    UsersLoaded(Vec<ZoomUser>),
     DownloadProgress(String),
+    DownloadOverallProgress(OverallProgress),
+    DownloadFileProgress {
+        task_id: String,
+        file_name: String,
+        state: TaskState,
+        progress: Option<DownloadProgress>,
+    },
+    DownloadTaskPriority {
+        task_id: String,
+        priority: TaskPriority,
+    },
     DownloadComplete(Vec<String>),
+    DownloadLowDiskSpace {
+        available_bytes: u64,
+        reserve_bytes: u64,
+    },
     DownloadPaused,
     DownloadResumed,
     DownloadCancelled,
+    ArchiveVerified(VerifyReport),
+    /// ゴミ箱への移動の事前確認（ドライラン）結果
+    TrashPreviewReady(TrashPlan),
+    /// ゴミ箱への移動の実行結果
+    TrashCompleted(TrashReport),
+    /// ゴミ箱にある録画
+    TrashedRecordingsLoaded(RecordingSearchResponse),
+    /// ゴミ箱の録画の復元・完全削除の実行結果
+    TrashActionCompleted(TrashedAction, TrashReport),
+    /// ダウンロードバッチの実行レポート（DownloadComplete / DownloadCancelled より先に届く）
+    RunReportReady(RunReport),
+    /// 直前のバッチで再試行できるファイルの一覧（バッチ終了ごとに置き換える）
+    RetryableFilesUpdated(Vec<FailedItem>),
     LogExported(String),
     SearchProgress(String),
     Error(String),
 }
 
+/// 設定画面で編集中の帯域スケジュール行
+#[derive(Debug, Clone, Default, PartialEq)]
+struct BandwidthWindowInput {
+    start: String,
+    end: String,
+    limit: String,
+}
+
+/// 1 MB（帯域入力の単位）
+const BYTES_PER_MB: f64 = 1024.0 * 1024.0;
+
+/// MB/s の入力を bytes/sec に変換する（空欄は無制限）
+fn parse_mbps(input: &str) -> Result<Option<u64>, String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Ok(None);
+    }
+    match input.parse::<f64>() {
+        Ok(mbps) if mbps > 0.0 && mbps.is_finite() => Ok(Some((mbps * BYTES_PER_MB) as u64)),
+        _ => Err(format!("Invalid bandwidth limit: {}", input)),
+    }
+}
+
+/// bytes/sec を MB/s の入力文字列に変換する（無制限は空欄）
+fn format_mbps(limit: Option<u64>) -> String {
+    limit
+        .map(|bytes| {
+            let mbps = format!("{:.2}", bytes as f64 / BYTES_PER_MB);
+            mbps.trim_end_matches('0').trim_end_matches('.').to_string()
+        })
+        .unwrap_or_default()
+}
+
+/// 競合ポリシーの表示名
+fn conflict_policy_label(policy: ConflictPolicy) -> &'static str {
+    match policy {
+        ConflictPolicy::Skip => "スキップ",
+        ConflictPolicy::Overwrite => "上書き",
+        ConflictPolicy::Rename => "別名で保存",
+        ConflictPolicy::OverwriteIfSizeDiffers => "サイズが異なる場合のみ上書き",
+    }
+}
+
+/// 接続先プリセットの表示名
+fn endpoint_preset_label(preset: EndpointPreset) -> &'static str {
+    match preset {
+        EndpointPreset::Zoom => "zoom.us",
+        EndpointPreset::ZoomGov => "ZoomGov",
+        EndpointPreset::Custom => "カスタム",
+    }
+}
+
+/// 省略可能な URL の入力欄（空欄は未指定として扱う）
+fn optional_url_input(ui: &mut egui::Ui, width: f32, value: &mut Option<String>, hint: &str) {
+    let mut text = value.clone().unwrap_or_default();
+    let response = ui.add_sized(
+        [width, 30.0],
+        egui::TextEdit::singleline(&mut text).hint_text(hint),
+    );
+    if response.changed() {
+        let trimmed = text.trim();
+        *value = (!trimmed.is_empty()).then(|| trimmed.to_string());
+    }
+}
+
+/// "HH:MM" 形式の時刻入力を解析する
+fn parse_time(input: &str) -> Result<NaiveTime, String> {
+    NaiveTime::parse_from_str(input.trim(), "%H:%M")
+        .map_err(|_| format!("Invalid time (expected HH:MM): {}", input))
+}
+
+/// 進捗画面に表示するファイル単位の進捗行
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileProgressRow {
+    pub task_id: String,
+    pub file_name: String,
+    pub state: TaskState,
+    pub progress: DownloadProgress,
+    pub priority: TaskPriority,
+}
+
 #[derive(Debug, PartialEq)]
 pub enum AppScreen {
     Config,     // SC002: 設定画面
@@ -174,6 +479,19 @@ pub enum AppScreen {
     Recordings, // SC004: 録画リスト画面
     Progress,   // SC005: ダウンロード進捗画面
     Error,      // SC006: エラー表示画面
+    Trash,      // SC007: ゴミ箱画面
+}
+
+/// 録画リスト画面で選ぶ取得対象
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RecordingScopeMode {
+    /// 自分の録画
+    #[default]
+    Me,
+    /// アカウント全体
+    AllUsers,
+    /// 選択したユーザー
+    SelectedUsers,
 }
 
 pub struct ZoomDownloaderApp {
@@ -185,6 +503,11 @@ pub struct ZoomDownloaderApp {
     to_date: String,
     output_dir: String,
     auth_code: String,
+    download_settings: DownloadSettings,
+    endpoints: EndpointProfile,
+    bandwidth_limit_input: String,
+    bandwidth_schedule_inputs: Vec<BandwidthWindowInput>,
+    live_bandwidth_input: String,
 
     // App State
     config_loaded: bool,
@@ -194,17 +517,40 @@ pub struct ZoomDownloaderApp {
     is_searching: bool,
     is_download_paused: bool,
     download_can_resume: bool,
+    download_control: Option<DownloadControl>,
+    unfinished_jobs: Vec<JournalEntry>,
+    failed_items: Vec<FailedItem>,
+    space_warning: Option<SpaceCheck>,
+    low_disk_space: Option<(u64, u64)>,
+    is_verifying: bool,
+    verify_report: Option<VerifyReport>,
+    run_report: Option<RunReport>,
     access_token: Option<String>,
 
     // Recordings Data
     recordings: Option<RecordingSearchResponse>,
     selected_recordings: std::collections::HashSet<String>,
+    scope_mode: RecordingScopeMode,
+    account_users: Vec<ZoomUser>,
+    selected_users: std::collections::HashSet<String>,
+    is_loading_users: bool,
+    trash_granularity: TrashGranularity,
+    trash_plan: Option<TrashPlan>,
+    is_trashing: bool,
+    trash_report: Option<TrashReport>,
+    trashed_recordings: Option<RecordingSearchResponse>,
+    selected_trashed: std::collections::HashSet<String>,
+    is_loading_trash: bool,
+    pending_trash_action: Option<(TrashedAction, Vec<TrashedTarget>)>,
+    trash_action_report: Option<(TrashedAction, TrashReport)>,
 
     // Progress
     status_message: String,
     download_progress: Vec<String>,
     current_file: String,
     progress_percentage: f32,
+    overall_progress: OverallProgress,
+    file_progress: Vec<FileProgressRow>,
 
     // Error State
     error_message: String,
@@ -258,6 +604,11 @@ impl Default for ZoomDownloaderApp {
             to_date: String::new(),
             output_dir: String::new(),
             auth_code: String::new(),
+            download_settings: DownloadSettings::default(),
+            endpoints: EndpointProfile::default(),
+            bandwidth_limit_input: String::new(),
+            bandwidth_schedule_inputs: Vec::new(),
+            live_bandwidth_input: String::new(),
             config_loaded: false,
             auth_url: None,
             is_authenticating: false,
@@ -265,13 +616,36 @@ impl Default for ZoomDownloaderApp {
             is_searching: false,
             is_download_paused: false,
             download_can_resume: false,
+            download_control: None,
+            unfinished_jobs: Vec::new(),
+            failed_items: Vec::new(),
+            space_warning: None,
+            low_disk_space: None,
+            is_verifying: false,
+            verify_report: None,
+            run_report: None,
             access_token: None,
             recordings: None,
             selected_recordings: std::collections::HashSet::new(),
+            scope_mode: RecordingScopeMode::default(),
+            account_users: Vec::new(),
+            selected_users: std::collections::HashSet::new(),
+            is_loading_users: false,
+            trash_granularity: TrashGranularity::default(),
+            trash_plan: None,
+            is_trashing: false,
+            trash_report: None,
+            trashed_recordings: None,
+            selected_trashed: std::collections::HashSet::new(),
+            is_loading_trash: false,
+            pending_trash_action: None,
+            trash_action_report: None,
             status_message: "Ready".to_string(),
             download_progress: Vec::new(),
             current_file: String::new(),
             progress_percentage: 0.0,
+            overall_progress: OverallProgress::default(),
+            file_progress: Vec::new(),
             error_message: String::new(),
             error_details: String::new(),
             log_entries: Vec::new(),
@@ -282,6 +656,8 @@ impl Default for ZoomDownloaderApp {
 
         // 初期ログエントリを追加
         app.add_log_entry(LogLevel::Info, "Application started".to_string(), None);
+        app.check_unfinished_jobs();
+        app.check_failed_items();
         app
     }
 }
@@ -378,40 +754,347 @@ impl ZoomDownloaderApp {
         }
     }
 
+    /// 前回セッションの未完了ジョブを確認する（サービス経由）
+    fn check_unfinished_jobs(&mut self) {
+        match self.services.download_service.unfinished_jobs() {
+            Ok(jobs) if !jobs.is_empty() => {
+                self.status_message = format!(
+                    "{} unfinished download(s) from a previous session",
+                    jobs.len()
+                );
+                self.add_log_entry(
+                    LogLevel::Info,
+                    format!("Found {} unfinished download(s)", jobs.len()),
+                    None,
+                );
+                self.unfinished_jobs = jobs;
+            }
+            Ok(_) => {}
+            Err(e) => {
+                self.add_log_entry(
+                    LogLevel::Warning,
+                    "Failed to read download journal".to_string(),
+                    Some(e.to_string()),
+                );
+            }
+        }
+    }
+
+    /// 前回のバッチで失敗したファイルを確認する（サービス経由）
+    fn check_failed_items(&mut self) {
+        match self.services.download_service.failed_items() {
+            Ok(items) => {
+                if !items.is_empty() {
+                    self.add_log_entry(
+                        LogLevel::Info,
+                        format!("Found {} failed file(s) from the last batch", items.len()),
+                        None,
+                    );
+                }
+                self.failed_items = items;
+            }
+            Err(e) => {
+                self.add_log_entry(
+                    LogLevel::Warning,
+                    "Failed to read failed download list".to_string(),
+                    Some(e.to_string()),
+                );
+            }
+        }
+    }
+
+    /// 前回のバッチで失敗したファイルだけを再ダウンロードする（サービス経由）
+    fn retry_failed_items(&mut self) {
+        let Some(access_token) = self.access_token.clone() else {
+            return;
+        };
+        if self.is_downloading || self.failed_items.is_empty() {
+            return;
+        }
+        if let Err(e) = self.apply_bandwidth_inputs() {
+            self.status_message = e;
+            return;
+        }
+
+        self.is_downloading = true;
+        self.is_download_paused = false;
+        self.reset_progress_view();
+        self.add_log_entry(
+            LogLevel::Info,
+            format!("Retrying {} failed file(s)", self.failed_items.len()),
+            None,
+        );
+        self.current_screen = AppScreen::Progress;
+
+        let control = DownloadControl::new();
+        self.download_control = Some(control.clone());
+
+        let options = DownloadOptions {
+            output_dir: self.output_dir.clone(),
+            settings: self.download_settings.clone(),
+            endpoints: self.endpoints.clone(),
+        };
+        let sender = self.sender.clone();
+        let download_service = Arc::clone(&self.services.download_service);
+
+        thread::spawn(move || {
+            let result =
+                download_service.retry_failed(&access_token, &options, control, sender.clone());
+            Self::report_download_outcome(result, &sender);
+        });
+    }
+
+    /// 未完了ジョブを再開する（サービス経由）
+    fn resume_unfinished_jobs(&mut self) {
+        let Some(access_token) = self.access_token.clone() else {
+            return;
+        };
+        if let Err(e) = self.apply_bandwidth_inputs() {
+            self.status_message = e;
+            return;
+        }
+
+        self.is_downloading = true;
+        self.is_download_paused = false;
+        self.reset_progress_view();
+        self.unfinished_jobs.clear();
+        self.current_screen = AppScreen::Progress;
+
+        let control = DownloadControl::new();
+        self.download_control = Some(control.clone());
+
+        let options = DownloadOptions {
+            output_dir: self.output_dir.clone(),
+            settings: self.download_settings.clone(),
+            endpoints: self.endpoints.clone(),
+        };
+        let sender = self.sender.clone();
+        let download_service = Arc::clone(&self.services.download_service);
+
+        thread::spawn(move || {
+            let result = download_service.resume_unfinished_jobs(
+                &access_token,
+                &options,
+                control,
+                sender.clone(),
+            );
+            Self::report_download_outcome(result, &sender);
+        });
+    }
+
+    /// 未完了ジョブを破棄する（サービス経由）
+    fn discard_unfinished_jobs(&mut self) {
+        match self.services.download_service.discard_unfinished_jobs() {
+            Ok(_) => {
+                self.add_log_entry(
+                    LogLevel::Info,
+                    format!(
+                        "Discarded {} unfinished download(s)",
+                        self.unfinished_jobs.len()
+                    ),
+                    None,
+                );
+                self.unfinished_jobs.clear();
+                self.status_message = "Unfinished downloads discarded".to_string();
+            }
+            Err(e) => {
+                self.status_message = format!("Failed to discard unfinished downloads: {}", e);
+            }
+        }
+    }
+
+    /// 出力ディレクトリのアーカイブを検証する（サービス経由）
+    ///
+    /// 全ファイルを再ハッシュするため別スレッドで実行し、結果は ArchiveVerified で受け取る
+    fn verify_archive(&mut self) {
+        if self.is_verifying {
+            return;
+        }
+        if self.output_dir.trim().is_empty() {
+            self.status_message = "Output directory is required".to_string();
+            return;
+        }
+
+        self.is_verifying = true;
+        self.verify_report = None;
+        self.status_message = "Verifying archive...".to_string();
+
+        let output_dir = self.output_dir.clone();
+        let sender = self.sender.clone();
+        let download_service = Arc::clone(&self.services.download_service);
+
+        thread::spawn(move || {
+            let message = match download_service.verify_archive(&output_dir) {
+                Ok(report) => AppMessage::ArchiveVerified(report),
+                Err(e) => AppMessage::Error(format!("Archive verification error: {}", e)),
+            };
+            let _ = sender.send(message);
+        });
+    }
+
+    /// 選択中の録画をゴミ箱へ移動した場合の対象を確認する（サービス経由、クラウドは変更しない）
+    ///
+    /// ローカルファイルを再ハッシュするため別スレッドで実行し、結果は TrashPreviewReady で受け取る
+    fn preview_trash(&mut self) {
+        let Some(recordings) = self.recordings.clone() else {
+            return;
+        };
+        if self.is_trashing || self.selected_recordings.is_empty() {
+            return;
+        }
+
+        self.is_trashing = true;
+        self.trash_plan = None;
+        self.trash_report = None;
+        self.status_message = "Verifying downloaded files...".to_string();
+
+        let options = DownloadOptions {
+            output_dir: self.output_dir.clone(),
+            settings: self.download_settings.clone(),
+            endpoints: self.endpoints.clone(),
+        };
+        let selected: Vec<String> = self.selected_recordings.iter().cloned().collect();
+        let granularity = self.trash_granularity;
+        let sender = self.sender.clone();
+        let download_service = Arc::clone(&self.services.download_service);
+
+        thread::spawn(move || {
+            let message =
+                match download_service.preview_trash(&recordings, &selected, &options, granularity)
+                {
+                    Ok(plan) => AppMessage::TrashPreviewReady(plan),
+                    Err(e) => AppMessage::Error(format!("Trash preview error: {}", e)),
+                };
+            let _ = sender.send(message);
+        });
+    }
+
+    /// 確認済みの対象をゴミ箱へ移動する（サービス経由）
+    fn trash_confirmed(&mut self) {
+        let Some(access_token) = self.access_token.clone() else {
+            return;
+        };
+        let Some(plan) = self.trash_plan.take() else {
+            return;
+        };
+
+        self.is_trashing = true;
+        self.status_message = "Moving recordings to trash...".to_string();
+
+        let options = DownloadOptions {
+            output_dir: self.output_dir.clone(),
+            settings: self.download_settings.clone(),
+            endpoints: self.endpoints.clone(),
+        };
+        let sender = self.sender.clone();
+        let download_service = Arc::clone(&self.services.download_service);
+
+        thread::spawn(move || {
+            let message = match download_service.trash_recordings(&access_token, &plan, &options) {
+                Ok(report) => AppMessage::TrashCompleted(report),
+                Err(e) => AppMessage::Error(format!("Move to trash error: {}", e)),
+            };
+            let _ = sender.send(message);
+        });
+    }
+
+    /// 直近の実行レポートを出力ディレクトリの reports フォルダへ書き出す
+    fn export_run_report(&mut self, format: ReportFormat) {
+        let Some(report) = &self.run_report else {
+            return;
+        };
+        match report.export(&self.output_dir, format) {
+            Ok(path) => {
+                self.add_log_entry(
+                    LogLevel::Info,
+                    format!("Run report exported to: {}", path.display()),
+                    None,
+                );
+                self.status_message = format!("Run report exported: {}", path.display());
+            }
+            Err(e) => {
+                self.status_message = format!("Failed to export run report: {}", e);
+            }
+        }
+    }
+
+    /// ダウンロード処理の結果をメッセージとして通知する
+    fn report_download_outcome(
+        result: Result<DownloadOutcome, Box<dyn std::error::Error + Send + Sync>>,
+        sender: &mpsc::Sender<AppMessage>,
+    ) {
+        match result {
+            Ok(DownloadOutcome::Completed(files)) => {
+                let _ = sender.send(AppMessage::DownloadComplete(files));
+            }
+            Ok(DownloadOutcome::Cancelled {
+                completed_files,
+                cancelled_tasks,
+            }) => {
+                let _ = sender.send(AppMessage::DownloadProgress(format!(
+                    "Cancelled: {} file(s) saved, {} file(s) not downloaded",
+                    completed_files.len(),
+                    cancelled_tasks
+                )));
+                let _ = sender.send(AppMessage::DownloadCancelled);
+            }
+            Err(e) => {
+                let _ = sender.send(AppMessage::Error(format!("Download error: {}", e)));
+            }
+        }
+    }
+
     /// ダウンロードを一時停止する
+    ///
+    /// ワーカーへ一時停止を要求するのみで、画面状態は DownloadPaused 受信時に更新する
     fn pause_download(&mut self) {
         if self.is_downloading && !self.is_download_paused {
-            self.is_download_paused = true;
-            self.download_can_resume = true;
-            self.status_message = "Download paused by user".to_string();
-            self.add_log_entry(
-                LogLevel::Info,
-                "Download paused".to_string(),
-                Some("User requested pause".to_string()),
-            );
-
-            let _ = self.sender.send(AppMessage::DownloadPaused);
+            if let Some(control) = &self.download_control {
+                control.pause();
+                self.status_message = "Pausing download...".to_string();
+                self.add_log_entry(
+                    LogLevel::Info,
+                    "Pause requested".to_string(),
+                    Some("Waiting for download workers to pause".to_string()),
+                );
+            }
         }
     }
 
     /// ダウンロードを再開する
+    ///
+    /// ワーカーへ再開を要求するのみで、画面状態は DownloadResumed 受信時に更新する
     fn resume_download(&mut self) {
         if self.is_download_paused && self.download_can_resume {
-            self.is_download_paused = false;
-            self.status_message = "Download resumed".to_string();
-            self.add_log_entry(
-                LogLevel::Info,
-                "Download resumed".to_string(),
-                Some("User requested resume".to_string()),
-            );
-
-            let _ = self.sender.send(AppMessage::DownloadResumed);
+            if let Some(control) = &self.download_control {
+                control.resume();
+                self.status_message = "Resuming download...".to_string();
+                self.add_log_entry(
+                    LogLevel::Info,
+                    "Resume requested".to_string(),
+                    Some("Waiting for download workers to resume".to_string()),
+                );
+            }
         }
     }
 
     /// ダウンロードをキャンセルする
+    ///
+    /// ワーカーへキャンセルを要求し、画面状態は DownloadCancelled 受信時に更新する
     fn cancel_download(&mut self) {
-        if self.is_downloading {
+        if !self.is_downloading {
+            return;
+        }
+
+        if let Some(control) = &self.download_control {
+            control.cancel();
+            self.status_message = "Cancelling download...".to_string();
+            self.add_log_entry(
+                LogLevel::Warning,
+                "Cancel requested".to_string(),
+                Some("Waiting for download workers to stop".to_string()),
+            );
+        } else {
             self.is_downloading = false;
             self.is_download_paused = false;
             self.download_can_resume = false;
@@ -423,11 +1106,91 @@ impl ZoomDownloaderApp {
                 "Download cancelled".to_string(),
                 Some("User requested cancellation".to_string()),
             );
+        }
+    }
+
+    /// ファイル単位の進捗行を更新する（未登録のタスクは行を追加）
+    ///
+    /// # 事後条件
+    /// - progress が None の場合は既存の進捗値が保持される
+    /// - 転送中のファイルは current_file に反映される
+    fn update_file_progress(
+        &mut self,
+        task_id: String,
+        file_name: String,
+        state: TaskState,
+        progress: Option<DownloadProgress>,
+    ) {
+        if state == TaskState::InProgress {
+            self.current_file = file_name.clone();
+        }
+
+        match self
+            .file_progress
+            .iter_mut()
+            .find(|row| row.task_id == task_id)
+        {
+            Some(row) => {
+                row.state = state;
+                if let Some(progress) = progress {
+                    row.progress = progress;
+                } else if row.state == TaskState::Completed {
+                    row.progress.percentage = 1.0;
+                    row.progress.current_speed = 0.0;
+                }
+            }
+            None => {
+                let mut progress = progress.unwrap_or_default();
+                if state == TaskState::Completed {
+                    progress.percentage = 1.0;
+                }
+                self.file_progress.push(FileProgressRow {
+                    task_id,
+                    file_name,
+                    state,
+                    progress,
+                    priority: TaskPriority::default(),
+                });
+            }
+        }
+    }
+
+    /// 待機中タスクの優先度を変更する
+    ///
+    /// # 事後条件
+    /// - 実行中のダウンロードへ変更が通知され、ファイル別進捗の表示も更新される
+    fn change_task_priority(&mut self, task_id: &str, priority: TaskPriority) {
+        let Some(control) = &self.download_control else {
+            return;
+        };
+        control.set_task_priority(task_id, priority);
 
-            let _ = self.sender.send(AppMessage::DownloadCancelled);
+        if let Some(row) = self
+            .file_progress
+            .iter_mut()
+            .find(|row| row.task_id == task_id)
+        {
+            row.priority = priority;
+            let message = format!(
+                "Priority of {} set to {}",
+                row.file_name,
+                priority_label(priority)
+            );
+            self.add_log_entry(LogLevel::Info, message, None);
         }
     }
 
+    /// 進捗表示をリセットする（新しいダウンロード開始時）
+    fn reset_progress_view(&mut self) {
+        self.live_bandwidth_input = format_mbps(self.download_settings.max_bytes_per_sec);
+        self.download_progress.clear();
+        self.current_file.clear();
+        self.progress_percentage = 0.0;
+        self.overall_progress = OverallProgress::default();
+        self.file_progress.clear();
+        self.low_disk_space = None;
+    }
+
     /// メッセージを処理する
     fn process_messages(&mut self) {
         while let Ok(msg) = self.receiver.try_recv() {
@@ -450,6 +1213,14 @@ impl ZoomDownloaderApp {
                     self.is_searching = false;
                     self.status_message = "Recordings loaded.".to_string();
                 }
+                AppMessage::UsersLoaded(users) => {
+                    self.is_loading_users = false;
+                    self.status_message = format!("{} user(s) loaded.", users.len());
+                    // 一覧にいなくなったユーザーは選択から外す
+                    self.selected_users
+                        .retain(|id| users.iter().any(|user| &user.id == id));
+                    self.account_users = users;
+                }
                 AppMessage::SearchProgress(msg) => {
                     self.status_message = msg;
                 }
@@ -458,16 +1229,55 @@ impl ZoomDownloaderApp {
                     self.status_message = msg;
                     self.current_screen = AppScreen::Progress;
                 }
+                AppMessage::DownloadOverallProgress(overall) => {
+                    self.progress_percentage = overall.overall_percentage.clamp(0.0, 1.0) as f32;
+                    self.overall_progress = overall;
+                }
+                AppMessage::DownloadFileProgress {
+                    task_id,
+                    file_name,
+                    state,
+                    progress,
+                } => {
+                    self.update_file_progress(task_id, file_name, state, progress);
+                }
+                AppMessage::DownloadTaskPriority { task_id, priority } => {
+                    if let Some(row) = self
+                        .file_progress
+                        .iter_mut()
+                        .find(|row| row.task_id == task_id)
+                    {
+                        row.priority = priority;
+                    }
+                }
                 AppMessage::DownloadComplete(files) => {
                     self.is_downloading = false;
+                    self.is_download_paused = false;
+                    self.download_control = None;
                     self.current_screen = AppScreen::Recordings;
                     self.status_message = format!("Download completed: {} files", files.len());
                     self.download_progress
                         .push(format!("Completed: Downloaded {} files", files.len()));
                 }
+                AppMessage::DownloadLowDiskSpace {
+                    available_bytes,
+                    reserve_bytes,
+                } => {
+                    self.low_disk_space = Some((available_bytes, reserve_bytes));
+                    self.add_log_entry(
+                        LogLevel::Warning,
+                        "Download paused: low disk space".to_string(),
+                        Some(format!(
+                            "{} free, {} reserved",
+                            format_bytes(available_bytes),
+                            format_bytes(reserve_bytes)
+                        )),
+                    );
+                }
                 AppMessage::DownloadPaused => {
                     self.is_download_paused = true;
                     self.download_can_resume = true;
+                    self.status_message = "Download paused".to_string();
                     self.add_log_entry(
                         LogLevel::Info,
                         "Download paused by background task".to_string(),
@@ -476,6 +1286,8 @@ impl ZoomDownloaderApp {
                 }
                 AppMessage::DownloadResumed => {
                     self.is_download_paused = false;
+                    self.low_disk_space = None;
+                    self.status_message = "Download resumed".to_string();
                     self.add_log_entry(
                         LogLevel::Info,
                         "Download resumed by background task".to_string(),
@@ -486,26 +1298,135 @@ impl ZoomDownloaderApp {
                     self.is_downloading = false;
                     self.is_download_paused = false;
                     self.download_can_resume = false;
+                    self.download_control = None;
+                    self.progress_percentage = 0.0;
                     self.current_screen = AppScreen::Recordings;
+                    self.status_message = "Download cancelled".to_string();
                     self.add_log_entry(
                         LogLevel::Warning,
                         "Download cancelled by background task".to_string(),
                         None,
                     );
                 }
-                AppMessage::LogExported(filepath) => {
+                AppMessage::ArchiveVerified(report) => {
+                    self.is_verifying = false;
+                    self.status_message = format!("Archive verification: {}", report.summary());
+                    let level = if report.is_clean() {
+                        LogLevel::Info
+                    } else {
+                        LogLevel::Warning
+                    };
                     self.add_log_entry(
-                        LogLevel::Info,
-                        format!("Log exported to: {}", filepath),
+                        level,
+                        format!("Archive verification: {}", report.summary()),
                         None,
                     );
-                    self.status_message = format!("Log exported successfully: {}", filepath);
+                    self.verify_report = Some(report);
                 }
-                AppMessage::Error(err) => {
-                    self.is_authenticating = false;
-                    self.is_downloading = false;
-                    self.is_searching = false;
-                    self.error_message = err.clone();
+                AppMessage::TrashPreviewReady(plan) => {
+                    self.is_trashing = false;
+                    let eligible = plan.eligible().count();
+                    self.status_message = format!(
+                        "Trash preview: {} eligible, {} blocked",
+                        eligible,
+                        plan.items.len() - eligible
+                    );
+                    self.trash_plan = Some(plan);
+                }
+                AppMessage::TrashCompleted(report) => {
+                    self.is_trashing = false;
+                    let summary = format!(
+                        "{} trashed, {} failed, {} skipped",
+                        report.succeeded(),
+                        report.failed(),
+                        report.skipped()
+                    );
+                    let level = if report.failed() + report.skipped() == 0 {
+                        LogLevel::Info
+                    } else {
+                        LogLevel::Warning
+                    };
+                    for record in &report.records {
+                        match &record.result {
+                            TrashResult::Succeeded => {}
+                            TrashResult::Failed { error } => self.add_log_entry(
+                                LogLevel::Error,
+                                format!("Failed to trash {}", record.topic),
+                                Some(error.clone()),
+                            ),
+                            TrashResult::Skipped { reason } => self.add_log_entry(
+                                LogLevel::Warning,
+                                format!("Did not trash {}", record.topic),
+                                Some(reason.clone()),
+                            ),
+                        }
+                    }
+                    self.add_log_entry(level, format!("Move to trash: {}", summary), None);
+                    self.status_message = format!("Move to trash: {}", summary);
+                    self.trash_report = Some(report);
+                }
+                AppMessage::TrashedRecordingsLoaded(recordings) => {
+                    self.is_loading_trash = false;
+                    self.status_message =
+                        format!("{} trashed meeting(s) loaded.", recordings.meetings.len());
+                    self.selected_trashed.clear();
+                    self.trashed_recordings = Some(recordings);
+                }
+                AppMessage::TrashActionCompleted(action, report) => {
+                    self.is_loading_trash = false;
+                    let summary = format!(
+                        "{} succeeded, {} failed",
+                        report.succeeded(),
+                        report.failed()
+                    );
+                    for record in &report.records {
+                        if let TrashResult::Failed { error } = &record.result {
+                            self.add_log_entry(
+                                LogLevel::Error,
+                                format!("Failed to {} {}", action, record.topic),
+                                Some(error.clone()),
+                            );
+                        }
+                    }
+                    let level = if report.failed() == 0 {
+                        LogLevel::Info
+                    } else {
+                        LogLevel::Warning
+                    };
+                    self.add_log_entry(level, format!("Trash {}: {}", action, summary), None);
+                    self.status_message = format!("Trash {}: {}", action, summary);
+                    self.remove_processed_trashed(&report);
+                    self.trash_action_report = Some((action, report));
+                }
+                AppMessage::RunReportReady(report) => {
+                    let level = if report.count(FinalState::Failed) > 0 {
+                        LogLevel::Warning
+                    } else {
+                        LogLevel::Info
+                    };
+                    self.add_log_entry(level, format!("Run report: {}", report.summary()), None);
+                    self.run_report = Some(report);
+                }
+                AppMessage::RetryableFilesUpdated(items) => {
+                    self.failed_items = items;
+                }
+                AppMessage::LogExported(filepath) => {
+                    self.add_log_entry(
+                        LogLevel::Info,
+                        format!("Log exported to: {}", filepath),
+                        None,
+                    );
+                    self.status_message = format!("Log exported successfully: {}", filepath);
+                }
+                AppMessage::Error(err) => {
+                    self.is_authenticating = false;
+                    self.is_downloading = false;
+                    self.is_searching = false;
+                    self.is_loading_users = false;
+                    self.is_verifying = false;
+                    self.is_trashing = false;
+                    self.is_loading_trash = false;
+                    self.error_message = err.clone();
                     self.error_details = format!(
                         "Timestamp: {}",
                         chrono::Local::now().format("%Y-%m-%d %H:%M:%S")
@@ -555,6 +1476,7 @@ impl ZoomDownloaderApp {
                             self.access_token.is_some(),
                         ),
                         ("ダウンロード", AppScreen::Progress, self.is_downloading),
+                        ("ゴミ箱", AppScreen::Trash, self.access_token.is_some()),
                     ];
 
                     for (label, screen, visible) in tabs {
@@ -582,12 +1504,25 @@ impl ZoomDownloaderApp {
 
                 ui.add_space(8.0);
 
+                // 前回セッションの未完了ジョブ
+                if !self.unfinished_jobs.is_empty() && !self.is_downloading {
+                    self.render_unfinished_jobs_banner(ui);
+                    ui.add_space(8.0);
+                }
+
+                // 前回のバッチで失敗したファイル
+                if !self.failed_items.is_empty() && !self.is_downloading {
+                    self.render_failed_items_banner(ui);
+                    ui.add_space(8.0);
+                }
+
                 // 現在のタブコンテンツ表示エリア
                 match self.current_screen {
                     AppScreen::Config => self.render_config(ui),
                     AppScreen::Auth => self.render_auth(ui),
                     AppScreen::Recordings => self.render_recordings(ui),
                     AppScreen::Progress => self.render_progress(ui),
+                    AppScreen::Trash => self.render_trash(ui),
                     AppScreen::Error => {
                         self.render_error(ui);
                     }
@@ -679,6 +1614,237 @@ impl ZoomDownloaderApp {
                         egui::TextEdit::singleline(&mut self.output_dir),
                     );
                     ui.end_row();
+
+                    // CF015: 接続先（OAuth・API）
+                    ui.add(egui::Label::new(
+                        egui::RichText::new("Endpoint").color(TEXT_SECONDARY),
+                    ));
+                    egui::ComboBox::from_id_source("endpoint_preset")
+                        .selected_text(endpoint_preset_label(self.endpoints.preset))
+                        .show_ui(ui, |ui| {
+                            for preset in [
+                                EndpointPreset::Zoom,
+                                EndpointPreset::ZoomGov,
+                                EndpointPreset::Custom,
+                            ] {
+                                ui.selectable_value(
+                                    &mut self.endpoints.preset,
+                                    preset,
+                                    endpoint_preset_label(preset),
+                                );
+                            }
+                        });
+                    ui.end_row();
+
+                    if self.endpoints.preset == EndpointPreset::Custom {
+                        let endpoints = &mut self.endpoints;
+                        for (label, value, hint) in [
+                            (
+                                "Auth URL",
+                                &mut endpoints.auth_url,
+                                "http://127.0.0.1:9000/oauth/authorize",
+                            ),
+                            (
+                                "Token URL",
+                                &mut endpoints.token_url,
+                                "http://127.0.0.1:9000/oauth/token",
+                            ),
+                            (
+                                "Revoke URL",
+                                &mut endpoints.revoke_url,
+                                "http://127.0.0.1:9000/oauth/revoke",
+                            ),
+                            (
+                                "API Base URL",
+                                &mut endpoints.api_base_url,
+                                "http://127.0.0.1:9000/v2",
+                            ),
+                        ] {
+                            ui.add(egui::Label::new(
+                                egui::RichText::new(label).color(TEXT_SECONDARY),
+                            ));
+                            optional_url_input(ui, field_width, value, hint);
+                            ui.end_row();
+                        }
+                    }
+
+                    // CF006: キャンセル時の途中ファイル扱い
+                    ui.add(egui::Label::new(
+                        egui::RichText::new("On Cancel").color(TEXT_SECONDARY),
+                    ));
+                    ui.checkbox(
+                        &mut self.download_settings.keep_partial_on_cancel,
+                        "途中までのファイル（.part）を残す",
+                    );
+                    ui.end_row();
+
+                    // CF009: 同名ファイルが存在する場合の扱い
+                    ui.add(egui::Label::new(
+                        egui::RichText::new("If File Exists").color(TEXT_SECONDARY),
+                    ));
+                    egui::ComboBox::from_id_source("conflict_policy")
+                        .selected_text(conflict_policy_label(
+                            self.download_settings.conflict_policy,
+                        ))
+                        .show_ui(ui, |ui| {
+                            for policy in [
+                                ConflictPolicy::Skip,
+                                ConflictPolicy::Overwrite,
+                                ConflictPolicy::Rename,
+                                ConflictPolicy::OverwriteIfSizeDiffers,
+                            ] {
+                                ui.selectable_value(
+                                    &mut self.download_settings.conflict_policy,
+                                    policy,
+                                    conflict_policy_label(policy),
+                                );
+                            }
+                        });
+                    ui.end_row();
+
+                    // CF010: 確保する空き容量
+                    ui.add(egui::Label::new(
+                        egui::RichText::new("Free Space Reserve").color(TEXT_SECONDARY),
+                    ));
+                    ui.horizontal(|ui| {
+                        ui.add(
+                            egui::DragValue::new(&mut self.download_settings.min_free_space_mb)
+                                .speed(64.0)
+                                .suffix(" MB"),
+                        );
+                        ui.add(egui::Label::new(
+                            egui::RichText::new("下回ると一時停止（0 で監視しない）")
+                                .color(TEXT_SECONDARY),
+                        ));
+                    });
+                    ui.end_row();
+
+                    // CF011: 同時実行数の自動調整
+                    ui.add(egui::Label::new(
+                        egui::RichText::new("Adaptive Concurrency").color(TEXT_SECONDARY),
+                    ));
+                    ui.horizontal(|ui| {
+                        ui.checkbox(&mut self.download_settings.adaptive_concurrency, "自動調整");
+                        ui.add_enabled_ui(self.download_settings.adaptive_concurrency, |ui| {
+                            ui.add(
+                                egui::DragValue::new(
+                                    &mut self.download_settings.min_concurrent_downloads,
+                                )
+                                .range(1..=10)
+                                .prefix("min "),
+                            );
+                            ui.add(
+                                egui::DragValue::new(
+                                    &mut self.download_settings.max_concurrent_downloads,
+                                )
+                                .range(1..=10)
+                                .prefix("max "),
+                            );
+                        });
+                    });
+                    ui.end_row();
+
+                    // CF012: 大きなファイルの分割ダウンロード
+                    ui.add(egui::Label::new(
+                        egui::RichText::new("Segmented Download").color(TEXT_SECONDARY),
+                    ));
+                    ui.horizontal(|ui| {
+                        ui.add(
+                            egui::DragValue::new(&mut self.download_settings.segment_count)
+                                .range(1..=16)
+                                .suffix(" 分割"),
+                        );
+                        ui.add(
+                            egui::DragValue::new(&mut self.download_settings.segment_threshold_mb)
+                                .speed(16.0)
+                                .prefix("≥ ")
+                                .suffix(" MB"),
+                        );
+                        ui.add(egui::Label::new(
+                            egui::RichText::new("1 で分割しない").color(TEXT_SECONDARY),
+                        ));
+                    });
+                    ui.end_row();
+
+                    // CF013: 停滞検出
+                    ui.add(egui::Label::new(
+                        egui::RichText::new("Stall Detection").color(TEXT_SECONDARY),
+                    ));
+                    ui.horizontal(|ui| {
+                        ui.add(
+                            egui::DragValue::new(&mut self.download_settings.stall_min_kb_per_sec)
+                                .suffix(" KB/s"),
+                        );
+                        ui.add(
+                            egui::DragValue::new(&mut self.download_settings.stall_window_secs)
+                                .range(1..=3600)
+                                .suffix(" 秒"),
+                        );
+                        ui.add(egui::Label::new(
+                            egui::RichText::new("下回ると中断して再試行（0 KB/s で無効）")
+                                .color(TEXT_SECONDARY),
+                        ));
+                    });
+                    ui.end_row();
+
+                    // CF007: 帯域上限
+                    ui.add(egui::Label::new(
+                        egui::RichText::new("Bandwidth Limit").color(TEXT_SECONDARY),
+                    ));
+                    ui.horizontal(|ui| {
+                        ui.add_sized(
+                            [100.0, 30.0],
+                            egui::TextEdit::singleline(&mut self.bandwidth_limit_input)
+                                .hint_text("無制限"),
+                        );
+                        ui.add(egui::Label::new(
+                            egui::RichText::new("MB/s（空欄で無制限）").color(TEXT_SECONDARY),
+                        ));
+                    });
+                    ui.end_row();
+
+                    // CF008: 時間帯別の帯域上限
+                    ui.add(egui::Label::new(
+                        egui::RichText::new("Bandwidth Schedule").color(TEXT_SECONDARY),
+                    ));
+                    ui.vertical(|ui| {
+                        let mut removed = None;
+                        for (index, window) in self.bandwidth_schedule_inputs.iter_mut().enumerate()
+                        {
+                            ui.horizontal(|ui| {
+                                ui.add_sized(
+                                    [60.0, 26.0],
+                                    egui::TextEdit::singleline(&mut window.start)
+                                        .hint_text("09:00"),
+                                );
+                                ui.label("–");
+                                ui.add_sized(
+                                    [60.0, 26.0],
+                                    egui::TextEdit::singleline(&mut window.end).hint_text("18:00"),
+                                );
+                                ui.add_sized(
+                                    [70.0, 26.0],
+                                    egui::TextEdit::singleline(&mut window.limit)
+                                        .hint_text("無制限"),
+                                );
+                                ui.label("MB/s");
+                                if ui.small_button("削除").clicked() {
+                                    removed = Some(index);
+                                }
+                            });
+                        }
+                        if let Some(index) = removed {
+                            self.bandwidth_schedule_inputs.remove(index);
+                        }
+                        if ui.small_button("+ 時間帯を追加").clicked() {
+                            self.bandwidth_schedule_inputs.push(BandwidthWindowInput {
+                                start: "09:00".to_string(),
+                                end: "18:00".to_string(),
+                                limit: String::new(),
+                            });
+                        }
+                    });
+                    ui.end_row();
                 });
         });
 
@@ -703,10 +1869,34 @@ impl ZoomDownloaderApp {
             if ui.add_sized([140.0, 40.0], load_button).clicked() {
                 self.load_config();
             }
+
+            ui.add_space(12.0);
+
+            // CF014: アーカイブの整合性検証
+            let verify_label = if self.is_verifying {
+                "検証中..."
+            } else {
+                "アーカイブを検証"
+            };
+            let verify_button =
+                egui::Button::new(egui::RichText::new(verify_label).color(TEXT_ON_PRIMARY))
+                    .fill(BTN_LOAD)
+                    .rounding(egui::Rounding::same(8.0));
+            let response = ui
+                .add_enabled_ui(!self.is_verifying, |ui| {
+                    ui.add_sized([160.0, 40.0], verify_button)
+                })
+                .inner
+                .on_hover_text("manifest.json と照合し、欠落・改変・余分なファイルを検出します");
+            if response.clicked() {
+                self.verify_archive();
+            }
         });
 
         ui.add_space(12.0);
 
+        self.render_verify_report(ui);
+
         // 入力検証とバリデーションメッセージ（色付きフレーム）
         if self.client_id.is_empty() {
             egui::Frame::none()
@@ -754,6 +1944,409 @@ impl eframe::App for ZoomDownloaderApp {
 }
 
 impl ZoomDownloaderApp {
+    /// 未完了ジョブの再開・破棄バナーをレンダリングする
+    fn render_unfinished_jobs_banner(&mut self, ui: &mut egui::Ui) {
+        egui::Frame::none()
+            .fill(WARNING_BG)
+            .rounding(egui::Rounding::same(8.0))
+            .stroke(egui::Stroke::new(1.0, WARNING_TEXT))
+            .inner_margin(egui::Margin::same(10.0))
+            .show(ui, |ui| {
+                ui.add(egui::Label::new(
+                    egui::RichText::new(format!(
+                        "前回のセッションで未完了のダウンロードが {} 件あります",
+                        self.unfinished_jobs.len()
+                    ))
+                    .color(WARNING_TEXT),
+                ));
+                ui.add_space(6.0);
+                ui.horizontal(|ui| {
+                    let can_resume = self.access_token.is_some();
+                    let resume_button =
+                        egui::Button::new(egui::RichText::new("再開").color(TEXT_ON_PRIMARY))
+                            .fill(BTN_SAVE)
+                            .rounding(egui::Rounding::same(8.0));
+                    let response = ui
+                        .add_enabled_ui(can_resume, |ui| ui.add_sized([120.0, 32.0], resume_button))
+                        .inner
+                        .on_disabled_hover_text("認証後に再開できます");
+                    if response.clicked() {
+                        self.resume_unfinished_jobs();
+                    }
+
+                    ui.add_space(8.0);
+
+                    let discard_button =
+                        egui::Button::new(egui::RichText::new("破棄").color(TEXT_ON_PRIMARY))
+                            .fill(BTN_CANCEL)
+                            .rounding(egui::Rounding::same(8.0));
+                    if ui.add_sized([120.0, 32.0], discard_button).clicked() {
+                        self.discard_unfinished_jobs();
+                    }
+                });
+            });
+    }
+
+    /// 前回のバッチで失敗したファイルの再試行バナーをレンダリングする
+    fn render_failed_items_banner(&mut self, ui: &mut egui::Ui) {
+        egui::Frame::none()
+            .fill(WARNING_BG)
+            .rounding(egui::Rounding::same(8.0))
+            .stroke(egui::Stroke::new(1.0, WARNING_TEXT))
+            .inner_margin(egui::Margin::same(10.0))
+            .show(ui, |ui| {
+                ui.add(egui::Label::new(
+                    egui::RichText::new(format!(
+                        "前回のダウンロードで取得できなかったファイルが {} 件あります",
+                        self.failed_items.len()
+                    ))
+                    .color(WARNING_TEXT),
+                ));
+                ui.add_space(6.0);
+                let can_retry = self.access_token.is_some();
+                let retry_button = egui::Button::new(
+                    egui::RichText::new("失敗したファイルを再試行").color(TEXT_ON_PRIMARY),
+                )
+                .fill(BTN_SAVE)
+                .rounding(egui::Rounding::same(8.0));
+                let response = ui
+                    .add_enabled_ui(can_retry, |ui| ui.add_sized([200.0, 32.0], retry_button))
+                    .inner
+                    .on_disabled_hover_text("認証後に再試行できます");
+                if response.clicked() {
+                    self.retry_failed_items();
+                }
+            });
+    }
+
+    /// アーカイブ検証の結果を表示する（問題のあるファイルは種別ごとに列挙）
+    fn render_verify_report(&mut self, ui: &mut egui::Ui) {
+        let Some(report) = &self.verify_report else {
+            return;
+        };
+        let (fill, text) = if report.is_clean() {
+            (SUCCESS_BG, SUCCESS_TEXT)
+        } else {
+            (WARNING_BG, WARNING_TEXT)
+        };
+
+        let mut dismissed = false;
+        egui::Frame::none()
+            .fill(fill)
+            .rounding(egui::Rounding::same(8.0))
+            .stroke(egui::Stroke::new(1.0, text))
+            .inner_margin(egui::Margin::same(10.0))
+            .show(ui, |ui| {
+                ui.add(egui::Label::new(
+                    egui::RichText::new(format!("アーカイブ検証: {}", report.summary()))
+                        .color(text),
+                ));
+                let problems = report
+                    .missing
+                    .iter()
+                    .map(|path| format!("欠落: {}", path.display()))
+                    .chain(report.modified.iter().map(|modified| {
+                        format!("改変: {} ({})", modified.path.display(), modified.reason)
+                    }))
+                    .chain(
+                        report
+                            .extra
+                            .iter()
+                            .map(|path| format!("記録なし: {}", path.display())),
+                    );
+                egui::ScrollArea::vertical()
+                    .id_source("verify_report")
+                    .max_height(160.0)
+                    .show(ui, |ui| {
+                        for problem in problems {
+                            ui.add(egui::Label::new(egui::RichText::new(problem).color(text)));
+                        }
+                    });
+                ui.add_space(6.0);
+                if ui.button("閉じる").clicked() {
+                    dismissed = true;
+                }
+            });
+        if dismissed {
+            self.verify_report = None;
+        }
+        ui.add_space(12.0);
+    }
+
+    /// 直近のダウンロードの実行レポートを表示する（失敗・スキップしたファイルを列挙）
+    fn render_run_report(&mut self, ui: &mut egui::Ui) {
+        let Some(report) = &self.run_report else {
+            return;
+        };
+        let (fill, text) = if report.count(FinalState::Failed) == 0 {
+            (SUCCESS_BG, SUCCESS_TEXT)
+        } else {
+            (WARNING_BG, WARNING_TEXT)
+        };
+
+        let mut export = None;
+        let mut dismissed = false;
+        ui.add_space(8.0);
+        egui::Frame::none()
+            .fill(fill)
+            .rounding(egui::Rounding::same(8.0))
+            .stroke(egui::Stroke::new(1.0, text))
+            .inner_margin(egui::Margin::same(10.0))
+            .show(ui, |ui| {
+                ui.add(egui::Label::new(
+                    egui::RichText::new(format!("実行レポート: {}", report.summary())).color(text),
+                ));
+                let problems = report
+                    .entries
+                    .iter()
+                    .filter(|entry| entry.state != FinalState::Completed)
+                    .map(|entry| {
+                        let detail = entry
+                            .error
+                            .clone()
+                            .or_else(|| entry.skip_reason.map(|reason| reason.to_string()))
+                            .unwrap_or_default();
+                        format!("{}: {} {}", entry.state, entry.file.file_name, detail)
+                            .trim_end()
+                            .to_string()
+                    });
+                egui::ScrollArea::vertical()
+                    .id_source("run_report")
+                    .max_height(120.0)
+                    .show(ui, |ui| {
+                        for problem in problems {
+                            ui.add(egui::Label::new(egui::RichText::new(problem).color(text)));
+                        }
+                    });
+                ui.add_space(6.0);
+                ui.horizontal(|ui| {
+                    if ui.button("JSON でエクスポート").clicked() {
+                        export = Some(ReportFormat::Json);
+                    }
+                    if ui.button("CSV でエクスポート").clicked() {
+                        export = Some(ReportFormat::Csv);
+                    }
+                    if ui.button("閉じる").clicked() {
+                        dismissed = true;
+                    }
+                });
+            });
+        if let Some(format) = export {
+            self.export_run_report(format);
+        }
+        if dismissed {
+            self.run_report = None;
+        }
+    }
+
+    /// ゴミ箱への移動（事前確認・確認・結果）を描画する
+    fn render_trash_section(&mut self, ui: &mut egui::Ui) {
+        card_frame().show(ui, |ui| {
+            ui.add(egui::Label::new(
+                egui::RichText::new("クラウド録画をゴミ箱へ移動")
+                    .strong()
+                    .color(TEXT_PRIMARY),
+            ));
+            ui.add(egui::Label::new(
+                egui::RichText::new(
+                    "ダウンロード済みで、サイズと内容を確認できたファイルのみ移動します。",
+                )
+                .size(13.0)
+                .color(TEXT_SECONDARY),
+            ));
+            ui.horizontal(|ui| {
+                egui::ComboBox::from_id_source("trash_granularity")
+                    .selected_text(trash_granularity_label(self.trash_granularity))
+                    .show_ui(ui, |ui| {
+                        for granularity in [TrashGranularity::File, TrashGranularity::Meeting] {
+                            ui.selectable_value(
+                                &mut self.trash_granularity,
+                                granularity,
+                                trash_granularity_label(granularity),
+                            );
+                        }
+                    });
+                if self.is_trashing {
+                    ui.add(egui::Spinner::new().size(16.0));
+                } else if ui
+                    .add_enabled(
+                        !self.selected_recordings.is_empty() && !self.is_downloading,
+                        egui::Button::new("ゴミ箱への移動をプレビュー（ドライラン）"),
+                    )
+                    .clicked()
+                {
+                    self.preview_trash();
+                }
+            });
+
+            if self.trash_plan.is_some() && !self.is_trashing {
+                ui.add_space(6.0);
+                self.render_trash_confirmation(ui);
+            }
+
+            if let Some(report) = &self.trash_report {
+                let (fill, text) = if report.failed() + report.skipped() == 0 {
+                    (SUCCESS_BG, SUCCESS_TEXT)
+                } else {
+                    (WARNING_BG, WARNING_TEXT)
+                };
+                ui.add_space(6.0);
+                egui::Frame::none()
+                    .fill(fill)
+                    .rounding(egui::Rounding::same(8.0))
+                    .inner_margin(egui::Margin::same(8.0))
+                    .show(ui, |ui| {
+                        ui.add(egui::Label::new(
+                            egui::RichText::new(format!(
+                                "移動: {} · 失敗: {} · 中止: {}",
+                                report.succeeded(),
+                                report.failed(),
+                                report.skipped()
+                            ))
+                            .color(text),
+                        ));
+                    });
+            }
+        });
+    }
+
+    /// ゴミ箱へ移動する対象を一覧し、実行するか取り消すかを選択する
+    fn render_trash_confirmation(&mut self, ui: &mut egui::Ui) {
+        let Some(plan) = &self.trash_plan else {
+            return;
+        };
+        let eligible: Vec<String> = plan.eligible().map(|item| item.label()).collect();
+        let blocked: Vec<(String, Vec<String>)> = plan
+            .blocked()
+            .map(|item| (item.label(), item.block_reasons()))
+            .collect();
+
+        let mut confirmed = false;
+        let mut cancelled = false;
+        egui::Frame::none()
+            .fill(WARNING_BG)
+            .rounding(egui::Rounding::same(8.0))
+            .stroke(egui::Stroke::new(1.0, WARNING_TEXT))
+            .inner_margin(egui::Margin::same(10.0))
+            .show(ui, |ui| {
+                ui.add(egui::Label::new(
+                    egui::RichText::new(format!(
+                        "次の {} 件をクラウドのゴミ箱へ移動します（{} 件は移動しません）",
+                        eligible.len(),
+                        blocked.len()
+                    ))
+                    .color(WARNING_TEXT),
+                ));
+                egui::ScrollArea::vertical()
+                    .id_source("trash_plan")
+                    .max_height(160.0)
+                    .show(ui, |ui| {
+                        for label in &eligible {
+                            ui.add(egui::Label::new(
+                                egui::RichText::new(format!("移動する: {}", label))
+                                    .size(13.0)
+                                    .color(WARNING_TEXT),
+                            ));
+                        }
+                        for (label, reasons) in &blocked {
+                            ui.add(egui::Label::new(
+                                egui::RichText::new(format!("移動しない: {}", label))
+                                    .size(13.0)
+                                    .color(ERROR_TEXT),
+                            ));
+                            for reason in reasons {
+                                ui.add(egui::Label::new(
+                                    egui::RichText::new(format!("    {}", reason))
+                                        .size(12.0)
+                                        .color(ERROR_TEXT),
+                                ));
+                            }
+                        }
+                    });
+                ui.add_space(6.0);
+                ui.horizontal(|ui| {
+                    let trash_button = egui::Button::new(
+                        egui::RichText::new(format!("{} 件をゴミ箱へ移動", eligible.len()))
+                            .color(TEXT_ON_PRIMARY),
+                    )
+                    .fill(BTN_CANCEL)
+                    .rounding(egui::Rounding::same(8.0));
+                    if ui.add_enabled(!eligible.is_empty(), trash_button).clicked() {
+                        confirmed = true;
+                    }
+
+                    ui.add_space(8.0);
+
+                    if ui.button("キャンセル").clicked() {
+                        cancelled = true;
+                    }
+                });
+            });
+        if confirmed {
+            self.trash_confirmed();
+        }
+        if cancelled {
+            self.trash_plan = None;
+            self.status_message = "Move to trash cancelled".to_string();
+        }
+    }
+
+    /// 空き容量不足の警告を表示する（このまま開始するか中止するかを選択）
+    fn render_space_warning_banner(&mut self, ui: &mut egui::Ui) {
+        let Some(check) = &self.space_warning else {
+            return;
+        };
+        let mut details = format!(
+            "必要: {} · 空き: {} · 確保: {} · 不足: {}",
+            format_bytes(check.required_bytes),
+            format_bytes(check.available_bytes),
+            format_bytes(check.reserve_bytes),
+            format_bytes(check.shortfall())
+        );
+        if check.unknown_size_files > 0 {
+            details.push_str(&format!(
+                "（サイズ不明の {} 件は含まない）",
+                check.unknown_size_files
+            ));
+        }
+
+        egui::Frame::none()
+            .fill(WARNING_BG)
+            .rounding(egui::Rounding::same(8.0))
+            .stroke(egui::Stroke::new(1.0, WARNING_TEXT))
+            .inner_margin(egui::Margin::same(10.0))
+            .show(ui, |ui| {
+                ui.add(egui::Label::new(
+                    egui::RichText::new("出力先の空き容量が不足しています").color(WARNING_TEXT),
+                ));
+                ui.add(egui::Label::new(
+                    egui::RichText::new(details).size(13.0).color(WARNING_TEXT),
+                ));
+                ui.add_space(6.0);
+                ui.horizontal(|ui| {
+                    let start_button = egui::Button::new(
+                        egui::RichText::new("このまま開始").color(TEXT_ON_PRIMARY),
+                    )
+                    .fill(BTN_PAUSE)
+                    .rounding(egui::Rounding::same(8.0));
+                    if ui.add_sized([120.0, 32.0], start_button).clicked() {
+                        self.begin_download();
+                    }
+
+                    ui.add_space(8.0);
+
+                    let abort_button =
+                        egui::Button::new(egui::RichText::new("中止").color(TEXT_ON_PRIMARY))
+                            .fill(BTN_CANCEL)
+                            .rounding(egui::Rounding::same(8.0));
+                    if ui.add_sized([120.0, 32.0], abort_button).clicked() {
+                        self.space_warning = None;
+                        self.status_message = "Download not started".to_string();
+                    }
+                });
+            });
+    }
+
     /// SC003: 認証画面をレンダリングする
     fn render_auth(&mut self, ui: &mut egui::Ui) {
         ui.add(egui::Label::new(
@@ -935,112 +2528,33 @@ impl ZoomDownloaderApp {
                     }
                 }
             });
+
+            // RL005: 取得対象（自分・アカウント全体・選択したユーザー）
+            ui.add_space(6.0);
+            self.render_scope_picker(ui);
         });
 
+        // RL004: 直近のダウンロードの実行レポート
+        self.render_run_report(ui);
+
         // 録画リスト表示
         if let Some(recordings) = &self.recordings {
-            let meeting_uuids: Vec<String> =
-                recordings.meetings.iter().map(|m| m.uuid.clone()).collect();
-
-            card_frame().show(ui, |ui| {
-                // ヘッダー行: 選択数バッジ + 全選択/全解除
-                ui.horizontal(|ui| {
-                    ui.add(egui::Label::new(
-                        egui::RichText::new("録画一覧").strong().color(TEXT_PRIMARY),
-                    ));
-
-                    // 選択数バッジ
-                    egui::Frame::none()
-                        .fill(PRIMARY)
-                        .rounding(egui::Rounding::same(12.0))
-                        .inner_margin(egui::Margin::symmetric(10.0, 3.0))
-                        .show(ui, |ui| {
-                            ui.add(egui::Label::new(
-                                egui::RichText::new(format!("{}", self.selected_recordings.len()))
-                                    .color(TEXT_ON_PRIMARY)
-                                    .size(13.0),
-                            ));
-                        });
-
-                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                        let deselect_btn =
-                            egui::Button::new("全解除").rounding(egui::Rounding::same(8.0));
-                        if ui.add(deselect_btn).clicked() {
-                            self.selected_recordings.clear();
-                        }
-                        let select_btn =
-                            egui::Button::new("全選択").rounding(egui::Rounding::same(8.0));
-                        if ui.add(select_btn).clicked() {
-                            for uuid in &meeting_uuids {
-                                self.selected_recordings.insert(uuid.clone());
-                            }
-                        }
-                    });
-                });
-
-                ui.add_space(6.0);
-
-                // 録画リスト
-                egui::ScrollArea::vertical()
-                    .max_height(300.0)
-                    .show(ui, |ui| {
-                        for meeting in &recordings.meetings {
-                            ui.horizontal(|ui| {
-                                let mut meeting_selected =
-                                    self.selected_recordings.contains(&meeting.uuid);
-                                if ui
-                                    .checkbox(
-                                        &mut meeting_selected,
-                                        format!("Meeting - {}", meeting.topic),
-                                    )
-                                    .changed()
-                                {
-                                    if meeting_selected {
-                                        self.selected_recordings.insert(meeting.uuid.clone());
-                                    } else {
-                                        self.selected_recordings.remove(&meeting.uuid);
-                                    }
-                                }
-                            });
-
-                            for file in &meeting.recording_files {
-                                ui.horizontal(|ui| {
-                                    ui.add_space(20.0);
-                                    let file_id = format!("{}-{}", meeting.uuid, file.stable_id());
-                                    let mut file_selected =
-                                        self.selected_recordings.contains(&file_id);
-                                    let ext_display = if file.file_extension.is_empty() {
-                                        file.file_type.to_string()
-                                    } else {
-                                        file.file_extension.clone()
-                                    };
-                                    if ui
-                                        .checkbox(
-                                            &mut file_selected,
-                                            format!(
-                                                "{} ({}) - {}MB",
-                                                file.file_type,
-                                                ext_display,
-                                                file.file_size / 1024 / 1024
-                                            ),
-                                        )
-                                        .changed()
-                                    {
-                                        if file_selected {
-                                            self.selected_recordings.insert(file_id);
-                                        } else {
-                                            self.selected_recordings.remove(&file_id);
-                                        }
-                                    }
-                                });
-                            }
-                            ui.add_space(5.0);
-                        }
-                    });
-            });
+            render_recording_list(
+                ui,
+                "録画一覧",
+                recordings,
+                &mut self.selected_recordings,
+                "recordings",
+            );
 
             ui.add_space(8.0);
 
+            // 空き容量不足の警告
+            if self.space_warning.is_some() && !self.is_downloading {
+                self.render_space_warning_banner(ui);
+                ui.add_space(8.0);
+            }
+
             // ダウンロードボタン
             ui.horizontal(|ui| {
                 ui.add(egui::Label::new(
@@ -1067,6 +2581,10 @@ impl ZoomDownloaderApp {
                     }
                 });
             });
+
+            // RL006: ダウンロード済みのクラウド録画をゴミ箱へ移動
+            ui.add_space(8.0);
+            self.render_trash_section(ui);
         } else {
             // 空状態
             card_frame().show(ui, |ui| {
@@ -1104,6 +2622,31 @@ impl ZoomDownloaderApp {
                     .show_percentage(),
             );
 
+            // PR006: 全体統計（件数・バイト数・速度・残り時間）
+            let overall = &self.overall_progress;
+            ui.add(egui::Label::new(
+                egui::RichText::new(format!(
+                    "{}/{} files completed · {} failed · {} active",
+                    overall.completed_tasks,
+                    overall.total_tasks,
+                    overall.failed_tasks,
+                    overall.active_tasks
+                ))
+                .size(13.0)
+                .color(TEXT_SECONDARY),
+            ));
+            ui.add(egui::Label::new(
+                egui::RichText::new(format!(
+                    "{} / {} · {}/s · ETA {}",
+                    format_bytes(overall.downloaded_bytes),
+                    format_bytes(overall.total_bytes),
+                    format_bytes(overall.current_speed as u64),
+                    format_eta(overall.eta)
+                ))
+                .size(13.0)
+                .color(TEXT_SECONDARY),
+            ));
+
             ui.add_space(10.0);
 
             // PR002: 現在ファイル名
@@ -1114,8 +2657,14 @@ impl ZoomDownloaderApp {
                 ));
 
                 // PR003: ファイル進捗バー
+                let current_percentage = self
+                    .file_progress
+                    .iter()
+                    .find(|row| row.file_name == self.current_file)
+                    .map(|row| row.progress.percentage)
+                    .unwrap_or(0.0);
                 ui.add(
-                    egui::ProgressBar::new(self.progress_percentage)
+                    egui::ProgressBar::new(current_percentage as f32)
                         .fill(PROGRESS_FILL)
                         .rounding(egui::Rounding::same(6.0))
                         .desired_height(12.0)
@@ -1123,6 +2672,28 @@ impl ZoomDownloaderApp {
                 );
             }
 
+            // PR009: 空き容量不足による一時停止の通知
+            if let (Some((available_bytes, reserve_bytes)), true) =
+                (self.low_disk_space, self.is_download_paused)
+            {
+                ui.add_space(8.0);
+                egui::Frame::none()
+                    .fill(WARNING_BG)
+                    .rounding(egui::Rounding::same(8.0))
+                    .stroke(egui::Stroke::new(1.0, WARNING_TEXT))
+                    .inner_margin(egui::Margin::same(10.0))
+                    .show(ui, |ui| {
+                        ui.add(egui::Label::new(
+                            egui::RichText::new(format!(
+                                "空き容量が不足したため一時停止しました（空き {} / 確保 {}）。空きを確保してから再開してください",
+                                format_bytes(available_bytes),
+                                format_bytes(reserve_bytes)
+                            ))
+                            .color(WARNING_TEXT),
+                        ));
+                    });
+            }
+
             ui.add_space(12.0);
 
             // PR004 & PR005: 制御ボタン
@@ -1155,25 +2726,121 @@ impl ZoomDownloaderApp {
                     self.cancel_download();
                 }
             });
+
+            // PR008: 帯域上限（実行中に変更可能）
+            if let Some(control) = &self.download_control {
+                let current_limit = match control.bandwidth_limit() {
+                    Some(limit) => format!("{}/s", format_bytes(limit)),
+                    None => "無制限".to_string(),
+                };
+                ui.add_space(8.0);
+                ui.horizontal(|ui| {
+                    ui.add(egui::Label::new(
+                        egui::RichText::new(format!("Bandwidth: {}", current_limit))
+                            .color(TEXT_SECONDARY),
+                    ));
+                    ui.add_sized(
+                        [80.0, 26.0],
+                        egui::TextEdit::singleline(&mut self.live_bandwidth_input)
+                            .hint_text("無制限"),
+                    );
+                    ui.label("MB/s");
+                    if ui.button("適用").clicked() {
+                        self.apply_live_bandwidth_limit();
+                    }
+                });
+            }
         });
 
+        // ファイル別進捗カード
+        let mut priority_change = None;
+        if !self.file_progress.is_empty() {
+            card_frame().show(ui, |ui| {
+                ui.add(egui::Label::new(
+                    egui::RichText::new("Files").strong().color(TEXT_PRIMARY),
+                ));
+                ui.add_space(4.0);
+                egui::ScrollArea::vertical()
+                    .id_source("file_progress_rows")
+                    .max_height(240.0)
+                    .show(ui, |ui| {
+                        let can_reorder = self.download_control.is_some();
+                        for row in &self.file_progress {
+                            ui.horizontal(|ui| {
+                                ui.add_sized(
+                                    [240.0, 18.0],
+                                    egui::Label::new(
+                                        egui::RichText::new(&row.file_name)
+                                            .size(13.0)
+                                            .color(TEXT_PRIMARY),
+                                    )
+                                    .truncate(),
+                                );
+                                ui.add(
+                                    egui::ProgressBar::new(row.progress.percentage as f32)
+                                        .fill(PROGRESS_FILL)
+                                        .rounding(egui::Rounding::same(4.0))
+                                        .desired_width(160.0)
+                                        .desired_height(8.0),
+                                );
+                                ui.add(egui::Label::new(
+                                    egui::RichText::new(file_row_status(row))
+                                        .size(12.0)
+                                        .color(TEXT_SECONDARY),
+                                ));
+
+                                // PR010: 待機中タスクの優先度変更
+                                if can_reorder && row.state == TaskState::Pending {
+                                    if ui
+                                        .small_button("▲")
+                                        .on_hover_text("優先度を上げる")
+                                        .clicked()
+                                    {
+                                        priority_change =
+                                            Some((row.task_id.clone(), row.priority.raised()));
+                                    }
+                                    if ui
+                                        .small_button("▼")
+                                        .on_hover_text("優先度を下げる")
+                                        .clicked()
+                                    {
+                                        priority_change =
+                                            Some((row.task_id.clone(), row.priority.lowered()));
+                                    }
+                                    if ui.small_button("後回し").clicked() {
+                                        priority_change =
+                                            Some((row.task_id.clone(), TaskPriority::Deferred));
+                                    }
+                                }
+                            });
+                        }
+                    });
+            });
+        }
+        if let Some((task_id, priority)) = priority_change {
+            self.change_task_priority(&task_id, priority);
+        }
+
         // ログカード
         card_frame().show(ui, |ui| {
-            ui.add(egui::Label::new(
+            egui::CollapsingHeader::new(
                 egui::RichText::new("Download Log")
                     .strong()
                     .color(TEXT_PRIMARY),
-            ));
-            ui.add_space(4.0);
-            egui::ScrollArea::vertical()
-                .max_height(200.0)
-                .show(ui, |ui| {
-                    for msg in &self.download_progress {
-                        ui.add(egui::Label::new(
-                            egui::RichText::new(msg).size(13.0).color(TEXT_SECONDARY),
-                        ));
-                    }
-                });
+            )
+            .default_open(false)
+            .show(ui, |ui| {
+                egui::ScrollArea::vertical()
+                    .id_source("download_log")
+                    .max_height(200.0)
+                    .show(ui, |ui| {
+                        for msg in &self.download_progress {
+                            ui.add(egui::Label::new(
+                                egui::RichText::new(msg).size(13.0).color(TEXT_SECONDARY),
+                            ));
+                        }
+                    });
+            });
         });
 
         ui.add_space(6.0);
@@ -1186,6 +2853,198 @@ impl ZoomDownloaderApp {
         ));
     }
 
+    /// SC007: ゴミ箱画面をレンダリングする
+    fn render_trash(&mut self, ui: &mut egui::Ui) {
+        ui.add(egui::Label::new(
+            egui::RichText::new("ゴミ箱")
+                .size(26.0)
+                .strong()
+                .color(TEXT_PRIMARY),
+        ));
+        ui.add_space(8.0);
+
+        card_frame().show(ui, |ui| {
+            ui.add(egui::Label::new(
+                egui::RichText::new(format!(
+                    "期間 {} 〜 {} · 対象は録画リストの設定と共通です",
+                    self.from_date, self.to_date
+                ))
+                .color(TEXT_SECONDARY),
+            ));
+            ui.horizontal(|ui| {
+                // TR001: ゴミ箱の取得ボタン
+                if self.is_loading_trash {
+                    ui.add(egui::Spinner::new().size(20.0));
+                } else {
+                    let load_btn = egui::Button::new(
+                        egui::RichText::new("ゴミ箱を表示").color(TEXT_ON_PRIMARY),
+                    )
+                    .fill(PRIMARY)
+                    .rounding(egui::Rounding::same(8.0));
+                    if ui.add_sized([120.0, 36.0], load_btn).clicked() {
+                        self.fetch_trashed_recordings();
+                    }
+                }
+            });
+        });
+
+        // TR002: ゴミ箱の録画一覧
+        let Some(recordings) = &self.trashed_recordings else {
+            card_frame().show(ui, |ui| {
+                ui.add(egui::Label::new(
+                    egui::RichText::new(
+                        "ゴミ箱の録画を読み込むには「ゴミ箱を表示」をクリックしてください。",
+                    )
+                    .color(TEXT_SECONDARY),
+                ));
+            });
+            return;
+        };
+        render_recording_list(
+            ui,
+            "ゴミ箱の録画",
+            recordings,
+            &mut self.selected_trashed,
+            "trashed_recordings",
+        );
+
+        ui.add_space(8.0);
+
+        // TR003: 復元・完全削除（確認後に実行）
+        if self.pending_trash_action.is_some() && !self.is_loading_trash {
+            self.render_trash_action_confirmation(ui);
+            ui.add_space(8.0);
+        } else {
+            ui.horizontal(|ui| {
+                let enabled = !self.selected_trashed.is_empty() && !self.is_loading_trash;
+                let recover_btn =
+                    egui::Button::new(egui::RichText::new("復元").color(TEXT_ON_PRIMARY))
+                        .fill(BTN_SAVE)
+                        .rounding(egui::Rounding::same(8.0));
+                if ui
+                    .add_enabled(enabled, recover_btn)
+                    .on_hover_text("選択した録画をクラウドの録画一覧に戻します")
+                    .clicked()
+                {
+                    self.request_trash_action(TrashedAction::Recover);
+                }
+                let delete_btn =
+                    egui::Button::new(egui::RichText::new("完全に削除").color(TEXT_ON_PRIMARY))
+                        .fill(BTN_CANCEL)
+                        .rounding(egui::Rounding::same(8.0));
+                if ui
+                    .add_enabled(enabled, delete_btn)
+                    .on_hover_text("選択した録画を Zoom から削除します（元に戻せません）")
+                    .clicked()
+                {
+                    self.request_trash_action(TrashedAction::Delete);
+                }
+            });
+        }
+
+        if let Some((action, report)) = &self.trash_action_report {
+            let (fill, text) = if report.failed() == 0 {
+                (SUCCESS_BG, SUCCESS_TEXT)
+            } else {
+                (WARNING_BG, WARNING_TEXT)
+            };
+            ui.add_space(6.0);
+            egui::Frame::none()
+                .fill(fill)
+                .rounding(egui::Rounding::same(8.0))
+                .inner_margin(egui::Margin::same(8.0))
+                .show(ui, |ui| {
+                    ui.add(egui::Label::new(
+                        egui::RichText::new(format!(
+                            "{}: 成功 {} · 失敗 {}",
+                            trash_action_label(*action),
+                            report.succeeded(),
+                            report.failed()
+                        ))
+                        .color(text),
+                    ));
+                });
+        }
+    }
+
+    /// 復元・完全削除する対象を一覧し、実行するか取り消すかを選択する
+    fn render_trash_action_confirmation(&mut self, ui: &mut egui::Ui) {
+        let Some((action, targets)) = &self.pending_trash_action else {
+            return;
+        };
+        let action = *action;
+        let labels: Vec<String> = targets.iter().map(|target| target.label()).collect();
+        let (fill, text, heading) = match action {
+            TrashedAction::Recover => (
+                WARNING_BG,
+                WARNING_TEXT,
+                format!("次の {} 件をゴミ箱から復元します", labels.len()),
+            ),
+            TrashedAction::Delete => (
+                ERROR_BG,
+                ERROR_TEXT,
+                format!(
+                    "次の {} 件を完全に削除します。削除した録画は元に戻せません。",
+                    labels.len()
+                ),
+            ),
+        };
+
+        let mut confirmed = false;
+        let mut cancelled = false;
+        egui::Frame::none()
+            .fill(fill)
+            .rounding(egui::Rounding::same(8.0))
+            .stroke(egui::Stroke::new(1.0, text))
+            .inner_margin(egui::Margin::same(10.0))
+            .show(ui, |ui| {
+                ui.add(egui::Label::new(egui::RichText::new(heading).color(text)));
+                egui::ScrollArea::vertical()
+                    .id_source("trash_action_targets")
+                    .max_height(160.0)
+                    .show(ui, |ui| {
+                        for label in &labels {
+                            ui.add(egui::Label::new(
+                                egui::RichText::new(label).size(13.0).color(text),
+                            ));
+                        }
+                    });
+                ui.add_space(6.0);
+                ui.horizontal(|ui| {
+                    let confirm_button = egui::Button::new(
+                        egui::RichText::new(format!(
+                            "{} 件を{}",
+                            labels.len(),
+                            trash_action_label(action)
+                        ))
+                        .color(TEXT_ON_PRIMARY),
+                    )
+                    .fill(if action == TrashedAction::Delete {
+                        BTN_CANCEL
+                    } else {
+                        BTN_SAVE
+                    })
+                    .rounding(egui::Rounding::same(8.0));
+                    if ui.add(confirm_button).clicked() {
+                        confirmed = true;
+                    }
+
+                    ui.add_space(8.0);
+
+                    if ui.button("キャンセル").clicked() {
+                        cancelled = true;
+                    }
+                });
+            });
+        if confirmed {
+            self.trash_action_confirmed();
+        }
+        if cancelled {
+            self.pending_trash_action = None;
+            self.status_message = format!("Trash {} cancelled", action);
+        }
+    }
+
     /// SC006: エラー表示画面をレンダリングする
     fn render_error(&mut self, ui: &mut egui::Ui) {
         ui.add(egui::Label::new(
@@ -1323,30 +3182,129 @@ impl ZoomDownloaderApp {
                     }
                 }
             });
-        });
+        });
+    }
+
+    /// 取得対象の選択欄を描画する
+    fn render_scope_picker(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            ui.add(egui::Label::new(
+                egui::RichText::new("対象").color(TEXT_SECONDARY),
+            ));
+            ui.radio_value(&mut self.scope_mode, RecordingScopeMode::Me, "自分");
+            ui.radio_value(
+                &mut self.scope_mode,
+                RecordingScopeMode::AllUsers,
+                "全ユーザー",
+            );
+            ui.radio_value(
+                &mut self.scope_mode,
+                RecordingScopeMode::SelectedUsers,
+                "選択したユーザー",
+            );
+            ui.separator();
+            ui.checkbox(
+                &mut self.download_settings.per_host_subfolders,
+                "ホスト別フォルダーに保存",
+            );
+        });
+
+        if self.scope_mode != RecordingScopeMode::SelectedUsers {
+            return;
+        }
+
+        ui.horizontal(|ui| {
+            if self.is_loading_users {
+                ui.add(egui::Spinner::new().size(16.0));
+            } else if ui.button("ユーザー一覧を取得").clicked() {
+                self.load_account_users();
+            }
+            ui.add(egui::Label::new(
+                egui::RichText::new(format!(
+                    "{} / {} 人を選択",
+                    self.selected_users.len(),
+                    self.account_users.len()
+                ))
+                .color(TEXT_SECONDARY),
+            ));
+            if !self.account_users.is_empty() {
+                if ui.button("全選択").clicked() {
+                    self.selected_users = self
+                        .account_users
+                        .iter()
+                        .map(|user| user.id.clone())
+                        .collect();
+                }
+                if ui.button("全解除").clicked() {
+                    self.selected_users.clear();
+                }
+            }
+        });
+
+        egui::ScrollArea::vertical()
+            .id_source("account_users")
+            .max_height(160.0)
+            .show(ui, |ui| {
+                for user in &self.account_users {
+                    let mut selected = self.selected_users.contains(&user.id);
+                    let mut label = format!("{} <{}>", user.label(), user.email);
+                    if user.status == "inactive" {
+                        label.push_str(" (無効化済み)");
+                    }
+                    if ui.checkbox(&mut selected, label).changed() {
+                        if selected {
+                            self.selected_users.insert(user.id.clone());
+                        } else {
+                            self.selected_users.remove(&user.id);
+                        }
+                    }
+                }
+            });
+    }
+
+    /// 選択中の取得対象
+    fn recording_scope(&self) -> RecordingScope {
+        match self.scope_mode {
+            RecordingScopeMode::Me => RecordingScope::Me,
+            RecordingScopeMode::AllUsers => RecordingScope::AllUsers,
+            RecordingScopeMode::SelectedUsers => RecordingScope::Users(
+                self.account_users
+                    .iter()
+                    .filter(|user| self.selected_users.contains(&user.id))
+                    .cloned()
+                    .collect(),
+            ),
+        }
+    }
+
+    /// アカウントのユーザー一覧を取得する（サービス経由）
+    fn load_account_users(&mut self) {
+        let Some(access_token) = self.access_token.clone() else {
+            return;
+        };
+        self.is_loading_users = true;
+        self.status_message = "ユーザー一覧を取得中...".to_string();
+
+        let endpoints = self.endpoints.clone();
+        let sender = self.sender.clone();
+        let recording_service = Arc::clone(&self.services.recording_service);
+        thread::spawn(
+            move || match recording_service.list_users(&access_token, &endpoints) {
+                Ok(users) => {
+                    let _ = sender.send(AppMessage::UsersLoaded(users));
+                }
+                Err(e) => {
+                    let _ = sender.send(AppMessage::Error(format!("Failed to fetch users: {}", e)));
+                }
+            },
+        );
     }
 
     /// 録画データを取得する（サービス経由）
     fn fetch_recordings(&mut self) {
-        // 日付バリデーション
-        let from_parsed = chrono::NaiveDate::parse_from_str(&self.from_date, "%Y-%m-%d");
-        let to_parsed = chrono::NaiveDate::parse_from_str(&self.to_date, "%Y-%m-%d");
-
-        match (from_parsed, to_parsed) {
-            (Err(_), _) => {
-                self.status_message = "エラー: 開始日の形式が不正です (YYYY-MM-DD)".to_string();
-                return;
-            }
-            (_, Err(_)) => {
-                self.status_message = "エラー: 終了日の形式が不正です (YYYY-MM-DD)".to_string();
-                return;
-            }
-            (Ok(from), Ok(to)) if from > to => {
-                self.status_message = "エラー: 開始日は終了日以前にしてください".to_string();
-                return;
-            }
-            _ => {} // OK
-        }
+        let Some(scope) = self.validated_search_scope() else {
+            return;
+        };
 
         if let Some(access_token) = &self.access_token {
             self.is_searching = true;
@@ -1355,15 +3313,17 @@ impl ZoomDownloaderApp {
             let access_token = access_token.clone();
             let from_date = self.from_date.clone();
             let to_date = self.to_date.clone();
+            let endpoints = self.endpoints.clone();
             let sender = self.sender.clone();
             let recording_service = Arc::clone(&self.services.recording_service);
 
             thread::spawn(move || {
                 match recording_service.get_recordings(
                     &access_token,
-                    "me",
+                    &scope,
                     &from_date,
                     &to_date,
+                    &endpoints,
                     sender.clone(),
                 ) {
                     Ok(recordings) => {
@@ -1380,12 +3340,167 @@ impl ZoomDownloaderApp {
         }
     }
 
+    /// 検索期間と取得対象を検証する（不正な場合はステータスに理由を表示して None）
+    fn validated_search_scope(&mut self) -> Option<RecordingScope> {
+        // 日付バリデーション
+        let from_parsed = chrono::NaiveDate::parse_from_str(&self.from_date, "%Y-%m-%d");
+        let to_parsed = chrono::NaiveDate::parse_from_str(&self.to_date, "%Y-%m-%d");
+
+        match (from_parsed, to_parsed) {
+            (Err(_), _) => {
+                self.status_message = "エラー: 開始日の形式が不正です (YYYY-MM-DD)".to_string();
+                return None;
+            }
+            (_, Err(_)) => {
+                self.status_message = "エラー: 終了日の形式が不正です (YYYY-MM-DD)".to_string();
+                return None;
+            }
+            (Ok(from), Ok(to)) if from > to => {
+                self.status_message = "エラー: 開始日は終了日以前にしてください".to_string();
+                return None;
+            }
+            _ => {} // OK
+        }
+
+        let scope = self.recording_scope();
+        if scope == RecordingScope::Users(Vec::new()) {
+            self.status_message = "エラー: 対象のユーザーを選択してください".to_string();
+            return None;
+        }
+        Some(scope)
+    }
+
+    /// ゴミ箱にある録画を取得する（サービス経由、期間と取得対象は録画リストと共通）
+    fn fetch_trashed_recordings(&mut self) {
+        let Some(access_token) = self.access_token.clone() else {
+            return;
+        };
+        let Some(scope) = self.validated_search_scope() else {
+            return;
+        };
+
+        self.is_loading_trash = true;
+        self.pending_trash_action = None;
+        self.trash_action_report = None;
+        self.status_message = "ゴミ箱を取得中...".to_string();
+
+        let from_date = self.from_date.clone();
+        let to_date = self.to_date.clone();
+        let endpoints = self.endpoints.clone();
+        let sender = self.sender.clone();
+        let recording_service = Arc::clone(&self.services.recording_service);
+
+        thread::spawn(move || {
+            let message = match recording_service.get_trashed_recordings(
+                &access_token,
+                &scope,
+                &from_date,
+                &to_date,
+                &endpoints,
+            ) {
+                Ok(recordings) => AppMessage::TrashedRecordingsLoaded(recordings),
+                Err(e) => AppMessage::Error(format!("Failed to fetch trashed recordings: {}", e)),
+            };
+            let _ = sender.send(message);
+        });
+    }
+
+    /// ゴミ箱で選択中の録画を対象に、復元・完全削除の確認を表示する
+    fn request_trash_action(&mut self, action: TrashedAction) {
+        let Some(recordings) = &self.trashed_recordings else {
+            return;
+        };
+        let selected: Vec<String> = self.selected_trashed.iter().cloned().collect();
+        let targets = trash::trashed_targets(recordings, &selected);
+        if targets.is_empty() {
+            self.status_message = "エラー: 対象の録画を選択してください".to_string();
+            return;
+        }
+        self.trash_action_report = None;
+        self.pending_trash_action = Some((action, targets));
+    }
+
+    /// 確認済みの復元・完全削除を実行する（サービス経由）
+    fn trash_action_confirmed(&mut self) {
+        let Some(access_token) = self.access_token.clone() else {
+            return;
+        };
+        let Some((action, targets)) = self.pending_trash_action.take() else {
+            return;
+        };
+
+        self.is_loading_trash = true;
+        self.status_message = match action {
+            TrashedAction::Recover => "録画を復元中...".to_string(),
+            TrashedAction::Delete => "録画を完全に削除中...".to_string(),
+        };
+
+        let endpoints = self.endpoints.clone();
+        let sender = self.sender.clone();
+        let recording_service = Arc::clone(&self.services.recording_service);
+
+        thread::spawn(move || {
+            let result = match action {
+                TrashedAction::Recover => {
+                    recording_service.recover_recordings(&access_token, &targets, &endpoints)
+                }
+                TrashedAction::Delete => recording_service.delete_recordings_permanently(
+                    &access_token,
+                    &targets,
+                    &endpoints,
+                ),
+            };
+            let message = match result {
+                Ok(report) => AppMessage::TrashActionCompleted(action, report),
+                Err(e) => AppMessage::Error(format!("Trash {} error: {}", action, e)),
+            };
+            let _ = sender.send(message);
+        });
+    }
+
+    /// 復元・完全削除に成功した録画をゴミ箱の一覧と選択から取り除く
+    fn remove_processed_trashed(&mut self, report: &TrashReport) {
+        let Some(recordings) = &mut self.trashed_recordings else {
+            return;
+        };
+        for record in &report.records {
+            if record.result != TrashResult::Succeeded {
+                continue;
+            }
+            match &record.recording_id {
+                Some(recording_id) => {
+                    for meeting in &mut recordings.meetings {
+                        if meeting.uuid == record.meeting_uuid {
+                            meeting
+                                .recording_files
+                                .retain(|file| &file.id != recording_id);
+                        }
+                    }
+                    self.selected_trashed
+                        .remove(&format!("{}-{}", record.meeting_uuid, recording_id));
+                }
+                None => {
+                    recordings
+                        .meetings
+                        .retain(|meeting| meeting.uuid != record.meeting_uuid);
+                    self.selected_trashed.remove(&record.meeting_uuid);
+                }
+            }
+        }
+        recordings
+            .meetings
+            .retain(|meeting| !meeting.recording_files.is_empty());
+    }
+
     /// 設定ファイルを読み込み、GUI状態を更新する（サービス経由）
     fn load_config(&mut self) {
         match self.services.config_service.load_config("config.toml") {
             Ok(config) => {
                 self.client_id = config.client_id;
                 self.client_secret = config.client_secret;
+                self.download_settings = config.download;
+                self.endpoints = config.endpoints;
+                self.sync_bandwidth_inputs();
                 self.config_loaded = true;
                 self.status_message = "Configuration loaded".to_string();
             }
@@ -1400,12 +3515,83 @@ impl ZoomDownloaderApp {
         }
     }
 
+    /// 帯域設定を入力欄へ反映する
+    fn sync_bandwidth_inputs(&mut self) {
+        self.bandwidth_limit_input = format_mbps(self.download_settings.max_bytes_per_sec);
+        self.bandwidth_schedule_inputs = self
+            .download_settings
+            .bandwidth_schedule
+            .iter()
+            .map(|window| BandwidthWindowInput {
+                start: window.start.format("%H:%M").to_string(),
+                end: window.end.format("%H:%M").to_string(),
+                limit: format_mbps(window.max_bytes_per_sec),
+            })
+            .collect();
+    }
+
+    /// 帯域設定の入力欄を検証し、ダウンロード設定へ反映する
+    ///
+    /// # 事後条件
+    /// - 入力が不正な場合は設定を変更せずにエラーメッセージを返す
+    fn apply_bandwidth_inputs(&mut self) -> Result<(), String> {
+        let max_bytes_per_sec = parse_mbps(&self.bandwidth_limit_input)?;
+        let bandwidth_schedule = self
+            .bandwidth_schedule_inputs
+            .iter()
+            .map(|input| {
+                Ok(BandwidthWindow {
+                    start: parse_time(&input.start)?,
+                    end: parse_time(&input.end)?,
+                    max_bytes_per_sec: parse_mbps(&input.limit)?,
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        self.download_settings.max_bytes_per_sec = max_bytes_per_sec;
+        self.download_settings.bandwidth_schedule = bandwidth_schedule;
+        Ok(())
+    }
+
+    /// 実行中のダウンロードに帯域上限を適用する
+    fn apply_live_bandwidth_limit(&mut self) {
+        let Some(control) = &self.download_control else {
+            return;
+        };
+
+        match parse_mbps(&self.live_bandwidth_input) {
+            Ok(limit) => {
+                control.set_bandwidth_limit(limit);
+                let message = match limit {
+                    Some(_) => format!("Bandwidth limit set to {} MB/s", format_mbps(limit)),
+                    None => "Bandwidth limit removed".to_string(),
+                };
+                self.status_message = message.clone();
+                self.add_log_entry(LogLevel::Info, message, None);
+            }
+            Err(e) => {
+                self.status_message = e;
+            }
+        }
+    }
+
     /// 現在のGUI設定をファイルに保存する（サービス経由）
     fn save_config(&mut self) {
+        if let Err(e) = self.apply_bandwidth_inputs() {
+            self.status_message = format!("Failed to save configuration: {}", e);
+            return;
+        }
+        if let Err(e) = self.endpoints.validate() {
+            self.status_message = format!("Failed to save configuration: {}", e);
+            return;
+        }
+
         let config = Config {
             client_id: self.client_id.clone(),
             client_secret: self.client_secret.clone(),
             redirect_uri: Some("http://localhost:8080/callback".to_string()),
+            endpoints: self.endpoints.clone(),
+            download: self.download_settings.clone(),
         };
 
         match self
@@ -1437,11 +3623,12 @@ impl ZoomDownloaderApp {
     fn start_authentication(&mut self) {
         let client_id = self.client_id.clone();
         let client_secret = self.client_secret.clone();
+        let endpoints = self.endpoints.clone();
         let sender = self.sender.clone();
         let auth_service = Arc::clone(&self.services.auth_service);
 
-        thread::spawn(
-            move || match auth_service.generate_auth_url(&client_id, &client_secret) {
+        thread::spawn(move || {
+            match auth_service.generate_auth_url(&client_id, &client_secret, &endpoints) {
                 Ok(url) => {
                     let _ = sender.send(AppMessage::AuthUrlGenerated(url));
                 }
@@ -1451,8 +3638,8 @@ impl ZoomDownloaderApp {
                         e
                     )));
                 }
-            },
-        );
+            }
+        });
     }
 
     /// 認証完了（サービス経由）
@@ -1460,11 +3647,17 @@ impl ZoomDownloaderApp {
         let client_id = self.client_id.clone();
         let client_secret = self.client_secret.clone();
         let auth_code = self.auth_code.clone();
+        let endpoints = self.endpoints.clone();
         let sender = self.sender.clone();
         let auth_service = Arc::clone(&self.services.auth_service);
 
         thread::spawn(move || {
-            match auth_service.exchange_code_for_token(&client_id, &client_secret, &auth_code) {
+            match auth_service.exchange_code_for_token(
+                &client_id,
+                &client_secret,
+                &auth_code,
+                &endpoints,
+            ) {
                 Ok(token) => {
                     let _ = sender.send(AppMessage::AuthComplete(token));
                 }
@@ -1477,31 +3670,95 @@ impl ZoomDownloaderApp {
     }
 
     /// ダウンロード開始（サービス経由）
+    ///
+    /// 開始前に出力先の空き容量を確認し、不足する場合は警告を表示して開始を保留する
     fn start_download(&mut self) {
-        if let (Some(access_token), Some(recordings)) = (&self.access_token, &self.recordings) {
+        if self.access_token.is_none() || self.recordings.is_none() {
+            return;
+        }
+        if let Err(e) = self.apply_bandwidth_inputs() {
+            self.status_message = e;
+            return;
+        }
+
+        let options = DownloadOptions {
+            output_dir: self.output_dir.clone(),
+            settings: self.download_settings.clone(),
+            endpoints: self.endpoints.clone(),
+        };
+        let selected: Vec<String> = self.selected_recordings.iter().cloned().collect();
+        let preflight = match &self.recordings {
+            Some(recordings) => self
+                .services
+                .download_service
+                .preflight_disk_space(recordings, &selected, &options),
+            None => return,
+        };
+        match preflight {
+            Ok(check) if !check.is_sufficient() => {
+                self.status_message = format!(
+                    "Not enough disk space: {} more needed",
+                    format_bytes(check.shortfall())
+                );
+                self.add_log_entry(
+                    LogLevel::Warning,
+                    "Disk space check failed".to_string(),
+                    Some(format!(
+                        "required {}, free {}, reserve {}",
+                        format_bytes(check.required_bytes),
+                        format_bytes(check.available_bytes),
+                        format_bytes(check.reserve_bytes)
+                    )),
+                );
+                self.space_warning = Some(check);
+                return;
+            }
+            Ok(_) => {}
+            Err(e) => {
+                // 空き容量を取得できない環境でもダウンロード自体は行う
+                self.add_log_entry(
+                    LogLevel::Warning,
+                    "Disk space check skipped".to_string(),
+                    Some(e.to_string()),
+                );
+            }
+        }
+
+        self.begin_download();
+    }
+
+    /// 空き容量の確認を行わずにダウンロードを開始する
+    fn begin_download(&mut self) {
+        self.space_warning = None;
+        if let (Some(access_token), Some(recordings)) =
+            (self.access_token.clone(), self.recordings.clone())
+        {
             self.is_downloading = true;
-            self.download_progress.clear();
+            self.is_download_paused = false;
+            self.reset_progress_view();
 
-            let access_token = access_token.clone();
-            let recordings = recordings.clone();
-            let output_dir = self.output_dir.clone();
+            let control = DownloadControl::new();
+            self.download_control = Some(control.clone());
+
+            let options = DownloadOptions {
+                output_dir: self.output_dir.clone(),
+                settings: self.download_settings.clone(),
+                endpoints: self.endpoints.clone(),
+            };
             let selected: Vec<String> = self.selected_recordings.iter().cloned().collect();
             let sender = self.sender.clone();
             let download_service = Arc::clone(&self.services.download_service);
 
             thread::spawn(move || {
-                match download_service.download_files(
+                let result = download_service.download_files(
                     &access_token,
                     &recordings,
                     &selected,
-                    &output_dir,
+                    &options,
+                    control,
                     sender.clone(),
-                ) {
-                    Ok(_) => {}
-                    Err(e) => {
-                        let _ = sender.send(AppMessage::Error(format!("Download error: {}", e)));
-                    }
-                }
+                );
+                Self::report_download_outcome(result, &sender);
             });
         }
     }
@@ -1511,21 +3768,16 @@ impl ZoomDownloaderApp {
 pub(crate) async fn generate_auth_url_async(
     client_id: &str,
     client_secret: &str,
+    endpoints: &EndpointProfile,
 ) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
-    use oauth2::{
-        basic::BasicClient, AuthUrl, ClientId, ClientSecret, CsrfToken, PkceCodeChallenge,
-        RedirectUrl, Scope, TokenUrl,
-    };
-
-    let oauth_client = BasicClient::new(
-        ClientId::new(client_id.to_string()),
-        Some(ClientSecret::new(client_secret.to_string())),
-        AuthUrl::new("https://zoom.us/oauth/authorize".to_string())?,
-        Some(TokenUrl::new("https://zoom.us/oauth/token".to_string())?),
-    )
-    .set_redirect_uri(RedirectUrl::new(
-        "http://localhost:8080/callback".to_string(),
-    )?);
+    use oauth2::{CsrfToken, PkceCodeChallenge, Scope};
+
+    let oauth_client = build_oauth_client(
+        client_id,
+        client_secret,
+        &OAuthConfig::default().redirect_uri,
+        endpoints,
+    )?;
 
     let (pkce_challenge, _pkce_verifier) = PkceCodeChallenge::new_random_sha256();
 
@@ -1533,6 +3785,8 @@ pub(crate) async fn generate_auth_url_async(
         .authorize_url(CsrfToken::new_random)
         .add_scope(Scope::new("recording:read".to_string()))
         .add_scope(Scope::new("user:read".to_string()))
+        // ダウンロード済み録画のゴミ箱への移動に必要
+        .add_scope(Scope::new("recording:write".to_string()))
         .set_pkce_challenge(pkce_challenge)
         .url();
 
@@ -1544,21 +3798,16 @@ pub(crate) async fn exchange_code_for_token_async(
     client_id: &str,
     client_secret: &str,
     auth_code: &str,
-) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
-    use oauth2::{
-        basic::BasicClient, AuthUrl, AuthorizationCode, ClientId, ClientSecret, RedirectUrl,
-        TokenResponse, TokenUrl,
-    };
-
-    let oauth_client = BasicClient::new(
-        ClientId::new(client_id.to_string()),
-        Some(ClientSecret::new(client_secret.to_string())),
-        AuthUrl::new("https://zoom.us/oauth/authorize".to_string())?,
-        Some(TokenUrl::new("https://zoom.us/oauth/token".to_string())?),
-    )
-    .set_redirect_uri(RedirectUrl::new(
-        "http://localhost:8080/callback".to_string(),
-    )?);
+    endpoints: &EndpointProfile,
+) -> Result<AuthToken, Box<dyn std::error::Error + Send + Sync>> {
+    use oauth2::{AuthorizationCode, TokenResponse};
+
+    let oauth_client = build_oauth_client(
+        client_id,
+        client_secret,
+        &OAuthConfig::default().redirect_uri,
+        endpoints,
+    )?;
 
     let (_, pkce_verifier) = oauth2::PkceCodeChallenge::new_random_sha256();
 
@@ -1568,7 +3817,19 @@ pub(crate) async fn exchange_code_for_token_async(
         .request_async(oauth2::reqwest::async_http_client)
         .await?;
 
-    Ok(token_result.access_token().secret().to_string())
+    // リフレッシュトークンと有効期限も保持し、長時間のバッチ中に更新できるようにする
+    let expires_in = token_result
+        .expires_in()
+        .map_or(3600, |duration| duration.as_secs() as i64);
+    Ok(AuthToken {
+        access_token: token_result.access_token().secret().to_string(),
+        token_type: "Bearer".to_string(),
+        expires_at: chrono::Utc::now() + chrono::Duration::seconds(expires_in),
+        refresh_token: token_result
+            .refresh_token()
+            .map(|token| token.secret().to_string()),
+        scopes: OAuthConfig::default().scopes,
+    })
 }
 
 // テスト用アクセサ・ファクトリ
@@ -1584,6 +3845,11 @@ impl ZoomDownloaderApp {
             to_date: String::new(),
             output_dir: String::new(),
             auth_code: String::new(),
+            download_settings: DownloadSettings::default(),
+            endpoints: EndpointProfile::default(),
+            bandwidth_limit_input: String::new(),
+            bandwidth_schedule_inputs: Vec::new(),
+            live_bandwidth_input: String::new(),
             config_loaded: false,
             auth_url: None,
             is_authenticating: false,
@@ -1591,13 +3857,36 @@ impl ZoomDownloaderApp {
             is_searching: false,
             is_download_paused: false,
             download_can_resume: false,
+            download_control: None,
+            unfinished_jobs: Vec::new(),
+            failed_items: Vec::new(),
+            space_warning: None,
+            low_disk_space: None,
+            is_verifying: false,
+            verify_report: None,
+            run_report: None,
             access_token: None,
             recordings: None,
             selected_recordings: std::collections::HashSet::new(),
+            scope_mode: RecordingScopeMode::default(),
+            account_users: Vec::new(),
+            selected_users: std::collections::HashSet::new(),
+            is_loading_users: false,
+            trash_granularity: TrashGranularity::default(),
+            trash_plan: None,
+            is_trashing: false,
+            trash_report: None,
+            trashed_recordings: None,
+            selected_trashed: std::collections::HashSet::new(),
+            is_loading_trash: false,
+            pending_trash_action: None,
+            trash_action_report: None,
             status_message: "Ready".to_string(),
             download_progress: Vec::new(),
             current_file: String::new(),
             progress_percentage: 0.0,
+            overall_progress: OverallProgress::default(),
+            file_progress: Vec::new(),
             error_message: String::new(),
             error_details: String::new(),
             log_entries: Vec::new(),
@@ -1631,6 +3920,126 @@ impl ZoomDownloaderApp {
         self.is_download_paused
     }
 
+    pub fn change_task_priority_for_test(&mut self, task_id: &str, priority: TaskPriority) {
+        self.change_task_priority(task_id, priority);
+    }
+
+    pub fn set_trash_granularity(&mut self, granularity: TrashGranularity) {
+        self.trash_granularity = granularity;
+    }
+
+    pub fn preview_trash_for_test(&mut self) {
+        self.preview_trash();
+    }
+
+    pub fn confirm_trash_for_test(&mut self) {
+        self.trash_confirmed();
+    }
+
+    pub fn trash_plan(&self) -> Option<&TrashPlan> {
+        self.trash_plan.as_ref()
+    }
+
+    pub fn trash_report(&self) -> Option<&TrashReport> {
+        self.trash_report.as_ref()
+    }
+
+    pub fn is_trashing(&self) -> bool {
+        self.is_trashing
+    }
+
+    pub fn fetch_trashed_recordings_for_test(&mut self) {
+        self.fetch_trashed_recordings();
+    }
+
+    pub fn trashed_recordings(&self) -> Option<&RecordingSearchResponse> {
+        self.trashed_recordings.as_ref()
+    }
+
+    pub fn select_trashed(&mut self, id: &str) {
+        self.selected_trashed.insert(id.to_string());
+    }
+
+    pub fn request_trash_action_for_test(&mut self, action: TrashedAction) {
+        self.request_trash_action(action);
+    }
+
+    pub fn pending_trash_action(&self) -> Option<&(TrashedAction, Vec<TrashedTarget>)> {
+        self.pending_trash_action.as_ref()
+    }
+
+    pub fn confirm_trash_action_for_test(&mut self) {
+        self.trash_action_confirmed();
+    }
+
+    pub fn trash_action_report(&self) -> Option<&(TrashedAction, TrashReport)> {
+        self.trash_action_report.as_ref()
+    }
+
+    pub fn is_loading_trash(&self) -> bool {
+        self.is_loading_trash
+    }
+
+    pub fn verify_archive_for_test(&mut self) {
+        self.verify_archive();
+    }
+
+    pub fn is_verifying(&self) -> bool {
+        self.is_verifying
+    }
+
+    pub fn verify_report(&self) -> Option<&VerifyReport> {
+        self.verify_report.as_ref()
+    }
+
+    pub fn run_report(&self) -> Option<&RunReport> {
+        self.run_report.as_ref()
+    }
+
+    pub fn export_run_report_for_test(&mut self, format: ReportFormat) {
+        self.export_run_report(format);
+    }
+
+    pub fn set_output_dir(&mut self, v: &str) {
+        self.output_dir = v.to_string();
+    }
+
+    pub fn space_warning(&self) -> Option<&SpaceCheck> {
+        self.space_warning.as_ref()
+    }
+
+    pub fn low_disk_space(&self) -> Option<(u64, u64)> {
+        self.low_disk_space
+    }
+
+    pub fn start_download_for_test(&mut self) {
+        self.start_download();
+    }
+
+    pub fn unfinished_jobs(&self) -> &[JournalEntry] {
+        &self.unfinished_jobs
+    }
+
+    pub fn failed_items(&self) -> &[FailedItem] {
+        &self.failed_items
+    }
+
+    pub fn check_failed_items_for_test(&mut self) {
+        self.check_failed_items();
+    }
+
+    pub fn retry_failed_items_for_test(&mut self) {
+        self.retry_failed_items();
+    }
+
+    pub fn check_unfinished_jobs_for_test(&mut self) {
+        self.check_unfinished_jobs();
+    }
+
+    pub fn discard_unfinished_jobs_for_test(&mut self) {
+        self.discard_unfinished_jobs();
+    }
+
     pub fn access_token(&self) -> &Option<String> {
         &self.access_token
     }
@@ -1655,6 +4064,99 @@ impl ZoomDownloaderApp {
         &self.download_progress
     }
 
+    pub fn overall_progress(&self) -> &OverallProgress {
+        &self.overall_progress
+    }
+
+    pub fn file_progress_rows(&self) -> &[FileProgressRow] {
+        &self.file_progress
+    }
+
+    pub fn progress_percentage(&self) -> f32 {
+        self.progress_percentage
+    }
+
+    pub fn current_file(&self) -> &str {
+        &self.current_file
+    }
+
+    pub fn download_settings(&self) -> &DownloadSettings {
+        &self.download_settings
+    }
+
+    pub fn set_bandwidth_limit_input(&mut self, v: &str) {
+        self.bandwidth_limit_input = v.to_string();
+    }
+
+    pub fn add_bandwidth_window_input(&mut self, start: &str, end: &str, limit: &str) {
+        self.bandwidth_schedule_inputs.push(BandwidthWindowInput {
+            start: start.to_string(),
+            end: end.to_string(),
+            limit: limit.to_string(),
+        });
+    }
+
+    pub fn save_config_for_test(&mut self) {
+        self.save_config();
+    }
+
+    pub fn endpoints(&self) -> &EndpointProfile {
+        &self.endpoints
+    }
+
+    pub fn set_endpoints(&mut self, endpoints: EndpointProfile) {
+        self.endpoints = endpoints;
+    }
+
+    pub fn start_authentication_for_test(&mut self) {
+        self.start_authentication();
+    }
+
+    pub fn auth_url(&self) -> Option<&String> {
+        self.auth_url.as_ref()
+    }
+
+    pub fn set_scope_mode(&mut self, mode: RecordingScopeMode) {
+        self.scope_mode = mode;
+    }
+
+    pub fn account_users(&self) -> &[ZoomUser] {
+        &self.account_users
+    }
+
+    pub fn select_user(&mut self, user_id: &str) {
+        self.selected_users.insert(user_id.to_string());
+    }
+
+    pub fn select_recording(&mut self, id: &str) {
+        self.selected_recordings.insert(id.to_string());
+    }
+
+    pub fn load_account_users_for_test(&mut self) {
+        self.load_account_users();
+    }
+
+    pub fn fetch_recordings_for_test(&mut self) {
+        self.fetch_recordings();
+    }
+
+    pub fn set_search_dates(&mut self, from: &str, to: &str) {
+        self.from_date = from.to_string();
+        self.to_date = to.to_string();
+    }
+
+    pub fn set_download_control(&mut self, control: Option<DownloadControl>) {
+        self.download_control = control;
+    }
+
+    pub fn set_live_bandwidth_input(&mut self, v: &str) {
+        self.live_bandwidth_input = v.to_string();
+    }
+
+    pub fn apply_live_bandwidth_limit_for_test(&mut self) {
+        self.apply_live_bandwidth_limit();
+    }
+
     pub fn process_messages_for_test(&mut self) {
         self.process_messages();
     }
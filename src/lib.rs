@@ -19,7 +19,9 @@ pub use components::api::{
     RecordingSearchResponse, SummaryDetail,
 };
 pub use components::auth::AuthToken;
-pub use components::config::{AppConfig, OAuthConfig};
+pub use components::config::{
+    AppConfig, DownloadSettings, EndpointPreset, EndpointProfile, OAuthConfig,
+};
 pub use errors::{AppError, AppResult};
 pub use gui::{AppMessage, ZoomDownloaderApp};
 
@@ -134,6 +136,27 @@ pub fn generate_file_path(meeting: &MeetingRecording, recording_file: &Recording
     format!("{}/{}", folder_name, file_name)
 }
 
+/// ホスト別のサブフォルダーを付けた保存パスを生成する
+///
+/// # 事後条件
+/// - "host/YYYY-MM-DD/..." 形式のパスが返される
+/// - host はホストのメールアドレス（不明な場合はホストID）をファイル名として安全にしたもの
+pub fn generate_host_file_path(
+    meeting: &MeetingRecording,
+    recording_file: &RecordingFile,
+) -> String {
+    let host = if meeting.host_email.is_empty() {
+        &meeting.host_id
+    } else {
+        &meeting.host_email
+    };
+    format!(
+        "{}/{}",
+        sanitize_filename(host),
+        generate_file_path(meeting, recording_file)
+    )
+}
+
 /// 日時文字列をパース
 ///
 /// # 事前条件
@@ -163,6 +186,11 @@ pub struct Config {
     pub client_id: String,
     pub client_secret: String,
     pub redirect_uri: Option<String>,
+    /// 接続先（OAuth・API）のエンドポイント
+    #[serde(default)]
+    pub endpoints: EndpointProfile,
+    #[serde(default)]
+    pub download: DownloadSettings,
 }
 
 impl Config {
@@ -170,6 +198,7 @@ impl Config {
         use std::fs;
         let content = fs::read_to_string(path)?;
         let config: Config = toml::from_str(&content)?;
+        config.endpoints.validate()?;
         Ok(config)
     }
 
@@ -179,6 +208,8 @@ impl Config {
             client_id: "your_zoom_client_id".to_string(),
             client_secret: "your_zoom_client_secret".to_string(),
             redirect_uri: Some("http://localhost:8080/callback".to_string()),
+            endpoints: EndpointProfile::default(),
+            download: DownloadSettings::default(),
         };
         let content = toml::to_string_pretty(&sample_config)?;
         fs::write(path, content)?;
@@ -217,6 +248,7 @@ mod tests {
             id: 1234567890,
             account_id: String::new(),
             host_id: "host".to_string(),
+            host_email: String::new(),
             topic: topic.to_string(),
             meeting_type: 2,
             start_time: start_time.to_string(),
@@ -284,4 +316,65 @@ mod tests {
         let folder = path.split('/').next().unwrap();
         assert_eq!(folder, "2025-02-24");
     }
+
+    #[test]
+    fn test_generate_host_file_path_prefers_host_email() {
+        let mut meeting = make_test_meeting("2025-02-24T10:30:00Z", "Test");
+        let file = make_test_file(RecordingFileType::MP4, "MP4");
+        assert!(generate_host_file_path(&meeting, &file).starts_with("host/2025-02-24/"));
+
+        meeting.host_email = "alice@example.com".to_string();
+        let path = generate_host_file_path(&meeting, &file);
+        assert_eq!(
+            path,
+            format!("alice@example.com/{}", generate_file_path(&meeting, &file))
+        );
+    }
+
+    #[test]
+    fn test_config_without_download_section_uses_defaults() {
+        let config: Config = toml::from_str(
+            r#"
+            client_id = "id"
+            client_secret = "secret"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.download, DownloadSettings::default());
+        assert!(!config.download.keep_partial_on_cancel);
+    }
+
+    #[test]
+    fn test_config_download_settings_roundtrip() {
+        use crate::components::download::ConflictPolicy;
+
+        let config: Config = toml::from_str(
+            r#"
+            client_id = "id"
+            client_secret = "secret"
+
+            [download]
+            max_bytes_per_sec = 10000000
+            conflict_policy = "overwrite_if_size_differs"
+
+            [[download.bandwidth_schedule]]
+            start = "09:00"
+            end = "18:00"
+            max_bytes_per_sec = 2000000
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.download.max_bytes_per_sec, Some(10_000_000));
+        assert_eq!(
+            config.download.conflict_policy,
+            ConflictPolicy::OverwriteIfSizeDiffers
+        );
+        assert_eq!(config.download.bandwidth_schedule.len(), 1);
+        let window = &config.download.bandwidth_schedule[0];
+        assert_eq!(window.start.to_string(), "09:00:00");
+        assert_eq!(window.max_bytes_per_sec, Some(2_000_000));
+
+        let reparsed: Config = toml::from_str(&toml::to_string(&config).unwrap()).unwrap();
+        assert_eq!(reparsed.download, config.download);
+    }
 }
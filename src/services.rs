@@ -3,7 +3,14 @@
 //! 外部依存を抽象化し、テスト時にMock化可能にするためのtrait群。
 //! GUI層はこれらのtraitを通じて外部システムにアクセスする。
 
-use crate::components::api::RecordingSearchResponse;
+use crate::components::api::{RecordingSearchResponse, ZoomUser};
+use crate::components::archive::VerifyReport;
+use crate::components::config::{DownloadSettings, EndpointProfile};
+use crate::components::disk_space::SpaceCheck;
+use crate::components::download::DownloadControl;
+use crate::components::failed_batch::FailedItem;
+use crate::components::journal::JournalEntry;
+use crate::components::trash::{TrashGranularity, TrashPlan, TrashReport, TrashedTarget};
 use crate::gui::AppMessage;
 use crate::Config;
 use std::sync::mpsc;
@@ -26,6 +33,7 @@ pub trait AuthService: Send + Sync + 'static {
         &self,
         client_id: &str,
         client_secret: &str,
+        endpoints: &EndpointProfile,
     ) -> Result<String, Box<dyn std::error::Error + Send + Sync>>;
 
     fn exchange_code_for_token(
@@ -33,20 +41,72 @@ pub trait AuthService: Send + Sync + 'static {
         client_id: &str,
         client_secret: &str,
         auth_code: &str,
+        endpoints: &EndpointProfile,
     ) -> Result<String, Box<dyn std::error::Error + Send + Sync>>;
 }
 
+/// 録画の取得範囲
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum RecordingScope {
+    /// 認証したユーザー自身の録画
+    #[default]
+    Me,
+    /// アカウント全体の録画（管理者権限が必要）
+    AllUsers,
+    /// 指定したユーザーの録画（管理者権限が必要）
+    Users(Vec<ZoomUser>),
+}
+
 /// 録画データ取得を担当するサービス
 #[cfg_attr(feature = "test-support", automock)]
 pub trait RecordingService: Send + Sync + 'static {
+    /// 録画を取得する
+    ///
+    /// アカウント全体・指定ユーザーの取得では、各ミーティングの host_email をユーザー一覧から補完する。
     fn get_recordings(
         &self,
         access_token: &str,
-        user_id: &str,
+        scope: &RecordingScope,
         from_date: &str,
         to_date: &str,
+        endpoints: &EndpointProfile,
         progress_sender: mpsc::Sender<AppMessage>,
     ) -> Result<RecordingSearchResponse, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// アカウントの有効なユーザーをすべて取得する（管理者権限が必要）
+    fn list_users(
+        &self,
+        access_token: &str,
+        endpoints: &EndpointProfile,
+    ) -> Result<Vec<ZoomUser>, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// ゴミ箱にある録画を取得する
+    ///
+    /// ゴミ箱はユーザー単位でしか一覧できないため、アカウント全体ではユーザーごとに取得する。
+    fn get_trashed_recordings(
+        &self,
+        access_token: &str,
+        scope: &RecordingScope,
+        from_date: &str,
+        to_date: &str,
+        endpoints: &EndpointProfile,
+    ) -> Result<RecordingSearchResponse, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// ゴミ箱の録画を復元する（結果は1件ずつ監査ログに追記される）
+    fn recover_recordings(
+        &self,
+        access_token: &str,
+        targets: &[TrashedTarget],
+        endpoints: &EndpointProfile,
+    ) -> Result<TrashReport, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// ゴミ箱の録画を完全に削除する（元に戻せない。結果は1件ずつ監査ログに追記される）
+    fn delete_recordings_permanently(
+        &self,
+        access_token: &str,
+        targets: &[TrashedTarget],
+        endpoints: &EndpointProfile,
+    ) -> Result<TrashReport, Box<dyn std::error::Error + Send + Sync>>;
 }
 
 /// ブラウザ起動を担当するサービス
@@ -55,7 +115,35 @@ pub trait BrowserLauncher: Send + Sync {
     fn open_url(&self, url: &str) -> Result<(), Box<dyn std::error::Error>>;
 }
 
+/// ダウンロード実行オプション
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DownloadOptions {
+    /// 出力ディレクトリ
+    pub output_dir: String,
+    /// ダウンロード動作設定
+    pub settings: DownloadSettings,
+    /// 接続先のエンドポイント（download_url の取り直し・要約APIに使用）
+    pub endpoints: EndpointProfile,
+}
+
+/// ダウンロード処理の結果
+#[derive(Debug, Clone, PartialEq)]
+pub enum DownloadOutcome {
+    /// 全タスクが終了した（保存したファイルパス）
+    Completed(Vec<String>),
+    /// ユーザーによりキャンセルされた
+    Cancelled {
+        /// キャンセル前に保存を終えたファイルパス
+        completed_files: Vec<String>,
+        /// 中断・未着手となったタスク数
+        cancelled_tasks: usize,
+    },
+}
+
 /// ファイルダウンロードを担当するサービス
+///
+/// `control` は呼び出し側が一時停止・再開・キャンセルを指示するためのハンドル。
+/// ワーカーが実際に停止・再開した時点で DownloadPaused / DownloadResumed が送信される。
 #[cfg_attr(feature = "test-support", automock)]
 pub trait DownloadService: Send + Sync + 'static {
     fn download_files(
@@ -63,7 +151,78 @@ pub trait DownloadService: Send + Sync + 'static {
         access_token: &str,
         recordings: &RecordingSearchResponse,
         selected_recordings: &[String],
-        output_dir: &str,
+        options: &DownloadOptions,
+        control: DownloadControl,
+        sender: mpsc::Sender<AppMessage>,
+    ) -> Result<DownloadOutcome, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// 選択ファイルの保存に必要な空き容量を確認する
+    ///
+    /// ダウンロード済み（マニフェストで確認できる）ファイルと、.part に取得済みのバイト数は除外する。
+    fn preflight_disk_space(
+        &self,
+        recordings: &RecordingSearchResponse,
+        selected_recordings: &[String],
+        options: &DownloadOptions,
+    ) -> Result<SpaceCheck, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// 前回セッションから持ち越された未完了ジョブを取得する
+    fn unfinished_jobs(
+        &self,
+    ) -> Result<Vec<JournalEntry>, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// 未完了ジョブを .part ファイルから再開する
+    fn resume_unfinished_jobs(
+        &self,
+        access_token: &str,
+        options: &DownloadOptions,
+        control: DownloadControl,
+        sender: mpsc::Sender<AppMessage>,
+    ) -> Result<DownloadOutcome, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// 直前のバッチで失敗した（または再試行で取得できる理由でスキップした）ファイルを取得する
+    fn failed_items(&self) -> Result<Vec<FailedItem>, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// 直前のバッチで失敗したファイルだけを再ダウンロードする
+    ///
+    /// 失効している可能性がある download_url は録画APIから取り直す。
+    /// 終了後は今回の結果で失敗ファイルの一覧が置き換えられる。
+    fn retry_failed(
+        &self,
+        access_token: &str,
+        options: &DownloadOptions,
+        control: DownloadControl,
         sender: mpsc::Sender<AppMessage>,
-    ) -> Result<Vec<String>, Box<dyn std::error::Error + Send + Sync>>;
+    ) -> Result<DownloadOutcome, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// 未完了ジョブと途中ファイルを破棄する
+    fn discard_unfinished_jobs(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    /// 出力ディレクトリのアーカイブを manifest.json と照合して再ハッシュ検証する
+    fn verify_archive(
+        &self,
+        output_dir: &str,
+    ) -> Result<VerifyReport, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// 選択した録画をゴミ箱へ移動した場合の対象を確認する（ドライラン、クラウドは変更しない）
+    ///
+    /// 各ファイルはローカルのダウンロードの記録とサイズ・SHA-256 で照合する。
+    fn preview_trash(
+        &self,
+        recordings: &RecordingSearchResponse,
+        selected_recordings: &[String],
+        options: &DownloadOptions,
+        granularity: TrashGranularity,
+    ) -> Result<TrashPlan, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// 事前確認で移動できると判定した対象をクラウドのゴミ箱へ移動する
+    ///
+    /// 移動の直前にローカルのファイルを再検証し、変更・削除されていた対象は移動しない。
+    /// 結果は1件ずつ監査ログに追記される。
+    fn trash_recordings(
+        &self,
+        access_token: &str,
+        plan: &TrashPlan,
+        options: &DownloadOptions,
+    ) -> Result<TrashReport, Box<dyn std::error::Error + Send + Sync>>;
 }
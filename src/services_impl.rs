@@ -5,21 +5,57 @@
 
 use crate::components::api::{
     ApiComponent, ApiConfig, MeetingRecording, RecordingFile, RecordingFileType,
-    RecordingSearchRequest, RecordingSearchResponse,
+    RecordingSearchRequest, RecordingSearchResponse, ZoomUser,
 };
-use crate::components::auth::AuthToken;
-use crate::components::download::{DownloadComponent, DownloadConfig, DownloadEvent};
+use crate::components::archive::{self, ArchiveManifest, VerifyReport};
+use crate::components::config::{EndpointProfile, OAuthConfig};
+use crate::components::disk_space::{self, SpaceCheck};
+use crate::components::download::{
+    DownloadComponent, DownloadConfig, DownloadControl, DownloadEvent, DownloadProgress,
+    DownloadTask, FailureReason, TaskPriority, TaskState,
+};
+use crate::components::event_bus::{BusMessage, EventEnvelope, DEFAULT_EVENT_BUS_CAPACITY};
+use crate::components::failed_batch::{self, FailedBatch, FailedItem};
+use crate::components::journal::{DownloadJournal, JournalEntry, JournalSource};
+use crate::components::manifest::{sha256_file, DownloadManifest, ManifestStatus};
+use crate::components::rate_limit::ApiRateLimiter;
+use crate::components::report::{FinalState, ReportFile, ReportFormat, RunRecorder, SkipReason};
+use crate::components::segmented::SegmentState;
+use crate::components::token_provider::{AuthTokenProvider, TokenProvider};
+use crate::components::trash::{
+    self, LocalVerification, TrashAction, TrashAuditLog, TrashAuditRecord, TrashFile,
+    TrashGranularity, TrashItem, TrashPlan, TrashReport, TrashResult, TrashedAction, TrashedTarget,
+};
+use crate::errors::AppError;
 use crate::gui::AppMessage;
 use crate::services::{
-    AuthService, BrowserLauncher, ConfigService, DownloadService, RecordingService,
+    AuthService, BrowserLauncher, ConfigService, DownloadOptions, DownloadOutcome, DownloadService,
+    RecordingScope, RecordingService,
 };
 use crate::Config;
 use chrono::{Datelike, NaiveDate};
 use log;
-use std::path::PathBuf;
+use std::collections::{HashMap, VecDeque};
+use std::path::{Path, PathBuf};
 use std::sync::{mpsc, Arc};
 use tokio::sync::Semaphore;
 
+/// 本番用の ApiComponent を作成する
+///
+/// 1日あたりの API 上限の消費状況は設定ディレクトリに保存し、同じ日の次回実行に引き継ぐ。
+fn new_api_component(tokens: Arc<dyn TokenProvider>, endpoints: &EndpointProfile) -> ApiComponent {
+    let mut api = ApiComponent::new(ApiConfig {
+        base_url: endpoints.api_base_url(),
+        ..ApiConfig::default()
+    });
+    api.set_token_provider(tokens);
+    match ApiRateLimiter::default_store_path() {
+        Ok(path) => api.set_quota_store(path),
+        Err(e) => log::warn!("API quota usage will not be persisted: {}", e),
+    }
+    api
+}
+
 /// 本番用設定サービス
 pub struct RealConfigService;
 
@@ -38,19 +74,26 @@ impl ConfigService for RealConfigService {
 }
 
 /// 本番用認証サービス
-pub struct RealAuthService;
+///
+/// 取得したトークンは session に保持し、録画取得・ダウンロードサービスと共有する。
+pub struct RealAuthService {
+    pub session: AuthTokenProvider,
+}
 
 impl AuthService for RealAuthService {
     fn generate_auth_url(
         &self,
         client_id: &str,
         client_secret: &str,
+        endpoints: &EndpointProfile,
     ) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
         let rt = tokio::runtime::Runtime::new()
             .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> { Box::new(e) })?;
         let client_id = client_id.to_string();
         let client_secret = client_secret.to_string();
-        rt.block_on(async { crate::gui::generate_auth_url_async(&client_id, &client_secret).await })
+        rt.block_on(async {
+            crate::gui::generate_auth_url_async(&client_id, &client_secret, endpoints).await
+        })
     }
 
     fn exchange_code_for_token(
@@ -58,6 +101,7 @@ impl AuthService for RealAuthService {
         client_id: &str,
         client_secret: &str,
         auth_code: &str,
+        endpoints: &EndpointProfile,
     ) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
         let rt = tokio::runtime::Runtime::new()
             .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> { Box::new(e) })?;
@@ -65,13 +109,33 @@ impl AuthService for RealAuthService {
         let client_secret = client_secret.to_string();
         let auth_code = auth_code.to_string();
         rt.block_on(async {
-            crate::gui::exchange_code_for_token_async(&client_id, &client_secret, &auth_code).await
+            let token = crate::gui::exchange_code_for_token_async(
+                &client_id,
+                &client_secret,
+                &auth_code,
+                endpoints,
+            )
+            .await?;
+            let access_token = token.access_token.clone();
+            let config = OAuthConfig {
+                client_id,
+                client_secret,
+                ..OAuthConfig::default()
+            };
+            self.session
+                .sign_in(config, endpoints.clone(), token)
+                .await?;
+            Ok(access_token)
         })
     }
 }
 
 /// 本番用録画取得サービス
-pub struct RealRecordingService;
+///
+/// session が認証済みの場合は渡された access_token より session のトークンを優先する。
+pub struct RealRecordingService {
+    pub session: AuthTokenProvider,
+}
 
 /// 日付範囲を最大1ヶ月ごとのチャンクに分割する
 ///
@@ -107,28 +171,23 @@ impl RecordingService for RealRecordingService {
     fn get_recordings(
         &self,
         access_token: &str,
-        user_id: &str,
+        scope: &RecordingScope,
         from_date: &str,
         to_date: &str,
+        endpoints: &EndpointProfile,
         progress_sender: mpsc::Sender<AppMessage>,
     ) -> Result<RecordingSearchResponse, Box<dyn std::error::Error + Send + Sync>> {
         let rt = tokio::runtime::Runtime::new()
             .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> { Box::new(e) })?;
         let access_token = access_token.to_string();
-        let user_id = user_id.to_string();
         let from_date = from_date.to_string();
         let to_date = to_date.to_string();
         rt.block_on(async {
-            // ApiComponent を生成し、認証トークンを設定
-            let api = ApiComponent::new(ApiConfig::default());
-            let token = AuthToken {
-                access_token,
-                token_type: "Bearer".to_string(),
-                expires_at: chrono::Utc::now() + chrono::Duration::hours(1),
-                refresh_token: None,
-                scopes: vec!["recording:read".to_string()],
-            };
-            api.set_auth_token(token).await;
+            // ApiComponent を生成し、トークンの供給元を設定
+            let api = new_api_component(
+                Arc::new(self.session.or_static(access_token)),
+                endpoints,
+            );
 
             // 日付パース
             let from = NaiveDate::parse_from_str(&from_date, "%Y-%m-%d")
@@ -140,35 +199,58 @@ impl RecordingService for RealRecordingService {
                     Box::new(e)
                 })?;
 
-            // 月単位チャンクに分割してページネーション対応で全件取得（並列）
+            // 取得対象（ユーザーID, アカウントID）
+            let owners: Vec<(Option<String>, Option<String>)> = match scope {
+                RecordingScope::Me => vec![(Some("me".to_string()), None)],
+                RecordingScope::AllUsers => vec![(None, Some("me".to_string()))],
+                RecordingScope::Users(users) => users
+                    .iter()
+                    .map(|user| (Some(user.id.clone()), None))
+                    .collect(),
+            };
+            // ホストID → メールアドレス
+            let mut host_emails: HashMap<String, String> = match scope {
+                RecordingScope::Users(users) => users
+                    .iter()
+                    .map(|user| (user.id.clone(), user.email.clone()))
+                    .collect(),
+                _ => HashMap::new(),
+            };
+            if owners.is_empty() {
+                return Err(Box::new(AppError::validation("No users selected", Some("users".to_string())))
+                    as Box<dyn std::error::Error + Send + Sync>);
+            }
+
+            // 月単位チャンク × 取得対象に分割してページネーション対応で全件取得（並列）
             let chunks = split_into_monthly_chunks(from, to);
-            let total_chunks = chunks.len();
-            log::info!("Fetching recordings: from={} to={}, split into {} chunk(s)", from, to, total_chunks);
+            let total_jobs = chunks.len() * owners.len();
+            log::info!("Fetching recordings: from={} to={}, split into {} chunk(s) for {} owner(s)",
+                from, to, chunks.len(), owners.len());
 
             let api = Arc::new(api);
             let mut all_meetings: Vec<MeetingRecording> = Vec::new();
 
             let chunk_semaphore = Arc::new(Semaphore::new(3));
             let mut handles = Vec::new();
-            for (chunk_idx, (chunk_from, chunk_to)) in chunks.iter().enumerate() {
+            let jobs = owners.iter().flat_map(|owner| chunks.iter().map(move |chunk| (owner.clone(), *chunk)));
+            for (job_idx, ((user_id, account_id), (chunk_from, chunk_to))) in jobs.enumerate() {
                 let api = Arc::clone(&api);
                 let sem = Arc::clone(&chunk_semaphore);
-                let user_id = user_id.clone();
                 let progress_sender = progress_sender.clone();
-                let chunk_from = *chunk_from;
-                let chunk_to = *chunk_to;
                 handles.push(tokio::spawn(async move {
                     let _permit = sem.acquire().await.unwrap();
                     let _ = progress_sender.send(AppMessage::SearchProgress(
-                        format!("録画データを取得中... ({}/{})", chunk_idx + 1, total_chunks),
+                        format!("録画データを取得中... ({}/{})", job_idx + 1, total_jobs),
                     ));
                     log::info!("Fetching chunk: from={} to={}", chunk_from, chunk_to);
                     let request = RecordingSearchRequest {
-                        user_id: Some(user_id),
+                        user_id,
+                        account_id,
                         from: chunk_from,
                         to: chunk_to,
                         page_size: None,
                         next_page_token: None,
+                        trash: false,
                     };
                     let result = api.get_all_recordings(request).await
                         .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> { Box::new(e) });
@@ -184,6 +266,24 @@ impl RecordingService for RealRecordingService {
 
             log::info!("Total meetings fetched: {}", all_meetings.len());
 
+            // アカウント全体の取得ではホストのメールアドレスをユーザー一覧から補完する
+            if *scope == RecordingScope::AllUsers {
+                let _ = progress_sender.send(AppMessage::SearchProgress(
+                    "ユーザー一覧を取得中...".to_string(),
+                ));
+                match api.get_all_users().await {
+                    Ok(users) => host_emails.extend(users.into_iter().map(|user| (user.id, user.email))),
+                    Err(e) => log::warn!("Could not fetch users to resolve host emails: {}", e),
+                }
+            }
+            for meeting in &mut all_meetings {
+                if meeting.host_email.is_empty() {
+                    if let Some(email) = host_emails.get(&meeting.host_id) {
+                        meeting.host_email = email.clone();
+                    }
+                }
+            }
+
             let mut recordings = RecordingSearchResponse {
                 from: from_date.clone(),
                 to: to_date.clone(),
@@ -255,6 +355,149 @@ impl RecordingService for RealRecordingService {
             Ok(recordings)
         })
     }
+
+    fn list_users(
+        &self,
+        access_token: &str,
+        endpoints: &EndpointProfile,
+    ) -> Result<Vec<ZoomUser>, Box<dyn std::error::Error + Send + Sync>> {
+        let rt = tokio::runtime::Runtime::new()
+            .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> { Box::new(e) })?;
+        let api = new_api_component(Arc::new(self.session.or_static(access_token)), endpoints);
+        Ok(rt.block_on(api.get_all_users())?)
+    }
+
+    fn get_trashed_recordings(
+        &self,
+        access_token: &str,
+        scope: &RecordingScope,
+        from_date: &str,
+        to_date: &str,
+        endpoints: &EndpointProfile,
+    ) -> Result<RecordingSearchResponse, Box<dyn std::error::Error + Send + Sync>> {
+        let rt = tokio::runtime::Runtime::new()
+            .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> { Box::new(e) })?;
+        let api = new_api_component(Arc::new(self.session.or_static(access_token)), endpoints);
+        let from = NaiveDate::parse_from_str(from_date, "%Y-%m-%d")
+            .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> { Box::new(e) })?;
+        let to = NaiveDate::parse_from_str(to_date, "%Y-%m-%d")
+            .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> { Box::new(e) })?;
+
+        rt.block_on(async {
+            // ゴミ箱はユーザー単位でしか一覧できないため、アカウント全体ではユーザーごとに取得する
+            let users: Vec<ZoomUser> = match scope {
+                RecordingScope::Me => Vec::new(),
+                RecordingScope::AllUsers => api.get_all_users().await?,
+                RecordingScope::Users(users) if users.is_empty() => {
+                    return Err(Box::new(AppError::validation(
+                        "No users selected",
+                        Some("users".to_string()),
+                    ))
+                        as Box<dyn std::error::Error + Send + Sync>);
+                }
+                RecordingScope::Users(users) => users.clone(),
+            };
+            let owners: Vec<String> = match scope {
+                RecordingScope::Me => vec!["me".to_string()],
+                _ => users.iter().map(|user| user.id.clone()).collect(),
+            };
+
+            let mut meetings = Vec::new();
+            for owner in &owners {
+                for (chunk_from, chunk_to) in split_into_monthly_chunks(from, to) {
+                    let request = RecordingSearchRequest {
+                        user_id: Some(owner.clone()),
+                        account_id: None,
+                        from: chunk_from,
+                        to: chunk_to,
+                        page_size: None,
+                        next_page_token: None,
+                        trash: true,
+                    };
+                    meetings.extend(api.get_all_recordings(request).await?);
+                }
+            }
+            for meeting in &mut meetings {
+                if meeting.host_email.is_empty() {
+                    if let Some(user) = users.iter().find(|user| user.id == meeting.host_id) {
+                        meeting.host_email = user.email.clone();
+                    }
+                }
+            }
+            log::info!("Trashed meetings fetched: {}", meetings.len());
+
+            Ok(RecordingSearchResponse {
+                from: from_date.to_string(),
+                to: to_date.to_string(),
+                page_count: 1,
+                page_size: meetings.len() as u32,
+                total_records: meetings.len() as u32,
+                next_page_token: None,
+                meetings,
+            })
+        })
+    }
+
+    fn recover_recordings(
+        &self,
+        access_token: &str,
+        targets: &[TrashedTarget],
+        endpoints: &EndpointProfile,
+    ) -> Result<TrashReport, Box<dyn std::error::Error + Send + Sync>> {
+        self.apply_to_trashed(TrashedAction::Recover, access_token, targets, endpoints)
+    }
+
+    fn delete_recordings_permanently(
+        &self,
+        access_token: &str,
+        targets: &[TrashedTarget],
+        endpoints: &EndpointProfile,
+    ) -> Result<TrashReport, Box<dyn std::error::Error + Send + Sync>> {
+        self.apply_to_trashed(TrashedAction::Delete, access_token, targets, endpoints)
+    }
+}
+
+impl RealRecordingService {
+    /// ゴミ箱の録画を1件ずつ復元・完全削除し、結果を監査ログに追記する
+    fn apply_to_trashed(
+        &self,
+        action: TrashedAction,
+        access_token: &str,
+        targets: &[TrashedTarget],
+        endpoints: &EndpointProfile,
+    ) -> Result<TrashReport, Box<dyn std::error::Error + Send + Sync>> {
+        let audit = TrashAuditLog::open_default()?;
+        let api = new_api_component(Arc::new(self.session.or_static(access_token)), endpoints);
+        let rt = tokio::runtime::Runtime::new()
+            .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> { Box::new(e) })?;
+
+        let mut report = TrashReport::default();
+        for target in targets {
+            let recording_id = target.recording_id.as_deref();
+            let outcome = match action {
+                TrashedAction::Recover => {
+                    rt.block_on(api.recover_recordings(&target.meeting_uuid, recording_id))
+                }
+                TrashedAction::Delete => rt.block_on(
+                    api.delete_recordings_permanently(&target.meeting_uuid, recording_id),
+                ),
+            };
+            let result = match outcome {
+                Ok(()) => TrashResult::Succeeded,
+                Err(e) => TrashResult::Failed {
+                    error: e.to_string(),
+                },
+            };
+
+            let record = TrashAuditRecord::for_target(action.into(), target, result);
+            if let Err(e) = audit.append(&record) {
+                log::error!("Failed to write trash audit record: {}", e);
+            }
+            report.records.push(record);
+        }
+
+        Ok(report)
+    }
 }
 
 /// 本番用ブラウザ起動サービス
@@ -267,8 +510,44 @@ impl BrowserLauncher for RealBrowserLauncher {
     }
 }
 
+/// 完了時にマニフェストへ記録するファイルの情報
+struct ManifestTarget {
+    /// ダウンロードマニフェストのキー
+    key: String,
+    meeting: MeetingRecording,
+    file: RecordingFile,
+}
+
+impl ManifestTarget {
+    fn new(meeting: &MeetingRecording, file: &RecordingFile) -> Self {
+        Self {
+            key: RealDownloadService::manifest_key(meeting, file),
+            meeting: meeting.clone(),
+            file: file.clone(),
+        }
+    }
+}
+
+/// run_download_tasks で実行するダウンロードの内容
+struct DownloadPlan {
+    tasks: Vec<DownloadTask>,
+    /// Meeting Summary API で取得する AI 要約
+    summary_fallback_targets: Vec<(MeetingRecording, RecordingFile)>,
+    /// task_id → マニフェストの記録先
+    manifest_targets: HashMap<String, ManifestTarget>,
+    /// 実行レポートの集計（事前にスキップしたファイルは記録済み）
+    recorder: RunRecorder,
+    /// 出力ファイル名 → 会議・録画ファイル（空の場合は失敗ファイル記録を更新しない）
+    sources: HashMap<String, (MeetingRecording, RecordingFile)>,
+}
+
 /// 本番用ダウンロードサービス
-pub struct RealDownloadService;
+///
+/// session が認証済みの場合は渡された access_token より session のトークンを優先し、
+/// バッチ中に期限切れになったトークンを更新する。
+pub struct RealDownloadService {
+    pub session: AuthTokenProvider,
+}
 
 impl RealDownloadService {
     /// 選択IDから対象ファイルを解決する
@@ -341,9 +620,10 @@ impl DownloadService for RealDownloadService {
         access_token: &str,
         recordings: &RecordingSearchResponse,
         selected_recordings: &[String],
-        output_dir: &str,
+        options: &DownloadOptions,
+        control: DownloadControl,
         sender: mpsc::Sender<AppMessage>,
-    ) -> Result<Vec<String>, Box<dyn std::error::Error + Send + Sync>> {
+    ) -> Result<DownloadOutcome, Box<dyn std::error::Error + Send + Sync>> {
         let _ = sender.send(AppMessage::DownloadProgress(
             "Resolving selected files...".to_string(),
         ));
@@ -352,24 +632,322 @@ impl DownloadService for RealDownloadService {
         let files_to_download = Self::resolve_selected_files(recordings, selected_recordings);
 
         if files_to_download.is_empty() {
-            let _ = sender.send(AppMessage::DownloadComplete(vec![]));
-            return Ok(vec![]);
+            return Ok(DownloadOutcome::Completed(vec![]));
         }
 
-        let total_files = files_to_download.len();
+        let plan = Self::plan_downloads(&files_to_download, options, &sender);
+        Self::run_download_tasks(
+            plan,
+            Arc::new(self.session.or_static(access_token)),
+            options,
+            control,
+            sender,
+        )
+    }
+
+    fn preflight_disk_space(
+        &self,
+        recordings: &RecordingSearchResponse,
+        selected_recordings: &[String],
+        options: &DownloadOptions,
+    ) -> Result<SpaceCheck, Box<dyn std::error::Error + Send + Sync>> {
+        let output_dir = PathBuf::from(&options.output_dir);
+        let manifest = Self::open_manifest(&output_dir);
+
+        let remaining_sizes = Self::resolve_selected_files(recordings, selected_recordings)
+            .into_iter()
+            .filter_map(|(meeting, file)| {
+                let file_name = options.settings.file_path(meeting, file);
+                let expected_size = (file.file_size > 0).then_some(file.file_size);
+                let key = Self::manifest_key(meeting, file);
+                if manifest.check(&key, Path::new(&file_name), expected_size)
+                    == ManifestStatus::Intact
+                {
+                    return None;
+                }
+
+                // .part に取得済みの分は再開時に不要
+                // （分割ダウンロードの .part は全体サイズで確保されるため状態ファイルで判定）
+                let mut part_path = output_dir.join(&file_name).into_os_string();
+                part_path.push(".part");
+                let part_path = PathBuf::from(part_path);
+                let partial = match SegmentState::load(&part_path) {
+                    Some(state) => state.downloaded(),
+                    None => std::fs::metadata(&part_path)
+                        .map(|metadata| metadata.len())
+                        .unwrap_or(0),
+                };
+                Some(expected_size.map(|size| size.saturating_sub(partial)))
+            })
+            .collect::<Vec<_>>();
+
+        Ok(disk_space::preflight(
+            &output_dir,
+            remaining_sizes,
+            options.settings.min_free_space_bytes(),
+        )?)
+    }
+
+    fn unfinished_jobs(
+        &self,
+    ) -> Result<Vec<JournalEntry>, Box<dyn std::error::Error + Send + Sync>> {
+        let journal = DownloadJournal::open_default()?;
+        Ok(journal.unfinished())
+    }
+
+    fn resume_unfinished_jobs(
+        &self,
+        access_token: &str,
+        options: &DownloadOptions,
+        control: DownloadControl,
+        sender: mpsc::Sender<AppMessage>,
+    ) -> Result<DownloadOutcome, Box<dyn std::error::Error + Send + Sync>> {
+        let entries = DownloadJournal::open_default()?.unfinished();
+        let _ = sender.send(AppMessage::DownloadProgress(format!(
+            "Resuming {} unfinished download(s) from previous session...",
+            entries.len()
+        )));
+
+        let plan = Self::plan_resume(&entries);
+        Self::run_download_tasks(
+            plan,
+            Arc::new(self.session.or_static(access_token)),
+            options,
+            control,
+            sender,
+        )
+    }
+
+    fn preview_trash(
+        &self,
+        recordings: &RecordingSearchResponse,
+        selected_recordings: &[String],
+        options: &DownloadOptions,
+        granularity: TrashGranularity,
+    ) -> Result<TrashPlan, Box<dyn std::error::Error + Send + Sync>> {
+        let output_dir = PathBuf::from(&options.output_dir);
+        let manifest = Self::open_manifest(&output_dir);
+        let verify = |meeting: &MeetingRecording, file: &RecordingFile| {
+            let local_path = options.settings.file_path(meeting, file);
+            let verification = if file.id.is_empty() {
+                LocalVerification::NoRecordingId
+            } else {
+                trash::verify_local_file(
+                    &manifest,
+                    &output_dir,
+                    &Self::manifest_key(meeting, file),
+                    Path::new(&local_path),
+                    (file.file_size > 0).then_some(file.file_size),
+                )
+            };
+            TrashFile {
+                recording_id: file.id.clone(),
+                file_type: file.file_type.to_string(),
+                local_path,
+                verification,
+            }
+        };
+        let item = |meeting: &MeetingRecording, files: Vec<TrashFile>| TrashItem {
+            meeting_uuid: meeting.uuid.clone(),
+            topic: meeting.topic.clone(),
+            start_time: meeting.start_time.clone(),
+            granularity,
+            files,
+        };
+
+        let selected_files = Self::resolve_selected_files(recordings, selected_recordings);
+        let items = match granularity {
+            TrashGranularity::File => selected_files
+                .into_iter()
+                .map(|(meeting, file)| item(meeting, vec![verify(meeting, file)]))
+                .collect(),
+            TrashGranularity::Meeting => {
+                // ミーティング単位では、選択の有無によらずミーティングの全録画ファイルを照合する
+                let mut meetings: Vec<&MeetingRecording> = Vec::new();
+                for (meeting, _) in selected_files {
+                    if !meetings.iter().any(|m| m.uuid == meeting.uuid) {
+                        meetings.push(meeting);
+                    }
+                }
+                meetings
+                    .into_iter()
+                    .map(|meeting| {
+                        let files = meeting
+                            .recording_files
+                            .iter()
+                            // Meeting Summary API から補った要約は録画ファイルではない
+                            .filter(|file| !file.id.is_empty())
+                            .map(|file| verify(meeting, file))
+                            .collect();
+                        item(meeting, files)
+                    })
+                    .collect()
+            }
+        };
+
+        Ok(TrashPlan { granularity, items })
+    }
+
+    fn trash_recordings(
+        &self,
+        access_token: &str,
+        plan: &TrashPlan,
+        options: &DownloadOptions,
+    ) -> Result<TrashReport, Box<dyn std::error::Error + Send + Sync>> {
+        let output_dir = PathBuf::from(&options.output_dir);
+        let manifest = Self::open_manifest(&output_dir);
+        let audit = TrashAuditLog::open_default()?;
+        let api = new_api_component(
+            Arc::new(self.session.or_static(access_token)),
+            &options.endpoints,
+        );
+        let rt = tokio::runtime::Runtime::new()
+            .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> { Box::new(e) })?;
+
+        let mut report = TrashReport::default();
+        for item in plan.eligible() {
+            let recording_id = match item.granularity {
+                TrashGranularity::File => Some(item.files[0].recording_id.clone()),
+                TrashGranularity::Meeting => None,
+            };
+            let result = match Self::reverify_trash_item(&manifest, &output_dir, item) {
+                Some(reason) => TrashResult::Skipped { reason },
+                None => {
+                    match rt
+                        .block_on(api.trash_recordings(&item.meeting_uuid, recording_id.as_deref()))
+                    {
+                        Ok(()) => TrashResult::Succeeded,
+                        Err(e) => TrashResult::Failed {
+                            error: e.to_string(),
+                        },
+                    }
+                }
+            };
+
+            let record = TrashAuditRecord {
+                at: chrono::Utc::now(),
+                action: TrashAction::Trash,
+                meeting_uuid: item.meeting_uuid.clone(),
+                topic: item.topic.clone(),
+                granularity: item.granularity,
+                recording_id,
+                files: item.files.clone(),
+                result,
+            };
+            if let Err(e) = audit.append(&record) {
+                log::error!("Failed to write trash audit record: {}", e);
+            }
+            report.records.push(record);
+        }
+
+        Ok(report)
+    }
+
+    fn failed_items(&self) -> Result<Vec<FailedItem>, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(FailedBatch::open_default()?.items().to_vec())
+    }
+
+    fn retry_failed(
+        &self,
+        access_token: &str,
+        options: &DownloadOptions,
+        control: DownloadControl,
+        sender: mpsc::Sender<AppMessage>,
+    ) -> Result<DownloadOutcome, Box<dyn std::error::Error + Send + Sync>> {
+        let mut items = FailedBatch::open_default()?.items().to_vec();
+        if items.is_empty() {
+            return Ok(DownloadOutcome::Completed(vec![]));
+        }
+        let _ = sender.send(AppMessage::DownloadProgress(format!(
+            "Retrying {} file(s) from the previous batch...",
+            items.len()
+        )));
+
+        let tokens: Arc<dyn TokenProvider> = Arc::new(self.session.or_static(access_token));
+        let api = new_api_component(tokens.clone(), &options.endpoints);
+        let rt = tokio::runtime::Runtime::new()
+            .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> { Box::new(e) })?;
+        rt.block_on(Self::refresh_download_urls(&api, &mut items));
+        drop(rt);
+
+        let files: Vec<(&MeetingRecording, &RecordingFile)> = items
+            .iter()
+            .map(|item| (&item.meeting, &item.file))
+            .collect();
+        let plan = Self::plan_downloads(&files, options, &sender);
+        Self::run_download_tasks(plan, tokens, options, control, sender)
+    }
+
+    fn discard_unfinished_jobs(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let mut journal = DownloadJournal::open_default()?;
+        for entry in journal.unfinished() {
+            let part_path = entry.to_task(String::new()).part_path();
+            SegmentState::remove(&part_path);
+            if part_path.exists() {
+                std::fs::remove_file(&part_path)?;
+                log::info!("Removed partial file: {}", part_path.display());
+            }
+        }
+        journal.clear()?;
+        Ok(())
+    }
+
+    fn verify_archive(
+        &self,
+        output_dir: &str,
+    ) -> Result<VerifyReport, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(archive::verify_archive(output_dir)?)
+    }
+}
+
+impl RealDownloadService {
+    /// 対象ファイルからダウンロードの内容を組み立てる
+    ///
+    /// # 副作用
+    /// - マニフェストの読み込みと更新（無効な記録の除去・既存ファイルの取り込み）
+    /// - スキップしたファイルの GUI への通知
+    ///
+    /// # 事後条件
+    /// - ダウンロード済みのファイルと URL 未発行のファイルはスキップとして recorder に記録される
+    /// - sources には全対象ファイルが出力ファイル名をキーとして含まれる
+    fn plan_downloads(
+        files: &[(&MeetingRecording, &RecordingFile)],
+        options: &DownloadOptions,
+        sender: &mpsc::Sender<AppMessage>,
+    ) -> DownloadPlan {
+        let total_files = files.len();
         let _ = sender.send(AppMessage::DownloadProgress(format!(
             "Preparing to download {} files...",
             total_files
         )));
 
         // DownloadComponent用のタスク情報を事前に収集
-        let access_token = access_token.to_string();
-        let output_dir = output_dir.to_string();
-        let mut tasks: Vec<(String, String, String, Option<u64>)> = Vec::new();
+        let output_dir = PathBuf::from(&options.output_dir);
+        let mut tasks: Vec<DownloadTask> = Vec::new();
         let mut skipped_files: Vec<String> = Vec::new();
         let mut summary_fallback_targets: Vec<(MeetingRecording, RecordingFile)> = Vec::new();
+        let mut manifest = Self::open_manifest(&output_dir);
+        let mut manifest_targets: HashMap<String, ManifestTarget> = HashMap::new();
+        let mut up_to_date_files: Vec<String> = Vec::new();
+        let mut recorder = RunRecorder::new();
+        let mut sources: HashMap<String, (MeetingRecording, RecordingFile)> = HashMap::new();
+
+        for (meeting, file) in files {
+            let file_name = options.settings.file_path(meeting, file);
+            sources.insert(file_name.clone(), ((*meeting).clone(), (*file).clone()));
+            let file_size = if file.file_size > 0 {
+                Some(file.file_size)
+            } else {
+                None
+            };
+            let manifest_key = Self::manifest_key(meeting, file);
+            let report_file = ReportFile::new(meeting, file, &file_name);
+            if Self::is_already_downloaded(&mut manifest, &manifest_key, &file_name, file_size) {
+                recorder.skip(report_file, SkipReason::AlreadyDownloaded);
+                up_to_date_files.push(file_name);
+                continue;
+            }
 
-        for (meeting, file) in &files_to_download {
             if file.download_url.is_empty() {
                 if file.file_type == RecordingFileType::Summary {
                     // SUMMARYファイルはMeeting Summary APIでフォールバック取得
@@ -381,30 +959,50 @@ impl DownloadService for RealDownloadService {
                         file.file_type, meeting.topic, meeting.start_time
                     );
                     log::warn!("[DL-DIAG] Skipping file with empty download_url: {}", msg);
+                    recorder.skip(report_file, SkipReason::EmptyDownloadUrl);
                     skipped_files.push(msg);
                 }
                 continue;
             }
             let task_id = format!("{}-{}", meeting.uuid, file.stable_id());
-            // Zoom APIではdownload_urlにaccess_tokenをクエリパラメータで付与する必要がある
-            let download_url = if file.download_url.contains('?') {
-                format!("{}&access_token={}", file.download_url, access_token)
-            } else {
-                format!("{}?access_token={}", file.download_url, access_token)
-            };
-            let file_name = crate::generate_file_path(meeting, file);
-            let file_size = if file.file_size > 0 {
-                Some(file.file_size)
-            } else {
-                None
-            };
+            let download_url = file.download_url.clone();
+            manifest_targets.insert(task_id.clone(), ManifestTarget::new(meeting, file));
+            recorder.track(task_id.clone(), report_file);
             log::info!(
                 "[DL-DIAG] Task created: id={}, type={}, url_len={}",
                 task_id,
                 file.file_type,
                 file.download_url.len()
             );
-            tasks.push((task_id, download_url, file_name, file_size));
+            tasks.push(DownloadTask {
+                task_id,
+                download_url,
+                output_path: output_dir.join(&file_name),
+                expected_size: file_size,
+                file_name,
+                state: TaskState::Pending,
+                progress: DownloadProgress::default(),
+                error: None,
+                retry_count: 0,
+                priority: TaskPriority::for_file_type(&file.file_type),
+                attempts: Vec::new(),
+                retry_at: None,
+                sha256: None,
+            });
+        }
+
+        // GUIに差分同期のスキップ通知
+        if !up_to_date_files.is_empty() {
+            let _ = sender.send(AppMessage::DownloadProgress(format!(
+                "{} file(s) already downloaded, skipped",
+                up_to_date_files.len()
+            )));
+            for file_name in &up_to_date_files {
+                let _ = sender.send(AppMessage::DownloadProgress(format!(
+                    "  Up to date: {}",
+                    file_name
+                )));
+            }
         }
 
         // GUIにスキップ通知
@@ -418,23 +1016,143 @@ impl DownloadService for RealDownloadService {
             }
         }
 
+        DownloadPlan {
+            tasks,
+            summary_fallback_targets,
+            manifest_targets,
+            recorder,
+            sources,
+        }
+    }
+
+    /// ジャーナルの未完了エントリから再開するダウンロードの内容を組み立てる
+    ///
+    /// # 事後条件
+    /// - 取得元が記録されたエントリは、新規のダウンロードと同様にマニフェスト・実行レポート・
+    ///   失敗ファイル記録の対象になる
+    /// - 取得元のないエントリは出力ファイル名のみで実行レポートに記録される
+    fn plan_resume(entries: &[JournalEntry]) -> DownloadPlan {
+        let mut tasks: Vec<DownloadTask> = Vec::new();
+        let mut manifest_targets: HashMap<String, ManifestTarget> = HashMap::new();
+        let mut recorder = RunRecorder::new();
+        let mut sources: HashMap<String, (MeetingRecording, RecordingFile)> = HashMap::new();
+
+        for entry in entries {
+            let task = entry.to_task(entry.download_url.clone());
+            match &entry.source {
+                Some(JournalSource { meeting, file }) => {
+                    manifest_targets
+                        .insert(task.task_id.clone(), ManifestTarget::new(meeting, file));
+                    recorder.track(
+                        task.task_id.clone(),
+                        ReportFile::new(meeting, file, &task.file_name),
+                    );
+                    sources.insert(task.file_name.clone(), (meeting.clone(), file.clone()));
+                }
+                None => recorder.track(
+                    task.task_id.clone(),
+                    ReportFile::unattributed(&task.file_name),
+                ),
+            }
+            tasks.push(task);
+        }
+
+        DownloadPlan {
+            tasks,
+            summary_fallback_targets: Vec::new(),
+            manifest_targets,
+            recorder,
+            sources,
+        }
+    }
+
+    /// DownloadComponent でタスクを実行し、進捗をGUIへ通知する
+    ///
+    /// # 副作用
+    /// - tokio runtime の作成とダウンロードワーカーの起動
+    /// - ジャーナルファイルへのタスク状態と取得元（manifest_targets に含まれるタスク）の記録
+    ///
+    /// # 事後条件
+    /// - 全タスク終了時は Completed、キャンセル時は Cancelled を返す
+    /// - 完了・キャンセル済みのタスクはジャーナルから取り除かれる
+    /// - manifest_targets（task_id → 記録先）に含まれる完了ファイルはマニフェストとアーカイブマニフェストに記録される
+    /// - 実行レポートを出力ディレクトリの reports フォルダへ保存し、RunReportReady で通知する
+    /// - sources がある場合は再試行できるファイルで失敗ファイル記録を置き換え、RetryableFilesUpdated で通知する
+    fn run_download_tasks(
+        plan: DownloadPlan,
+        tokens: Arc<dyn TokenProvider>,
+        options: &DownloadOptions,
+        control: DownloadControl,
+        sender: mpsc::Sender<AppMessage>,
+    ) -> Result<DownloadOutcome, Box<dyn std::error::Error + Send + Sync>> {
+        Self::run_download_tasks_with(
+            plan,
+            tokens,
+            options,
+            control,
+            sender,
+            Self::open_journal(),
+            DEFAULT_EVENT_BUS_CAPACITY,
+        )
+    }
+
+    /// run_download_tasks の本体（ジャーナルとイベントバスの容量を指定する）
+    ///
+    /// # 事後条件
+    /// - イベントバスで取りこぼした完了・失敗などの最終結果も、マニフェスト・件数・実行レポートに反映される
+    fn run_download_tasks_with(
+        plan: DownloadPlan,
+        tokens: Arc<dyn TokenProvider>,
+        options: &DownloadOptions,
+        control: DownloadControl,
+        sender: mpsc::Sender<AppMessage>,
+        journal: Option<Arc<std::sync::Mutex<DownloadJournal>>>,
+        event_capacity: usize,
+    ) -> Result<DownloadOutcome, Box<dyn std::error::Error + Send + Sync>> {
+        let DownloadPlan {
+            tasks,
+            summary_fallback_targets,
+            manifest_targets,
+            mut recorder,
+            sources,
+        } = plan;
+        let report = &mut recorder;
+        let output_dir = options.output_dir.clone();
+        let settings = options.settings.clone();
+        let endpoints = options.endpoints.clone();
+        let component_journal = journal.clone();
+        let mut manifest = Self::open_manifest(Path::new(&output_dir));
         let sender_clone = sender.clone();
 
         // tokio runtimeで非同期ダウンロードを実行
         let rt = tokio::runtime::Runtime::new()
             .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> { Box::new(e) })?;
 
-        let downloaded_files = rt.block_on(async move {
+        let outcome = rt.block_on(async move {
             // DownloadComponent を設定
             let config = DownloadConfig {
                 output_directory: PathBuf::from(&output_dir),
+                keep_partial_on_cancel: settings.keep_partial_on_cancel,
+                max_bytes_per_sec: settings.max_bytes_per_sec,
+                min_free_space_bytes: settings.min_free_space_bytes(),
+                adaptive_concurrency: settings.adaptive(),
+                segmented: settings.segmented(),
+                stall_detection: settings.stall_detection(),
+                bandwidth_schedule: settings.bandwidth_schedule.clone(),
+                conflict_policy: settings.conflict_policy,
                 ..DownloadConfig::default()
             };
             let mut component = DownloadComponent::new(config);
+            component.set_event_capacity(event_capacity);
+            // access_token は URL ではなく Authorization ヘッダーで送り、期限切れ時は更新する
+            component.set_token_provider(tokens.clone());
+            component.set_control(control.clone());
+            if let Some(journal) = &component_journal {
+                component.set_journal(journal.clone());
+            }
 
-            // イベントリスナーを設定
-            let (event_tx, mut event_rx) = tokio::sync::mpsc::unbounded_channel::<DownloadEvent>();
-            component.set_event_listener(event_tx);
+            // イベントを購読
+            let mut events = component.subscribe();
 
             // 出力ディレクトリを作成
             tokio::fs::create_dir_all(&output_dir).await.map_err(
@@ -446,22 +1164,42 @@ impl DownloadService for RealDownloadService {
                 },
             )?;
 
-            // タスクを追加
-            for (task_id, download_url, file_name, file_size) in &tasks {
-                component
-                    .add_download_task(
-                        task_id.clone(),
-                        download_url.clone(),
-                        file_name.clone(),
-                        *file_size,
-                    )
-                    .await
-                    .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> {
+            // タスクを追加（進捗画面には待機中の行として表示）
+            for task in &tasks {
+                let _ = sender_clone.send(AppMessage::DownloadFileProgress {
+                    task_id: task.task_id.clone(),
+                    file_name: task.file_name.clone(),
+                    state: TaskState::Pending,
+                    progress: Some(DownloadProgress {
+                        total_bytes: task.expected_size,
+                        ..DownloadProgress::default()
+                    }),
+                });
+                let _ = sender_clone.send(AppMessage::DownloadTaskPriority {
+                    task_id: task.task_id.clone(),
+                    priority: task.priority,
+                });
+                component.add_task(task.clone()).await.map_err(
+                    |e| -> Box<dyn std::error::Error + Send + Sync> {
                         Box::new(std::io::Error::new(
                             std::io::ErrorKind::Other,
                             format!("Failed to add task: {}", e),
                         ))
-                    })?;
+                    },
+                )?;
+                // 再開時にマニフェストへ記録できるよう、取得元をジャーナルに残す
+                if let (Some(journal), Some(target)) =
+                    (&component_journal, manifest_targets.get(&task.task_id))
+                {
+                    let source = JournalSource::new(&target.meeting, &target.file);
+                    let attached = match journal.lock() {
+                        Ok(mut journal) => journal.attach_source(&task.task_id, source),
+                        Err(_) => Err(AppError::data_integrity("Download journal lock poisoned")),
+                    };
+                    if let Err(e) = attached {
+                        log::warn!("Failed to record download source in journal: {}", e);
+                    }
+                }
             }
 
             // ダウンロード開始
@@ -478,65 +1216,205 @@ impl DownloadService for RealDownloadService {
             let mut completed_files: Vec<String> = Vec::new();
             let mut completed_count = 0u32;
             let mut failed_count = 0u32;
+            let mut skipped_count = 0u32;
+            let mut cancelled_tasks = 0usize;
+            let mut cancelled = false;
+            // バスで取りこぼし、記録から補った最終結果イベント
+            let mut recovered: VecDeque<EventEnvelope<DownloadEvent>> = VecDeque::new();
+            // バスで受信した最後の通番と、記録から補った最後の通番
+            let mut received_through = 0u64;
+            let mut recovered_through = 0u64;
             let download_task_count = tasks.len() as u32;
 
-            while download_task_count > 0 && completed_count + failed_count < download_task_count {
-                match event_rx.recv().await {
-                    Some(event) => match event {
-                        DownloadEvent::TaskStarted { task_id } => {
-                            // task_idからファイル名を取得
-                            let file_name = tasks
-                                .iter()
-                                .find(|(id, _, _, _)| *id == task_id)
-                                .map(|(_, _, name, _)| name.as_str())
-                                .unwrap_or("unknown");
-                            let _ = sender_clone.send(AppMessage::DownloadProgress(format!(
-                                "Downloading: {}",
-                                file_name
-                            )));
-                        }
-                        DownloadEvent::ProgressUpdate { task_id, progress } => {
-                            let file_name = tasks
-                                .iter()
-                                .find(|(id, _, _, _)| *id == task_id)
-                                .map(|(_, _, name, _)| name.as_str())
-                                .unwrap_or("unknown");
-                            let speed_mbps = progress.current_speed / 1024.0 / 1024.0;
-                            let _ = sender_clone.send(AppMessage::DownloadProgress(format!(
-                                "{}: {:.1}% ({:.1} MB/s)",
-                                file_name,
-                                progress.percentage * 100.0,
-                                speed_mbps
-                            )));
+            while download_task_count > 0
+                && !cancelled
+                && completed_count + failed_count + skipped_count < download_task_count
+            {
+                let event = match recovered.pop_front() {
+                    Some(envelope) => envelope.event,
+                    None => match events.recv().await {
+                        Some(BusMessage::Event(envelope)) => {
+                            received_through = envelope.sequence;
+                            // 記録から補ったイベントの重複と、それより古いタスクの進捗は読み捨てる
+                            if envelope.sequence <= recovered_through
+                                && Self::is_task_progress_or_outcome(&envelope.event)
+                            {
+                                continue;
+                            }
+                            // 最終状態はループ後にタスクの最終結果の記録から確定する
+                            if !envelope.event.is_outcome() {
+                                report.observe(&envelope);
+                            }
+                            envelope.event
                         }
-                        DownloadEvent::TaskCompleted { output_path, .. } => {
-                            completed_count += 1;
-                            let path_str = output_path.to_string_lossy().to_string();
-                            completed_files.push(path_str.clone());
+                        Some(BusMessage::Lagged { skipped }) => {
+                            // 取りこぼした完了・失敗などの最終結果は記録から補う
                             let _ = sender_clone.send(AppMessage::DownloadProgress(format!(
-                                "Completed ({}/{}): {}",
-                                completed_count, download_task_count, path_str
+                                "Progress updates fell behind, {} event(s) skipped",
+                                skipped
                             )));
+                            for envelope in component.task_outcomes() {
+                                if envelope.sequence > received_through.max(recovered_through) {
+                                    recovered_through = envelope.sequence;
+                                    recovered.push_back(envelope);
+                                }
+                            }
+                            continue;
                         }
-                        DownloadEvent::TaskFailed { task_id, error } => {
-                            failed_count += 1;
-                            let file_name = tasks
-                                .iter()
-                                .find(|(id, _, _, _)| *id == task_id)
-                                .map(|(_, _, name, _)| name.as_str())
-                                .unwrap_or("unknown");
-                            let _ = sender_clone.send(AppMessage::DownloadProgress(format!(
-                                "Failed: {} - {}",
-                                file_name, error
-                            )));
+                        // バスが閉じた
+                        None => break,
+                    },
+                };
+                match event {
+                    DownloadEvent::TaskStarted { task_id } => {
+                        let file_name = Self::task_file_name(&tasks, &task_id);
+                        let _ = sender_clone.send(AppMessage::DownloadProgress(format!(
+                            "Downloading: {}",
+                            file_name
+                        )));
+                        let _ = sender_clone.send(AppMessage::DownloadFileProgress {
+                            task_id,
+                            file_name,
+                            state: TaskState::InProgress,
+                            progress: None,
+                        });
+                    }
+                    DownloadEvent::ProgressUpdate { task_id, progress } => {
+                        let file_name = Self::task_file_name(&tasks, &task_id);
+                        let _ = sender_clone.send(AppMessage::DownloadFileProgress {
+                            task_id,
+                            file_name,
+                            state: TaskState::InProgress,
+                            progress: Some(progress),
+                        });
+                    }
+                    DownloadEvent::TaskCompleted {
+                        task_id,
+                        output_path,
+                        sha256,
+                    } => {
+                        completed_count += 1;
+                        if let Some(target) = manifest_targets.get(&task_id) {
+                            let relative = output_path
+                                .strip_prefix(&output_dir)
+                                .unwrap_or(&output_path)
+                                .to_path_buf();
+                            tokio::task::block_in_place(|| {
+                                Self::record_completed(
+                                    &mut manifest,
+                                    Path::new(&output_dir),
+                                    target,
+                                    &relative,
+                                    sha256,
+                                )
+                            });
                         }
-                        DownloadEvent::OverallProgressUpdate(_) => {
-                            // 全体進捗更新（現時点では未実装部分のためスキップ）
+                        let path_str = output_path.to_string_lossy().to_string();
+                        completed_files.push(path_str.clone());
+                        let _ = sender_clone.send(AppMessage::DownloadProgress(format!(
+                            "Completed ({}/{}): {}",
+                            completed_count, download_task_count, path_str
+                        )));
+                        let file_name = Self::task_file_name(&tasks, &task_id);
+                        let _ = sender_clone.send(AppMessage::DownloadFileProgress {
+                            task_id,
+                            file_name,
+                            state: TaskState::Completed,
+                            progress: None,
+                        });
+                    }
+                    DownloadEvent::TaskFailed {
+                        task_id,
+                        error,
+                        reason,
+                    } => {
+                        failed_count += 1;
+                        let file_name = Self::task_file_name(&tasks, &task_id);
+                        let label = match reason {
+                            FailureReason::Stalled => "Stalled",
+                            FailureReason::Error => "Failed",
+                        };
+                        let _ = sender_clone.send(AppMessage::DownloadProgress(format!(
+                            "{}: {} - {}",
+                            label, file_name, error
+                        )));
+                        let _ = sender_clone.send(AppMessage::DownloadFileProgress {
+                            task_id,
+                            file_name,
+                            state: TaskState::Failed,
+                            progress: None,
+                        });
+                    }
+                    DownloadEvent::TaskSkipped {
+                        task_id,
+                        output_path,
+                    } => {
+                        skipped_count += 1;
+                        let _ = sender_clone.send(AppMessage::DownloadProgress(format!(
+                            "Skipped (file exists): {}",
+                            output_path.display()
+                        )));
+                        let file_name = Self::task_file_name(&tasks, &task_id);
+                        let _ = sender_clone.send(AppMessage::DownloadFileProgress {
+                            task_id,
+                            file_name,
+                            state: TaskState::Completed,
+                            progress: None,
+                        });
+                    }
+                    DownloadEvent::OverallProgressUpdate(overall) => {
+                        let _ = sender_clone.send(AppMessage::DownloadOverallProgress(overall));
+                    }
+                    DownloadEvent::LowDiskSpace {
+                        available_bytes,
+                        reserve_bytes,
+                    } => {
+                        let _ = sender_clone.send(AppMessage::DownloadLowDiskSpace {
+                            available_bytes,
+                            reserve_bytes,
+                        });
+                    }
+                    DownloadEvent::ConcurrencyChanged {
+                        concurrency,
+                        reason,
+                    } => {
+                        let _ = sender_clone.send(AppMessage::DownloadProgress(format!(
+                            "Concurrency: {} ({})",
+                            concurrency, reason
+                        )));
+                    }
+                    DownloadEvent::Paused => {
+                        let _ = sender_clone.send(AppMessage::DownloadPaused);
+                    }
+                    DownloadEvent::Resumed => {
+                        let _ = sender_clone.send(AppMessage::DownloadResumed);
+                    }
+                    DownloadEvent::TaskCancelled { task_id } => {
+                        cancelled_tasks += 1;
+                        let file_name = Self::task_file_name(&tasks, &task_id);
+                        let _ = sender_clone.send(AppMessage::DownloadProgress(format!(
+                            "Cancelled: {}",
+                            file_name
+                        )));
+                        let _ = sender_clone.send(AppMessage::DownloadFileProgress {
+                            task_id,
+                            file_name,
+                            state: TaskState::Cancelled,
+                            progress: None,
+                        });
+                    }
+                    DownloadEvent::Cancelled { pending_tasks } => {
+                        cancelled_tasks += pending_tasks.len();
+                        cancelled = true;
+                        for task_id in pending_tasks {
+                            let file_name = Self::task_file_name(&tasks, &task_id);
+                            let _ = sender_clone.send(AppMessage::DownloadFileProgress {
+                                task_id,
+                                file_name,
+                                state: TaskState::Cancelled,
+                                progress: None,
+                            });
                         }
-                    },
-                    None => {
-                        // チャネルが閉じた
-                        break;
                     }
                 }
             }
@@ -544,6 +1422,27 @@ impl DownloadService for RealDownloadService {
             // シャットダウン
             let _ = component.stop_downloads().await;
 
+            // 実行レポートの最終状態は、バスでの取りこぼしに関係なくタスクの最終結果から確定する
+            for envelope in component.task_outcomes() {
+                report.observe(&envelope);
+            }
+
+            if cancelled || control.is_cancelled() {
+                for (meeting, file) in &summary_fallback_targets {
+                    let file_name = settings.file_path(meeting, file);
+                    report.record(
+                        ReportFile::new(meeting, file, &file_name),
+                        FinalState::Cancelled,
+                        None,
+                        None,
+                    );
+                }
+                return Ok(DownloadOutcome::Cancelled {
+                    completed_files,
+                    cancelled_tasks: cancelled_tasks + summary_fallback_targets.len(),
+                });
+            }
+
             // Meeting Summary APIフォールバック処理
             if !summary_fallback_targets.is_empty() {
                 let _ = sender_clone.send(AppMessage::DownloadProgress(format!(
@@ -551,25 +1450,34 @@ impl DownloadService for RealDownloadService {
                     summary_fallback_targets.len()
                 )));
 
-                let api = ApiComponent::new(ApiConfig::default());
-                let token = AuthToken {
-                    access_token: access_token.clone(),
-                    token_type: "Bearer".to_string(),
-                    expires_at: chrono::Utc::now() + chrono::Duration::hours(1),
-                    refresh_token: None,
-                    scopes: vec!["recording:read".to_string()],
-                };
-                api.set_auth_token(token).await;
+                let api = new_api_component(tokens, &endpoints);
+
+                for (index, (meeting, file)) in summary_fallback_targets.iter().enumerate() {
+                    if control.is_cancelled() {
+                        for (meeting, file) in &summary_fallback_targets[index..] {
+                            let file_name = settings.file_path(meeting, file);
+                            report.record(
+                                ReportFile::new(meeting, file, &file_name),
+                                FinalState::Cancelled,
+                                None,
+                                None,
+                            );
+                        }
+                        return Ok(DownloadOutcome::Cancelled {
+                            completed_files,
+                            cancelled_tasks: summary_fallback_targets.len() - index,
+                        });
+                    }
 
-                for (meeting, file) in &summary_fallback_targets {
                     let _ = sender_clone.send(AppMessage::DownloadProgress(format!(
                         "Fetching AI summary: {}",
                         meeting.topic
                     )));
 
+                    let file_name = settings.file_path(meeting, file);
+                    let report_file = ReportFile::new(meeting, file, &file_name);
                     match api.get_meeting_summary(&meeting.uuid).await {
                         Ok(Some(summary)) => {
-                            let file_name = crate::generate_file_path(meeting, file);
                             let output_path = PathBuf::from(&output_dir).join(&file_name);
 
                             if let Some(parent) = output_path.parent() {
@@ -581,6 +1489,19 @@ impl DownloadService for RealDownloadService {
                                     match tokio::fs::write(&output_path, json_str.as_bytes()).await
                                     {
                                         Ok(_) => {
+                                            Self::record_completed(
+                                                &mut manifest,
+                                                Path::new(&output_dir),
+                                                &ManifestTarget::new(meeting, file),
+                                                Path::new(&file_name),
+                                                None,
+                                            );
+                                            report.record(
+                                                report_file,
+                                                FinalState::Completed,
+                                                Some(json_str.len() as u64),
+                                                None,
+                                            );
                                             let path_str =
                                                 output_path.to_string_lossy().to_string();
                                             completed_files.push(path_str.clone());
@@ -591,6 +1512,12 @@ impl DownloadService for RealDownloadService {
                                         }
                                         Err(e) => {
                                             log::error!("Failed to write summary file: {}", e);
+                                            report.record(
+                                                report_file,
+                                                FinalState::Failed,
+                                                None,
+                                                Some(format!("Failed to save AI summary: {}", e)),
+                                            );
                                             let _ =
                                                 sender_clone.send(AppMessage::DownloadProgress(
                                                     format!("Failed to save AI summary: {}", e),
@@ -600,11 +1527,18 @@ impl DownloadService for RealDownloadService {
                                 }
                                 Err(e) => {
                                     log::error!("Failed to serialize summary: {}", e);
+                                    report.record(
+                                        report_file,
+                                        FinalState::Failed,
+                                        None,
+                                        Some(format!("Failed to serialize AI summary: {}", e)),
+                                    );
                                 }
                             }
                         }
                         Ok(None) => {
                             log::info!("No AI summary available for meeting_id={}", meeting.id);
+                            report.skip(report_file, SkipReason::NoAiSummary);
                             let _ = sender_clone.send(AppMessage::DownloadProgress(format!(
                                 "AI summary not available: {}",
                                 meeting.topic
@@ -620,17 +1554,293 @@ impl DownloadService for RealDownloadService {
                                 "AI summary fetch failed: {} - {}",
                                 meeting.topic, e
                             )));
+                            report.record(
+                                report_file,
+                                FinalState::Failed,
+                                None,
+                                Some(e.to_string()),
+                            );
                         }
                     }
                 }
             }
 
-            let _ = sender_clone.send(AppMessage::DownloadComplete(completed_files.clone()));
-
-            Ok::<Vec<String>, Box<dyn std::error::Error + Send + Sync>>(completed_files)
+            Ok::<DownloadOutcome, Box<dyn std::error::Error + Send + Sync>>(
+                DownloadOutcome::Completed(completed_files),
+            )
         })?;
 
-        Ok(downloaded_files)
+        // 完了・キャンセル済みのタスクはジャーナルから取り除く
+        if let Some(journal) = &journal {
+            if let Ok(mut journal) = journal.lock() {
+                if let Err(e) = journal.prune_finished() {
+                    log::warn!("Failed to prune download journal: {}", e);
+                }
+            }
+        }
+
+        // 実行レポートを保存してGUIへ渡す
+        let report = recorder.finish(matches!(outcome, DownloadOutcome::Cancelled { .. }));
+        match report.export(&options.output_dir, ReportFormat::Json) {
+            Ok(path) => log::info!("Run report saved: {}", path.display()),
+            Err(e) => log::warn!("Failed to save run report: {}", e),
+        }
+
+        // 再試行できるファイルを次回以降のために記録する
+        if !sources.is_empty() {
+            let items = failed_batch::retryable_items(&report, &sources);
+            Self::remember_failed_items(report.finished_at, items.clone());
+            let _ = sender.send(AppMessage::RetryableFilesUpdated(items));
+        }
+        let _ = sender.send(AppMessage::RunReportReady(report));
+
+        Ok(outcome)
+    }
+
+    /// マニフェストのキーを取得
+    ///
+    /// 通常は RecordingFile::stable_id。IDが空のファイルは stable_id が会議間で重複するため
+    /// 会議UUIDを付与する。
+    fn manifest_key(meeting: &MeetingRecording, file: &RecordingFile) -> String {
+        if file.id.is_empty() {
+            format!("{}-{}", meeting.uuid, file.stable_id())
+        } else {
+            file.stable_id()
+        }
+    }
+
+    /// ゴミ箱へ移動する直前に、事前確認時と同じ内容のファイルが残っているか再検証する
+    ///
+    /// # 事後条件
+    /// - すべてのファイルが事前確認時と同じ SHA-256 で残っている場合のみ None
+    /// - それ以外は移動しない理由が返される
+    fn reverify_trash_item(
+        manifest: &DownloadManifest,
+        output_dir: &Path,
+        item: &TrashItem,
+    ) -> Option<String> {
+        item.files.iter().find_map(|file| {
+            let LocalVerification::Verified { sha256, .. } = &file.verification else {
+                return Some(format!("{}: 検証されていません", file.local_path));
+            };
+            match trash::verify_local_file(
+                manifest,
+                output_dir,
+                &file.recording_id,
+                Path::new(&file.local_path),
+                None,
+            ) {
+                LocalVerification::Verified {
+                    sha256: current, ..
+                } if current == *sha256 => None,
+                LocalVerification::Verified { .. } => Some(format!(
+                    "{}: 事前確認の後に内容が変更されました",
+                    file.local_path
+                )),
+                other => Some(format!(
+                    "{}: {}",
+                    file.local_path,
+                    other.block_reason().unwrap_or_default()
+                )),
+            }
+        })
+    }
+
+    /// 出力ディレクトリのマニフェストを開く（破損時は新しいマニフェストで置き換える）
+    fn open_manifest(output_dir: &Path) -> DownloadManifest {
+        DownloadManifest::open(output_dir).unwrap_or_else(|e| {
+            log::warn!("Download manifest unavailable, starting a new one: {}", e);
+            DownloadManifest::new(output_dir)
+        })
+    }
+
+    /// ダウンロード済みで内容が記録どおり残っているファイルか判定する
+    ///
+    /// # 副作用
+    /// - 無効になった記録はマニフェストから取り除かれる
+    /// - 期待サイズと一致する未記録の既存ファイルはマニフェストに取り込まれる
+    fn is_already_downloaded(
+        manifest: &mut DownloadManifest,
+        key: &str,
+        file_name: &str,
+        expected_size: Option<u64>,
+    ) -> bool {
+        let relative = Path::new(file_name);
+        match manifest.check(key, relative, expected_size) {
+            ManifestStatus::Intact => true,
+            ManifestStatus::Stale(reason) => {
+                log::info!(
+                    "Manifest entry is stale ({}), re-downloading: {}",
+                    reason,
+                    file_name
+                );
+                if let Err(e) = manifest.remove(key) {
+                    log::warn!("Failed to update download manifest: {}", e);
+                }
+                false
+            }
+            ManifestStatus::NotRecorded => manifest
+                .adopt_existing(key, relative, expected_size)
+                .unwrap_or_else(|e| {
+                    log::warn!("Failed to adopt existing file {}: {}", file_name, e);
+                    false
+                }),
+        }
+    }
+
+    /// 完了したファイルをマニフェストとアーカイブマニフェストに記録する（失敗時は警告のみ）
+    ///
+    /// # 事後条件
+    /// - sha256 が None の場合はファイルを読み込んで計算する
+    /// - アーカイブマニフェストはファイルと同じフォルダーの manifest.json に記録される
+    fn record_completed(
+        manifest: &mut DownloadManifest,
+        output_dir: &Path,
+        target: &ManifestTarget,
+        relative_path: &Path,
+        sha256: Option<String>,
+    ) {
+        let full_path = output_dir.join(relative_path);
+        let sha256 = match sha256.map_or_else(|| sha256_file(&full_path), Ok) {
+            Ok(sha256) => sha256,
+            Err(e) => {
+                log::warn!("Failed to hash {}: {}", relative_path.display(), e);
+                return;
+            }
+        };
+
+        if let Err(e) = manifest.record_hashed(&target.key, relative_path, sha256.clone()) {
+            log::warn!(
+                "Failed to record {} in download manifest: {}",
+                relative_path.display(),
+                e
+            );
+        }
+
+        let (Some(directory), Some(file_name)) = (full_path.parent(), full_path.file_name()) else {
+            return;
+        };
+        let recorded = ArchiveManifest::open(directory).and_then(|mut archive| {
+            archive.record(
+                &target.meeting,
+                &target.file,
+                &file_name.to_string_lossy(),
+                sha256,
+            )
+        });
+        if let Err(e) = recorded {
+            log::warn!(
+                "Failed to record {} in archive manifest: {}",
+                relative_path.display(),
+                e
+            );
+        }
+    }
+
+    /// タスクの進捗・最終結果のイベントか（記録から補った後に届いた古いものは読み捨てる）
+    fn is_task_progress_or_outcome(event: &DownloadEvent) -> bool {
+        match event {
+            DownloadEvent::TaskStarted { .. }
+            | DownloadEvent::ProgressUpdate { .. }
+            | DownloadEvent::OverallProgressUpdate(_)
+            | DownloadEvent::TaskCompleted { .. }
+            | DownloadEvent::TaskFailed { .. }
+            | DownloadEvent::TaskSkipped { .. }
+            | DownloadEvent::TaskCancelled { .. }
+            | DownloadEvent::Cancelled { .. } => true,
+            DownloadEvent::LowDiskSpace { .. }
+            | DownloadEvent::ConcurrencyChanged { .. }
+            | DownloadEvent::Paused
+            | DownloadEvent::Resumed => false,
+        }
+    }
+
+    /// タスクIDからファイル名を取得
+    fn task_file_name(tasks: &[DownloadTask], task_id: &str) -> String {
+        tasks
+            .iter()
+            .find(|task| task.task_id == task_id)
+            .map(|task| task.file_name.clone())
+            .unwrap_or_else(|| "unknown".to_string())
+    }
+
+    /// 失敗ファイル記録を新しいバッチの結果で置き換える（読めない記録は破棄する）
+    fn remember_failed_items(finished_at: chrono::DateTime<chrono::Utc>, items: Vec<FailedItem>) {
+        let path = match FailedBatch::default_path() {
+            Ok(path) => path,
+            Err(e) => {
+                log::warn!("Failed download list disabled: {}", e);
+                return;
+            }
+        };
+        let mut batch = FailedBatch::open(&path).unwrap_or_else(|e| {
+            log::warn!("Discarding unreadable failed download list: {}", e);
+            FailedBatch::new(&path)
+        });
+        if let Err(e) = batch.replace(finished_at, items) {
+            log::warn!("Failed to save failed download list: {}", e);
+        }
+    }
+
+    /// 再試行するファイルの download_url を録画APIから取り直す
+    ///
+    /// 会議ごとに1回だけ取得し、stable_id が一致するファイルの情報で置き換える。
+    /// 会議情報は保存時のものを使い続けるため、出力ファイル名は前回と変わらない。
+    /// AI要約は Meeting Summary API で取得するため対象外。
+    ///
+    /// # 副作用
+    /// - HTTPリクエストの送信
+    ///
+    /// # 事後条件
+    /// - 取得に失敗した会議のファイルは保存時の情報のまま残る
+    async fn refresh_download_urls(api: &ApiComponent, items: &mut [FailedItem]) {
+        let mut meeting_uuids: Vec<String> = Vec::new();
+        for item in items.iter() {
+            if item.file.file_type != RecordingFileType::Summary
+                && !meeting_uuids.contains(&item.meeting.uuid)
+            {
+                meeting_uuids.push(item.meeting.uuid.clone());
+            }
+        }
+
+        for uuid in meeting_uuids {
+            let fresh = match api.get_meeting_recordings(&uuid).await {
+                Ok(fresh) => fresh,
+                Err(e) => {
+                    log::warn!(
+                        "Could not refresh download URLs for meeting_uuid={}: {}",
+                        uuid,
+                        e
+                    );
+                    continue;
+                }
+            };
+            for item in items.iter_mut().filter(|item| item.meeting.uuid == uuid) {
+                if let Some(file) = fresh
+                    .recording_files
+                    .iter()
+                    .find(|file| file.stable_id() == item.file.stable_id())
+                {
+                    item.file = file.clone();
+                }
+            }
+        }
+    }
+
+    /// 既定パスのジャーナルを開く（破損時は新しいジャーナルで置き換える）
+    fn open_journal() -> Option<Arc<std::sync::Mutex<DownloadJournal>>> {
+        let path = match DownloadJournal::default_path() {
+            Ok(path) => path,
+            Err(e) => {
+                log::warn!("Download journal disabled: {}", e);
+                return None;
+            }
+        };
+        let journal = DownloadJournal::open(&path).unwrap_or_else(|e| {
+            log::warn!("Discarding unreadable download journal: {}", e);
+            DownloadJournal::new(&path)
+        });
+        Some(Arc::new(std::sync::Mutex::new(journal)))
     }
 }
 
@@ -645,12 +1855,18 @@ pub struct AppServices {
 
 impl Default for AppServices {
     fn default() -> Self {
+        // 認証・録画取得・ダウンロードで同じトークンを共有する
+        let session = AuthTokenProvider::default();
         Self {
             config_service: Box::new(RealConfigService),
-            auth_service: Arc::new(RealAuthService),
-            recording_service: Arc::new(RealRecordingService),
+            auth_service: Arc::new(RealAuthService {
+                session: session.clone(),
+            }),
+            recording_service: Arc::new(RealRecordingService {
+                session: session.clone(),
+            }),
             browser_launcher: Box::new(RealBrowserLauncher),
-            download_service: Arc::new(RealDownloadService),
+            download_service: Arc::new(RealDownloadService { session }),
         }
     }
 }
@@ -666,6 +1882,7 @@ mod tests {
             id: 123,
             account_id: String::new(),
             host_id: "host1".to_string(),
+            host_email: String::new(),
             topic: "Test Meeting".to_string(),
             meeting_type: 2,
             start_time: "2025-01-01T00:00:00Z".to_string(),
@@ -827,4 +2044,331 @@ mod tests {
         assert_eq!(result[0].1.file_type, RecordingFileType::Summary);
         assert_eq!(result[0].1.stable_id(), "auto_summary");
     }
+
+    #[test]
+    fn test_manifest_key_disambiguates_files_without_id() {
+        let summary_file = make_file("", RecordingFileType::Summary, "");
+        let mp4_file = make_file("file1", RecordingFileType::MP4, "https://example.com/a");
+        let meeting = make_meeting("uuid-1", vec![]);
+
+        assert_eq!(
+            RealDownloadService::manifest_key(&meeting, &mp4_file),
+            "file1"
+        );
+        assert_eq!(
+            RealDownloadService::manifest_key(&meeting, &summary_file),
+            "uuid-1-auto_summary"
+        );
+    }
+
+    #[test]
+    fn test_is_already_downloaded_skips_intact_and_detects_stale_files() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut manifest = DownloadManifest::open(temp_dir.path()).unwrap();
+        let file_name = "2025-01-01/meeting.mp4";
+        std::fs::create_dir_all(temp_dir.path().join("2025-01-01")).unwrap();
+
+        // 未ダウンロード
+        assert!(!RealDownloadService::is_already_downloaded(
+            &mut manifest,
+            "file1",
+            file_name,
+            Some(1000)
+        ));
+
+        // 期待サイズと一致する既存ファイルは取り込まれる
+        std::fs::write(temp_dir.path().join(file_name), vec![0u8; 1000]).unwrap();
+        assert!(RealDownloadService::is_already_downloaded(
+            &mut manifest,
+            "file1",
+            file_name,
+            Some(1000)
+        ));
+        assert!(manifest.get("file1").is_some());
+
+        // 削除されたファイルは再ダウンロード対象になり、記録も取り除かれる
+        std::fs::remove_file(temp_dir.path().join(file_name)).unwrap();
+        assert!(!RealDownloadService::is_already_downloaded(
+            &mut manifest,
+            "file1",
+            file_name,
+            Some(1000)
+        ));
+        assert!(manifest.get("file1").is_none());
+    }
+
+    #[test]
+    fn test_reverify_trash_item_skips_files_changed_after_preview() {
+        use crate::components::trash::{self, TrashFile, TrashItem};
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let output_dir = temp_dir.path();
+        let file_name = "2025-01-01/meeting.mp4";
+        std::fs::create_dir_all(output_dir.join("2025-01-01")).unwrap();
+        std::fs::write(output_dir.join(file_name), vec![0u8; 1000]).unwrap();
+        let mut manifest = DownloadManifest::open(output_dir).unwrap();
+        manifest.record("file1", Path::new(file_name)).unwrap();
+
+        let verification =
+            trash::verify_local_file(&manifest, output_dir, "file1", Path::new(file_name), None);
+        let item = TrashItem {
+            meeting_uuid: "uuid1".to_string(),
+            topic: "Test Meeting".to_string(),
+            start_time: "2025-01-01T00:00:00Z".to_string(),
+            granularity: TrashGranularity::File,
+            files: vec![TrashFile {
+                recording_id: "file1".to_string(),
+                file_type: "MP4".to_string(),
+                local_path: file_name.to_string(),
+                verification,
+            }],
+        };
+        assert!(item.is_eligible());
+        assert_eq!(
+            RealDownloadService::reverify_trash_item(&manifest, output_dir, &item),
+            None
+        );
+
+        // 事前確認の後にファイルが削除された場合は移動しない
+        std::fs::remove_file(output_dir.join(file_name)).unwrap();
+        assert!(RealDownloadService::reverify_trash_item(&manifest, output_dir, &item).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_refresh_download_urls_replaces_files_but_keeps_meeting() {
+        use crate::components::report::FinalState;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/meetings/uuid1/recordings"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "uuid": "uuid1",
+                "id": 123,
+                "host_id": "host1",
+                "topic": "Renamed Meeting",
+                "start_time": "2025-01-01T00:00:00Z",
+                "duration": 60,
+                "recording_files": [{
+                    "id": "file1",
+                    "file_type": "MP4",
+                    "file_size": 1000,
+                    "download_url": "https://zoom.example/rec/fresh"
+                }]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let mut api = ApiComponent::new(ApiConfig {
+            base_url: server.uri(),
+            ..ApiConfig::default()
+        });
+        api.set_token_provider(Arc::new(
+            crate::components::token_provider::StaticToken::new("token"),
+        ));
+
+        let meeting = make_meeting("uuid1", vec![]);
+        let item = |file: RecordingFile| FailedItem {
+            meeting: meeting.clone(),
+            file,
+            state: FinalState::Failed,
+            skip_reason: None,
+            error: None,
+        };
+        let mut items = vec![
+            item(make_file("file1", RecordingFileType::MP4, "")),
+            item(make_file("", RecordingFileType::Summary, "")),
+        ];
+
+        RealDownloadService::refresh_download_urls(&api, &mut items).await;
+
+        // 会議は1回だけ取得し、出力ファイル名に使う会議情報は保存時のまま
+        assert_eq!(items[0].file.download_url, "https://zoom.example/rec/fresh");
+        assert_eq!(items[0].meeting.topic, "Test Meeting");
+        // AI要約は Meeting Summary API で取得するため置き換えない
+        assert!(items[1].file.download_url.is_empty());
+    }
+
+    #[test]
+    fn test_lagged_event_bus_still_records_every_completed_file() {
+        use crate::components::token_provider::StaticToken;
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let setup = tokio::runtime::Runtime::new().unwrap();
+        let server = setup.block_on(MockServer::start());
+        setup.block_on(
+            Mock::given(method("GET"))
+                .respond_with(ResponseTemplate::new(200).set_body_bytes(vec![7u8; 1000]))
+                .mount(&server),
+        );
+
+        let meetings: Vec<MeetingRecording> = (0..20)
+            .map(|i| {
+                let file = make_file(
+                    &format!("file{}", i),
+                    RecordingFileType::MP4,
+                    &format!("{}/download/{}", server.uri(), i),
+                );
+                let mut meeting = make_meeting(&format!("uuid-{}", i), vec![file]);
+                meeting.topic = format!("Meeting {}", i);
+                meeting
+            })
+            .collect();
+        let files: Vec<(&MeetingRecording, &RecordingFile)> = meetings
+            .iter()
+            .map(|meeting| (meeting, &meeting.recording_files[0]))
+            .collect();
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let options = DownloadOptions {
+            output_dir: temp_dir.path().to_string_lossy().to_string(),
+            settings: crate::components::config::DownloadSettings::default(),
+            endpoints: EndpointProfile::default(),
+        };
+        let (sender, receiver) = mpsc::channel();
+        let mut plan = RealDownloadService::plan_downloads(&files, &options, &sender);
+        // 失敗ファイル記録（既定パス）は更新しない
+        plan.sources.clear();
+
+        // 1 件しか保持しないバスでは購読者が必ず取りこぼす
+        let outcome = RealDownloadService::run_download_tasks_with(
+            plan,
+            Arc::new(StaticToken::new("token")),
+            &options,
+            DownloadControl::new(),
+            sender,
+            None,
+            1,
+        )
+        .unwrap();
+
+        let DownloadOutcome::Completed(completed_files) = outcome else {
+            panic!("download should complete: {:?}", outcome);
+        };
+        assert_eq!(completed_files.len(), 20);
+        let report = receiver
+            .try_iter()
+            .find_map(|message| match message {
+                AppMessage::RunReportReady(report) => Some(report),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(report.count(FinalState::Completed), 20);
+        assert_eq!(report.entries.len(), 20);
+        let manifest = DownloadManifest::open(temp_dir.path()).unwrap();
+        let archive = ArchiveManifest::open(temp_dir.path().join("2025-01-01")).unwrap();
+        for meeting in &meetings {
+            let file = &meeting.recording_files[0];
+            let key = RealDownloadService::manifest_key(meeting, file);
+            assert!(
+                manifest.get(&key).is_some(),
+                "{} is not in the manifest",
+                key
+            );
+            assert!(
+                archive.meeting(&meeting.uuid).is_some(),
+                "{} is not in manifest.json",
+                meeting.uuid
+            );
+        }
+    }
+
+    #[test]
+    fn test_resumed_journal_entry_is_recorded_in_manifest_and_report() {
+        use crate::components::token_provider::StaticToken;
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let setup = tokio::runtime::Runtime::new().unwrap();
+        let server = setup.block_on(MockServer::start());
+        // 前回のセッションでは失敗し、再開時には取得できる
+        setup.block_on(
+            Mock::given(method("GET"))
+                .respond_with(ResponseTemplate::new(404))
+                .up_to_n_times(1)
+                .mount(&server),
+        );
+        setup.block_on(
+            Mock::given(method("GET"))
+                .respond_with(ResponseTemplate::new(200).set_body_bytes(vec![7u8; 1000]))
+                .mount(&server),
+        );
+
+        let file = make_file(
+            "file1",
+            RecordingFileType::MP4,
+            &format!("{}/download/1", server.uri()),
+        );
+        let meeting = make_meeting("uuid-1", vec![file]);
+        let files = vec![(&meeting, &meeting.recording_files[0])];
+        let temp_dir = tempfile::tempdir().unwrap();
+        let options = DownloadOptions {
+            output_dir: temp_dir.path().to_string_lossy().to_string(),
+            settings: crate::components::config::DownloadSettings::default(),
+            endpoints: EndpointProfile::default(),
+        };
+        let journal_path = temp_dir.path().join("journal.json");
+        let journal = || {
+            Some(Arc::new(std::sync::Mutex::new(
+                DownloadJournal::open(&journal_path).unwrap(),
+            )))
+        };
+
+        let (sender, _receiver) = mpsc::channel();
+        let mut plan = RealDownloadService::plan_downloads(&files, &options, &sender);
+        // 失敗ファイル記録（既定パス）は更新しない
+        plan.sources.clear();
+        let outcome = RealDownloadService::run_download_tasks_with(
+            plan,
+            Arc::new(StaticToken::new("token")),
+            &options,
+            DownloadControl::new(),
+            sender,
+            journal(),
+            DEFAULT_EVENT_BUS_CAPACITY,
+        )
+        .unwrap();
+        assert!(matches!(outcome, DownloadOutcome::Completed(ref files) if files.is_empty()));
+
+        let entries = DownloadJournal::open(&journal_path).unwrap().unfinished();
+        assert_eq!(entries.len(), 1);
+        let mut plan = RealDownloadService::plan_resume(&entries);
+        assert_eq!(plan.manifest_targets.len(), 1);
+        assert_eq!(plan.sources.len(), 1);
+        plan.sources.clear();
+
+        let (sender, receiver) = mpsc::channel();
+        let outcome = RealDownloadService::run_download_tasks_with(
+            plan,
+            Arc::new(StaticToken::new("token")),
+            &options,
+            DownloadControl::new(),
+            sender,
+            journal(),
+            DEFAULT_EVENT_BUS_CAPACITY,
+        )
+        .unwrap();
+
+        let DownloadOutcome::Completed(completed_files) = outcome else {
+            panic!("resumed download should complete: {:?}", outcome);
+        };
+        assert_eq!(completed_files.len(), 1);
+        let key = RealDownloadService::manifest_key(&meeting, &meeting.recording_files[0]);
+        let manifest = DownloadManifest::open(temp_dir.path()).unwrap();
+        assert!(manifest.get(&key).is_some());
+        let archive = ArchiveManifest::open(temp_dir.path().join("2025-01-01")).unwrap();
+        assert!(archive.meeting("uuid-1").is_some());
+        let report = receiver
+            .try_iter()
+            .find_map(|message| match message {
+                AppMessage::RunReportReady(report) => Some(report),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(report.count(FinalState::Completed), 1);
+        assert_eq!(report.entries[0].file.meeting_uuid, "uuid-1");
+    }
 }
@@ -113,6 +113,8 @@ fn ui008_load_config_uses_mock_service() {
                 client_id: "mock_client_id".to_string(),
                 client_secret: "mock_secret".to_string(),
                 redirect_uri: Some("http://localhost:8080/callback".to_string()),
+                endpoints: Default::default(),
+                download: Default::default(),
             })
         });
     // update_uiで他のconfigメソッドが呼ばれる可能性に備える
@@ -159,3 +161,32 @@ fn ui009_save_config_does_not_panic_with_mock() {
     let mut app = ZoomDownloaderApp::new_with_services(services);
     run_update_ui(&mut app);
 }
+
+/// UI-010: Trash画面レンダリング（未取得・取得済み）
+#[test]
+fn ui010_trash_screen_renders() {
+    use zoom_video_mover_lib::gui::AppMessage;
+    use zoom_video_mover_lib::RecordingSearchResponse;
+
+    let mut app = create_test_app();
+    app.set_config_loaded(true);
+    app.set_access_token(Some("token".to_string()));
+    app.set_current_screen(AppScreen::Trash);
+    run_update_ui(&mut app);
+
+    app.sender()
+        .send(AppMessage::TrashedRecordingsLoaded(
+            RecordingSearchResponse {
+                from: "2025-01-01".to_string(),
+                to: "2025-01-31".to_string(),
+                page_count: 1,
+                page_size: 0,
+                total_records: 0,
+                next_page_token: None,
+                meetings: vec![],
+            },
+        ))
+        .unwrap();
+    run_update_ui(&mut app);
+    assert!(app.trashed_recordings().is_some());
+}
@@ -275,3 +275,801 @@ fn st015_sequential_messages_maintain_consistency() {
     assert!(!app.is_downloading());
     assert!(app.status_message().contains("1 files"));
 }
+
+/// ST-016: 未完了ジョブの検出と破棄
+#[test]
+fn st016_unfinished_jobs_are_offered_and_discarded() {
+    use super::helpers::mock_services;
+    use std::path::PathBuf;
+    use std::sync::Arc;
+    use zoom_video_mover_lib::components::download::TaskState;
+    use zoom_video_mover_lib::components::journal::JournalEntry;
+    use zoom_video_mover_lib::gui::ZoomDownloaderApp;
+    use zoom_video_mover_lib::services::MockDownloadService;
+
+    let mut mock_download = MockDownloadService::new();
+    mock_download
+        .expect_unfinished_jobs()
+        .times(1)
+        .returning(|| {
+            Ok(vec![JournalEntry {
+                task_id: "uuid-file".to_string(),
+                download_url: "https://zoom.us/rec/download/abc".to_string(),
+                output_path: PathBuf::from("downloads/video.mp4"),
+                file_name: "video.mp4".to_string(),
+                expected_size: Some(100),
+                state: TaskState::InProgress,
+                retry_count: 1,
+                priority: Default::default(),
+                bytes_written: 40,
+                error: None,
+                updated_at: chrono::Utc::now(),
+                source: None,
+            }])
+        });
+    mock_download
+        .expect_discard_unfinished_jobs()
+        .times(1)
+        .returning(|| Ok(()));
+
+    let mut services = mock_services();
+    services.download_service = Arc::new(mock_download);
+    let mut app = ZoomDownloaderApp::new_with_services(services);
+
+    app.check_unfinished_jobs_for_test();
+    assert_eq!(app.unfinished_jobs().len(), 1);
+    assert!(app.status_message().contains("1 unfinished"));
+
+    app.discard_unfinished_jobs_for_test();
+    assert!(app.unfinished_jobs().is_empty());
+}
+
+/// ST-017: 全体進捗・ファイル別進捗の反映
+#[test]
+fn st017_overall_and_file_progress_update_state() {
+    use zoom_video_mover_lib::components::download::{
+        DownloadProgress, OverallProgress, TaskState,
+    };
+
+    let mut app = create_test_app();
+    let sender = app.sender().clone();
+
+    sender
+        .send(AppMessage::DownloadFileProgress {
+            task_id: "task-1".to_string(),
+            file_name: "video.mp4".to_string(),
+            state: TaskState::Pending,
+            progress: Some(DownloadProgress {
+                total_bytes: Some(200),
+                ..DownloadProgress::default()
+            }),
+        })
+        .unwrap();
+    sender
+        .send(AppMessage::DownloadFileProgress {
+            task_id: "task-1".to_string(),
+            file_name: "video.mp4".to_string(),
+            state: TaskState::InProgress,
+            progress: Some(DownloadProgress {
+                downloaded_bytes: 50,
+                total_bytes: Some(200),
+                percentage: 0.25,
+                ..DownloadProgress::default()
+            }),
+        })
+        .unwrap();
+    sender
+        .send(AppMessage::DownloadOverallProgress(OverallProgress {
+            total_tasks: 2,
+            active_tasks: 1,
+            overall_percentage: 0.125,
+            downloaded_bytes: 50,
+            total_bytes: 400,
+            ..OverallProgress::default()
+        }))
+        .unwrap();
+    app.process_messages_for_test();
+
+    assert_eq!(app.file_progress_rows().len(), 1);
+    let row = &app.file_progress_rows()[0];
+    assert_eq!(row.state, TaskState::InProgress);
+    assert_eq!(row.progress.downloaded_bytes, 50);
+    assert_eq!(app.current_file(), "video.mp4");
+    assert_eq!(app.overall_progress().total_bytes, 400);
+    assert!((app.progress_percentage() - 0.125).abs() < f32::EPSILON);
+
+    sender
+        .send(AppMessage::DownloadFileProgress {
+            task_id: "task-1".to_string(),
+            file_name: "video.mp4".to_string(),
+            state: TaskState::Completed,
+            progress: None,
+        })
+        .unwrap();
+    app.process_messages_for_test();
+
+    let row = &app.file_progress_rows()[0];
+    assert_eq!(row.state, TaskState::Completed);
+    assert_eq!(row.progress.percentage, 1.0);
+    assert_eq!(row.progress.total_bytes, Some(200));
+}
+
+/// ST-018: 帯域設定の保存と実行中の上限変更
+#[test]
+fn st018_bandwidth_settings_are_saved_and_applied_live() {
+    use super::helpers::mock_services;
+    use zoom_video_mover_lib::components::download::DownloadControl;
+    use zoom_video_mover_lib::gui::ZoomDownloaderApp;
+    use zoom_video_mover_lib::services::MockConfigService;
+
+    let mut mock_config = MockConfigService::new();
+    mock_config
+        .expect_save_config()
+        .withf(|config, _| {
+            config.download.max_bytes_per_sec == Some(10 * 1024 * 1024)
+                && config.download.bandwidth_schedule.len() == 1
+                && config.download.bandwidth_schedule[0].max_bytes_per_sec == Some(2 * 1024 * 1024)
+        })
+        .times(1)
+        .returning(|_, _| Ok(()));
+
+    let mut services = mock_services();
+    services.config_service = Box::new(mock_config);
+    let mut app = ZoomDownloaderApp::new_with_services(services);
+
+    // 不正な入力は保存されない
+    app.set_bandwidth_limit_input("fast");
+    app.save_config_for_test();
+    assert!(app.status_message().contains("Invalid bandwidth limit"));
+
+    app.set_bandwidth_limit_input("10");
+    app.add_bandwidth_window_input("09:00", "18:00", "2");
+    app.save_config_for_test();
+    assert_eq!(app.status_message(), "Configuration saved");
+    assert_eq!(
+        app.download_settings().bandwidth_schedule[0]
+            .start
+            .format("%H:%M")
+            .to_string(),
+        "09:00"
+    );
+
+    let control = DownloadControl::new();
+    app.set_download_control(Some(control.clone()));
+    app.set_live_bandwidth_input("0.5");
+    app.apply_live_bandwidth_limit_for_test();
+    assert_eq!(control.bandwidth_limit(), Some(512 * 1024));
+
+    app.set_live_bandwidth_input("");
+    app.apply_live_bandwidth_limit_for_test();
+    assert_eq!(control.bandwidth_limit(), None);
+    assert_eq!(app.status_message(), "Bandwidth limit removed");
+}
+
+/// ST-019: 空き容量不足の警告と一時停止通知
+#[test]
+fn st019_low_disk_space_is_reported() {
+    use super::helpers::mock_services;
+    use std::sync::Arc;
+    use zoom_video_mover_lib::components::disk_space::SpaceCheck;
+    use zoom_video_mover_lib::gui::ZoomDownloaderApp;
+    use zoom_video_mover_lib::services::MockDownloadService;
+
+    let mut mock_download = MockDownloadService::new();
+    mock_download
+        .expect_preflight_disk_space()
+        .times(1)
+        .returning(|_, _, _| {
+            Ok(SpaceCheck {
+                required_bytes: 5 * 1024 * 1024 * 1024,
+                available_bytes: 2 * 1024 * 1024 * 1024,
+                reserve_bytes: 1024 * 1024 * 1024,
+                unknown_size_files: 0,
+            })
+        });
+
+    let mut services = mock_services();
+    services.download_service = Arc::new(mock_download);
+    let mut app = ZoomDownloaderApp::new_with_services(services);
+    app.set_access_token(Some("token".to_string()));
+    app.sender()
+        .send(AppMessage::RecordingsLoaded(RecordingSearchResponse {
+            from: "2025-01-01".to_string(),
+            to: "2025-01-31".to_string(),
+            page_count: 1,
+            page_size: 30,
+            total_records: 0,
+            next_page_token: None,
+            meetings: vec![],
+        }))
+        .unwrap();
+    app.process_messages_for_test();
+
+    // 容量不足の場合は開始せず警告を表示する
+    app.start_download_for_test();
+    assert!(!app.is_downloading());
+    assert_eq!(
+        app.space_warning().map(|check| check.shortfall()),
+        Some(4 * 1024 * 1024 * 1024)
+    );
+    assert!(app.status_message().contains("Not enough disk space"));
+
+    // ダウンロード中に容量が不足した場合は一時停止の理由を保持し、再開で解除する
+    app.set_is_downloading(true);
+    app.sender()
+        .send(AppMessage::DownloadLowDiskSpace {
+            available_bytes: 100,
+            reserve_bytes: 1024,
+        })
+        .unwrap();
+    app.sender().send(AppMessage::DownloadPaused).unwrap();
+    app.process_messages_for_test();
+    assert!(app.is_download_paused());
+    assert_eq!(app.low_disk_space(), Some((100, 1024)));
+
+    app.sender().send(AppMessage::DownloadResumed).unwrap();
+    app.process_messages_for_test();
+    assert_eq!(app.low_disk_space(), None);
+}
+
+/// ST-020: 待機中タスクの優先度表示と変更
+#[test]
+fn st020_task_priority_is_shown_and_changed() {
+    use zoom_video_mover_lib::components::download::{DownloadControl, TaskPriority, TaskState};
+
+    let mut app = create_test_app();
+    let control = DownloadControl::new();
+    app.set_download_control(Some(control.clone()));
+
+    for (task_id, file_name) in [("t-video", "video.mp4"), ("t-chat", "chat.txt")] {
+        app.sender()
+            .send(AppMessage::DownloadFileProgress {
+                task_id: task_id.to_string(),
+                file_name: file_name.to_string(),
+                state: TaskState::Pending,
+                progress: None,
+            })
+            .unwrap();
+    }
+    app.sender()
+        .send(AppMessage::DownloadTaskPriority {
+            task_id: "t-chat".to_string(),
+            priority: TaskPriority::High,
+        })
+        .unwrap();
+    app.process_messages_for_test();
+
+    let rows = app.file_progress_rows();
+    assert_eq!(rows[0].priority, TaskPriority::Normal);
+    assert_eq!(rows[1].priority, TaskPriority::High);
+
+    app.change_task_priority_for_test("t-video", TaskPriority::Deferred);
+    assert_eq!(app.file_progress_rows()[0].priority, TaskPriority::Deferred);
+    assert_eq!(
+        control.task_priority("t-video"),
+        Some(TaskPriority::Deferred)
+    );
+}
+
+/// ST-021: アーカイブ検証の実行と結果表示
+#[test]
+fn st021_archive_verification_reports_problems() {
+    use super::helpers::mock_services;
+    use std::path::PathBuf;
+    use std::sync::Arc;
+    use zoom_video_mover_lib::components::archive::{ModifiedFile, VerifyReport};
+    use zoom_video_mover_lib::gui::ZoomDownloaderApp;
+    use zoom_video_mover_lib::services::MockDownloadService;
+
+    let mut mock_download = MockDownloadService::new();
+    mock_download
+        .expect_verify_archive()
+        .withf(|output_dir| output_dir == "/archive")
+        .times(1)
+        .returning(|_| {
+            Ok(VerifyReport {
+                verified: vec![PathBuf::from("2025-01-01/video.mp4")],
+                missing: vec![PathBuf::from("2025-01-01/audio.m4a")],
+                modified: vec![ModifiedFile {
+                    path: PathBuf::from("2025-01-01/chat.txt"),
+                    reason: "SHA-256 does not match".to_string(),
+                }],
+                extra: vec![],
+            })
+        });
+
+    let mut services = mock_services();
+    services.download_service = Arc::new(mock_download);
+    let mut app = ZoomDownloaderApp::new_with_services(services);
+    app.set_output_dir("/archive");
+
+    app.verify_archive_for_test();
+    assert!(app.is_verifying());
+
+    // 検証は別スレッドで実行される
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+    while app.is_verifying() && std::time::Instant::now() < deadline {
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        app.process_messages_for_test();
+    }
+
+    assert!(!app.is_verifying());
+    let report = app.verify_report().expect("report should be shown");
+    assert!(!report.is_clean());
+    assert_eq!(
+        app.status_message(),
+        "Archive verification: 1 verified, 1 missing, 1 modified, 0 extra"
+    );
+    assert_eq!(app.current_screen(), &AppScreen::Config);
+}
+
+/// ST-022: 実行レポートの受信とエクスポート
+#[test]
+fn st022_run_report_is_kept_and_exported() {
+    use zoom_video_mover_lib::components::report::{
+        ReportFile, ReportFormat, RunRecorder, SkipReason,
+    };
+
+    let output_dir = tempfile::tempdir().unwrap();
+    let mut app = create_test_app();
+    app.set_output_dir(output_dir.path().to_str().unwrap());
+
+    let mut recorder = RunRecorder::new();
+    recorder.skip(
+        ReportFile::unattributed("2025-01-01/summary.json"),
+        SkipReason::EmptyDownloadUrl,
+    );
+    app.sender()
+        .send(AppMessage::RunReportReady(recorder.finish(false)))
+        .unwrap();
+    app.sender()
+        .send(AppMessage::DownloadComplete(vec![]))
+        .unwrap();
+    app.process_messages_for_test();
+
+    let report = app.run_report().expect("report should be kept");
+    assert_eq!(
+        report.summary(),
+        "0 completed, 1 skipped, 0 failed, 0 cancelled"
+    );
+
+    app.export_run_report_for_test(ReportFormat::Csv);
+    assert!(app.status_message().starts_with("Run report exported"));
+    let exported: Vec<_> = std::fs::read_dir(output_dir.path().join("reports"))
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    assert_eq!(exported.len(), 1);
+    let csv = std::fs::read_to_string(&exported[0]).unwrap();
+    assert!(csv.contains("2025-01-01/summary.json"));
+    assert!(csv.contains("empty_download_url"));
+}
+
+/// ST-023: 前回のバッチで失敗したファイルの再試行
+#[test]
+fn st023_failed_items_are_retried() {
+    use super::helpers::mock_services;
+    use std::sync::Arc;
+    use zoom_video_mover_lib::components::failed_batch::FailedItem;
+    use zoom_video_mover_lib::components::report::FinalState;
+    use zoom_video_mover_lib::gui::ZoomDownloaderApp;
+    use zoom_video_mover_lib::services::{DownloadOutcome, MockDownloadService};
+
+    let item = FailedItem {
+        meeting: serde_json::from_value(serde_json::json!({
+            "uuid": "meeting-uuid",
+            "id": 1,
+            "host_id": "host",
+            "topic": "Weekly",
+            "start_time": "2025-01-01T10:00:00Z",
+            "duration": 30,
+        }))
+        .unwrap(),
+        file: serde_json::from_value(serde_json::json!({
+            "id": "file-1",
+            "file_type": "MP4",
+        }))
+        .unwrap(),
+        state: FinalState::Failed,
+        skip_reason: None,
+        error: Some("HTTP 500".to_string()),
+    };
+
+    let mut mock_download = MockDownloadService::new();
+    mock_download
+        .expect_failed_items()
+        .times(1)
+        .returning(move || Ok(vec![item.clone()]));
+    mock_download
+        .expect_retry_failed()
+        .times(1)
+        .returning(|access_token, _, _, sender| {
+            assert_eq!(access_token, "token");
+            // 再試行で全件取得できたので一覧は空になる
+            let _ = sender.send(AppMessage::RetryableFilesUpdated(vec![]));
+            Ok(DownloadOutcome::Completed(vec![]))
+        });
+
+    let mut services = mock_services();
+    services.download_service = Arc::new(mock_download);
+    let mut app = ZoomDownloaderApp::new_with_services(services);
+
+    app.check_failed_items_for_test();
+    assert_eq!(app.failed_items().len(), 1);
+
+    // 未認証では再試行しない
+    app.retry_failed_items_for_test();
+    assert!(!app.is_downloading());
+
+    app.set_access_token(Some("token".to_string()));
+    app.retry_failed_items_for_test();
+    assert!(app.is_downloading());
+    assert_eq!(app.current_screen(), &AppScreen::Progress);
+
+    // 再試行は別スレッドで実行される
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+    while app.is_downloading() && std::time::Instant::now() < deadline {
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        app.process_messages_for_test();
+    }
+
+    assert!(!app.is_downloading());
+    assert!(app.failed_items().is_empty());
+}
+
+/// ST-024: 接続先プロファイルの保存と認証への反映
+#[test]
+fn st024_endpoint_profile_is_saved_and_used_for_auth() {
+    use super::helpers::mock_services;
+    use std::sync::Arc;
+    use zoom_video_mover_lib::gui::ZoomDownloaderApp;
+    use zoom_video_mover_lib::services::{MockAuthService, MockConfigService};
+    use zoom_video_mover_lib::{EndpointPreset, EndpointProfile};
+
+    let mut mock_config = MockConfigService::new();
+    mock_config
+        .expect_save_config()
+        .withf(|config, _| {
+            config.endpoints.preset == EndpointPreset::Custom
+                && config.endpoints.api_base_url() == "http://127.0.0.1:9000/v2"
+        })
+        .times(1)
+        .returning(|_, _| Ok(()));
+    let mut mock_auth = MockAuthService::new();
+    mock_auth
+        .expect_generate_auth_url()
+        .withf(|_, _, endpoints| endpoints.auth_url() == "http://127.0.0.1:9000/oauth/authorize")
+        .times(1)
+        .returning(|_, _, endpoints| Ok(endpoints.auth_url()));
+
+    let mut services = mock_services();
+    services.config_service = Box::new(mock_config);
+    services.auth_service = Arc::new(mock_auth);
+    let mut app = ZoomDownloaderApp::new_with_services(services);
+    assert_eq!(app.endpoints(), &EndpointProfile::default());
+
+    // URL が欠けたカスタム設定は保存されない
+    app.set_endpoints(EndpointProfile::preset(EndpointPreset::Custom));
+    app.save_config_for_test();
+    assert!(app
+        .status_message()
+        .contains("Failed to save configuration"));
+
+    app.set_endpoints(EndpointProfile::custom("http://127.0.0.1:9000"));
+    app.save_config_for_test();
+    assert_eq!(app.status_message(), "Configuration saved");
+
+    // 認証は別スレッドで実行される
+    app.start_authentication_for_test();
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+    while std::time::Instant::now() < deadline {
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        app.process_messages_for_test();
+        if app.auth_url().is_some() {
+            break;
+        }
+    }
+    assert_eq!(
+        app.auth_url().map(String::as_str),
+        Some("http://127.0.0.1:9000/oauth/authorize")
+    );
+}
+
+/// ST-025: 選択したユーザーの録画の取得
+#[test]
+fn st025_recordings_are_fetched_for_selected_users() {
+    use super::helpers::mock_services;
+    use std::sync::Arc;
+    use zoom_video_mover_lib::components::api::{RecordingSearchResponse, ZoomUser};
+    use zoom_video_mover_lib::gui::{RecordingScopeMode, ZoomDownloaderApp};
+    use zoom_video_mover_lib::services::{MockRecordingService, RecordingScope};
+
+    let user = |id: &str, email: &str| ZoomUser {
+        id: id.to_string(),
+        email: email.to_string(),
+        first_name: String::new(),
+        last_name: String::new(),
+        display_name: String::new(),
+        status: "active".to_string(),
+    };
+    let users = vec![
+        user("u1", "alice@example.com"),
+        user("u2", "bob@example.com"),
+    ];
+
+    let mut mock_recording = MockRecordingService::new();
+    mock_recording
+        .expect_list_users()
+        .times(1)
+        .returning(move |_, _| Ok(users.clone()));
+    mock_recording
+        .expect_get_recordings()
+        .withf(|_, scope, _, _, _, _| {
+            matches!(scope, RecordingScope::Users(users)
+                if users.len() == 1 && users[0].email == "bob@example.com")
+        })
+        .times(1)
+        .returning(|_, _, from, to, _, _| {
+            Ok(RecordingSearchResponse {
+                from: from.to_string(),
+                to: to.to_string(),
+                page_count: 1,
+                page_size: 0,
+                total_records: 0,
+                next_page_token: None,
+                meetings: vec![],
+            })
+        });
+
+    let mut services = mock_services();
+    services.recording_service = Arc::new(mock_recording);
+    let mut app = ZoomDownloaderApp::new_with_services(services);
+    app.set_access_token(Some("token".to_string()));
+    app.set_search_dates("2025-01-01", "2025-01-31");
+    app.set_scope_mode(RecordingScopeMode::SelectedUsers);
+
+    // ユーザー未選択では検索しない
+    app.fetch_recordings_for_test();
+    assert!(!app.is_searching());
+    assert!(app.status_message().contains("ユーザーを選択"));
+
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+    app.load_account_users_for_test();
+    while app.account_users().is_empty() && std::time::Instant::now() < deadline {
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        app.process_messages_for_test();
+    }
+    assert_eq!(app.account_users().len(), 2);
+
+    app.select_user("u2");
+    app.fetch_recordings_for_test();
+    assert!(app.is_searching());
+    while app.is_searching() && std::time::Instant::now() < deadline {
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        app.process_messages_for_test();
+    }
+    assert!(app.recordings().is_some());
+}
+
+/// ST-026: ゴミ箱への移動の事前確認と実行
+#[test]
+fn st026_only_verified_recordings_are_trashed_after_confirmation() {
+    use super::helpers::mock_services;
+    use std::sync::Arc;
+    use zoom_video_mover_lib::components::trash::{
+        LocalVerification, TrashAction, TrashAuditRecord, TrashFile, TrashGranularity, TrashItem,
+        TrashPlan, TrashReport, TrashResult,
+    };
+    use zoom_video_mover_lib::gui::ZoomDownloaderApp;
+    use zoom_video_mover_lib::services::MockDownloadService;
+
+    let item = |uuid: &str, verification: LocalVerification| TrashItem {
+        meeting_uuid: uuid.to_string(),
+        topic: "Weekly".to_string(),
+        start_time: "2025-01-01T10:00:00Z".to_string(),
+        granularity: TrashGranularity::Meeting,
+        files: vec![TrashFile {
+            recording_id: format!("{}-file", uuid),
+            file_type: "MP4".to_string(),
+            local_path: format!("{}.mp4", uuid),
+            verification,
+        }],
+    };
+    let plan = TrashPlan {
+        granularity: TrashGranularity::Meeting,
+        items: vec![
+            item(
+                "verified",
+                LocalVerification::Verified {
+                    size: 9,
+                    sha256: "abc".to_string(),
+                },
+            ),
+            item("missing", LocalVerification::Missing),
+        ],
+    };
+
+    let mut mock_download = MockDownloadService::new();
+    let preview = plan.clone();
+    mock_download
+        .expect_preview_trash()
+        .withf(|_, selected, _, granularity| {
+            selected == ["verified".to_string()] && *granularity == TrashGranularity::Meeting
+        })
+        .times(1)
+        .returning(move |_, _, _, _| Ok(preview.clone()));
+    mock_download
+        .expect_trash_recordings()
+        .times(1)
+        .returning(|_, plan, _| {
+            Ok(TrashReport {
+                records: plan
+                    .eligible()
+                    .map(|item| TrashAuditRecord {
+                        at: chrono::Utc::now(),
+                        action: TrashAction::Trash,
+                        meeting_uuid: item.meeting_uuid.clone(),
+                        topic: item.topic.clone(),
+                        granularity: item.granularity,
+                        recording_id: None,
+                        files: item.files.clone(),
+                        result: TrashResult::Succeeded,
+                    })
+                    .collect(),
+            })
+        });
+
+    let mut services = mock_services();
+    services.download_service = Arc::new(mock_download);
+    let mut app = ZoomDownloaderApp::new_with_services(services);
+    app.set_access_token(Some("token".to_string()));
+    app.sender()
+        .send(AppMessage::RecordingsLoaded(RecordingSearchResponse {
+            from: "2025-01-01".to_string(),
+            to: "2025-01-31".to_string(),
+            page_count: 1,
+            page_size: 0,
+            total_records: 0,
+            next_page_token: None,
+            meetings: vec![],
+        }))
+        .unwrap();
+    app.process_messages_for_test();
+
+    // 選択がなければ事前確認しない
+    app.preview_trash_for_test();
+    assert!(!app.is_trashing());
+
+    app.select_recording("verified");
+    app.set_trash_granularity(TrashGranularity::Meeting);
+    app.preview_trash_for_test();
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+    while app.is_trashing() && std::time::Instant::now() < deadline {
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        app.process_messages_for_test();
+    }
+    let shown = app.trash_plan().expect("preview should be shown");
+    assert_eq!(shown.eligible().count(), 1);
+    assert_eq!(shown.blocked().count(), 1);
+    // 確認前はクラウドを変更しない
+    assert!(app.trash_report().is_none());
+
+    app.confirm_trash_for_test();
+    assert!(app.trash_plan().is_none());
+    while app.is_trashing() && std::time::Instant::now() < deadline {
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        app.process_messages_for_test();
+    }
+    let report = app.trash_report().expect("report should be shown");
+    assert_eq!(report.succeeded(), 1);
+    assert_eq!(report.records[0].meeting_uuid, "verified");
+}
+
+/// ST-027: ゴミ箱の録画の復元と完全削除
+#[test]
+fn st027_trashed_recordings_are_recovered_and_deleted_after_confirmation() {
+    use super::helpers::mock_services;
+    use std::sync::Arc;
+    use zoom_video_mover_lib::components::trash::{
+        TrashAction, TrashAuditRecord, TrashReport, TrashResult, TrashedAction, TrashedTarget,
+    };
+    use zoom_video_mover_lib::gui::ZoomDownloaderApp;
+    use zoom_video_mover_lib::services::MockRecordingService;
+
+    let trashed: RecordingSearchResponse = serde_json::from_value(serde_json::json!({
+        "from": "2025-01-01",
+        "to": "2025-01-31",
+        "page_size": 2,
+        "total_records": 2,
+        "meetings": [
+            {
+                "uuid": "m1",
+                "id": 1,
+                "host_id": "me",
+                "topic": "Weekly",
+                "start_time": "2025-01-01T10:00:00Z",
+                "duration": 30,
+                "recording_files": [
+                    { "id": "f1", "file_type": "MP4" },
+                    { "id": "f2", "file_type": "CHAT" }
+                ]
+            },
+            {
+                "uuid": "m2",
+                "id": 2,
+                "host_id": "me",
+                "topic": "Review",
+                "start_time": "2025-01-02T10:00:00Z",
+                "duration": 30,
+                "recording_files": [{ "id": "f3", "file_type": "MP4" }]
+            }
+        ]
+    }))
+    .unwrap();
+    let succeeded = |action: TrashAction, targets: &[TrashedTarget]| TrashReport {
+        records: targets
+            .iter()
+            .map(|target| TrashAuditRecord::for_target(action, target, TrashResult::Succeeded))
+            .collect(),
+    };
+
+    let mut mock_recording = MockRecordingService::new();
+    mock_recording
+        .expect_get_trashed_recordings()
+        .times(1)
+        .returning(move |_, _, _, _, _| Ok(trashed.clone()));
+    mock_recording
+        .expect_recover_recordings()
+        .withf(|_, targets, _| {
+            targets.len() == 1 && targets[0].recording_id.as_deref() == Some("f1")
+        })
+        .times(1)
+        .returning(move |_, targets, _| Ok(succeeded(TrashAction::Recover, targets)));
+    mock_recording
+        .expect_delete_recordings_permanently()
+        .withf(|_, targets, _| {
+            targets.len() == 1
+                && targets[0].meeting_uuid == "m2"
+                && targets[0].recording_id.is_none()
+        })
+        .times(1)
+        .returning(move |_, targets, _| Ok(succeeded(TrashAction::Delete, targets)));
+
+    let mut services = mock_services();
+    services.recording_service = Arc::new(mock_recording);
+    let mut app = ZoomDownloaderApp::new_with_services(services);
+    app.set_access_token(Some("token".to_string()));
+    app.set_search_dates("2025-01-01", "2025-01-31");
+
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+    let wait = |app: &mut ZoomDownloaderApp| {
+        while app.is_loading_trash() && std::time::Instant::now() < deadline {
+            std::thread::sleep(std::time::Duration::from_millis(10));
+            app.process_messages_for_test();
+        }
+    };
+    app.fetch_trashed_recordings_for_test();
+    wait(&mut app);
+    assert_eq!(app.trashed_recordings().unwrap().meetings.len(), 2);
+
+    // ファイル単位の復元は確認するまで実行しない
+    app.select_trashed("m1-f1");
+    app.request_trash_action_for_test(TrashedAction::Recover);
+    let (action, targets) = app.pending_trash_action().expect("confirmation shown");
+    assert_eq!(*action, TrashedAction::Recover);
+    assert_eq!(targets.len(), 1);
+    app.confirm_trash_action_for_test();
+    wait(&mut app);
+    let meetings = &app.trashed_recordings().unwrap().meetings;
+    assert_eq!(meetings[0].recording_files.len(), 1);
+
+    // ミーティング単位の完全削除
+    app.select_trashed("m2");
+    app.request_trash_action_for_test(TrashedAction::Delete);
+    app.confirm_trash_action_for_test();
+    wait(&mut app);
+    let (action, report) = app.trash_action_report().expect("report shown");
+    assert_eq!(*action, TrashedAction::Delete);
+    assert_eq!(report.succeeded(), 1);
+    assert_eq!(app.trashed_recordings().unwrap().meetings.len(), 1);
+}
@@ -33,13 +33,14 @@ fn create_test_config() -> AppConfig {
         debug_mode: true,
         log_level: "debug".to_string(),
         api: ApiSettings {
-            base_url: "https://api.zoom.us/v2".to_string(),
             timeout_seconds: 30,
             max_retries: 3,
             default_page_size: 10,
             max_pages: 100,
             page_interval_ms: 100,
         },
+        endpoints: Default::default(),
+        download: Default::default(),
     }
 }
 
@@ -107,6 +108,7 @@ async fn test_download_component_lifecycle() -> AppResult<()> {
         timeout: std::time::Duration::from_secs(10),
         max_retries: 2,
         output_directory: temp_dir.path().to_path_buf(),
+        ..DownloadConfig::default()
     };
 
     let mut download_component = DownloadComponent::new(download_config);
@@ -140,10 +142,12 @@ async fn test_recording_search_request_validation() -> AppResult<()> {
     // 無効な日付範囲のテスト
     let invalid_request = RecordingSearchRequest {
         user_id: Some("test_user".to_string()),
+        account_id: None,
         from: NaiveDate::from_ymd_opt(2025, 1, 31).unwrap(),
         to: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(), // from > to
         page_size: Some(30),
         next_page_token: None,
+        trash: false,
     };
 
     // バリデーションエラーが発生することを確認
@@ -284,10 +288,12 @@ mod property_tests {
             // Property: RecordingSearchRequestの作成は成功する
             let request = RecordingSearchRequest {
                 user_id: Some("test_user".to_string()),
+                account_id: None,
                 from: from_date,
                 to: to_date,
                 page_size: Some(30),
                 next_page_token: None,
+                trash: false,
             };
 
             prop_assert!(request.from <= request.to);
@@ -144,6 +144,7 @@ prop_compose! {
             id,
             account_id: "account123".to_string(),
             host_id: "host123".to_string(),
+            host_email: String::new(),
             topic,
             meeting_type: 2,
             start_time: Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string(),
@@ -272,10 +273,12 @@ proptest! {
         // Property 2: 録画検索リクエストの妥当性
         let request = RecordingSearchRequest {
             user_id: Some("test_user".to_string()),
+            account_id: None,
             from: from_date,
             to: to_date,
             page_size: Some(30),
             next_page_token: None,
+            trash: false,
         };
 
         // 日付範囲が正しい場合、リクエスト自体は有効
@@ -369,6 +372,8 @@ proptest! {
             debug_mode: false,
             log_level: "info".to_string(),
             api: ApiSettings::default(),
+            endpoints: Default::default(),
+            download: Default::default(),
         };
 
         // Property 1: シリアライゼーション・デシリアライゼーションの冪等性
@@ -452,6 +457,7 @@ proptest! {
                 timeout: std::time::Duration::from_secs(30),
                 max_retries: 3,
                 output_directory: temp_dir.path().to_path_buf(),
+                ..DownloadConfig::default()
             };
             let mut download_component = DownloadComponent::new(download_config);
             let download_init_result = download_component.initialize().await;
@@ -495,6 +501,7 @@ mod stress_tests {
                 id: 1000000000 + i,
                 account_id: "stress_test_account".to_string(),
                 host_id: "stress_test_host".to_string(),
+                host_email: String::new(),
                 topic: format!("Stress Test Meeting {}", i),
                 meeting_type: 2,
                 start_time: Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string(),